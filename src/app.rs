@@ -10,27 +10,75 @@ mod questions;
 mod storage;
 mod ai_day;
 mod stats;
+mod session;
+mod answer;
+mod skill;
+mod attempts;
+mod mastery;
+mod adaptive;
+mod review;
+mod schema;
+mod backup;
+mod export;
+mod worksheet;
+mod timed;
+mod hints;
+mod misconceptions;
+mod formats;
+mod visuals;
+mod config;
+mod worker;
+mod verify;
+mod prefetch;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mock_worker;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod build_stamp;
 
 use data::{Day, DAYS};
 use grade::Grade;
 use questions::{
     questions_for as local_questions_for, Difficulty as LocalDifficulty, Question as LocalQuestion,
 };
-use storage::{load_grade, load_progress, save_grade, save_progress, Progress};
+use storage::{
+    active_profile_id, add_profile, load_grade, load_profiles, load_progress, remove_profile,
+    save_grade, save_progress, scoped_key, set_active_profile, Progress,
+};
 use ai_day::{DayResponse, Difficulty as AiDifficulty};
-use stats::{load_stats, last_n_days, record_attempt, save_stats, sum_days};
+use stats::{
+    days_in_range, find_session, load_stats, last_n_days, recent_sessions, record_attempt, record_session_attempt,
+    record_misconception, record_served, record_set_result, record_solve, record_timed_session, save_stats, sum_days,
+    ServedQuestion, SetMiss, SetResult,
+};
+use session::{Session, SET_LEN};
+use answer::{check_answer, kind_for_prompt, AnswerKind};
+use skill::{classify_skill, Skill};
+use attempts::{append_attempt, load_attempts, most_missed, Attempt, AttemptSource};
+use mastery::{badge_for_mastery, mastery_label, MASTERED};
+use adaptive::{day_level, load_adaptive, record_adaptive, Level};
+use review::{add_days, load_review, save_review, ReviewItem};
+use schema::{load_versioned, save_versioned, Versioned};
+use backup::{apply_import, export_backup, load_learner_data, parse_backup, preview, Backup, ImportMode};
+use export::{attempts_csv, skill_csv};
+use worksheet::{build_worksheet, COUNT_CHOICES, DEFAULT_COUNT};
+use timed::{clear_timed, clock_label, load_timed, save_timed, Phase, TimedRun};
+use hints::{help_for, Help};
+use misconceptions::{spot, KnownWrong};
+use formats::{format_for, QuestionFormat};
+use visuals::{Mark, Visual};
+use worker::{last_error, WorkerError};
+use prefetch::{prefetch, Cancel};
+use verify::AnswerCheck;
+use config::{
+    current_config, load_override, ready_config, save_override, site_config, valid_url,
+    ConfigOverride, Prefetch, MAX_TIMEOUT_MS, MIN_TIMEOUT_MS,
+};
 
 use rand::seq::SliceRandom;
+use rand::RngCore;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen_futures::spawn_local;
 
-// ----------------------------
-// CONFIG: set to your deployed Worker URL
-// Local dev: http://127.0.0.1:8787
-// Deployed:  https://the-numbers-worker.mikegyver.workers.dev
-// ----------------------------
-const WORKER_BASE_URL: &str = "https://the-numbers-worker.mikegyver.workers.dev";
-
 // ----------------------------
 // Routes
 // ----------------------------
@@ -41,6 +89,24 @@ pub enum Route {
     Home,
     #[at("/day/:id")]
     Day { id: usize },
+    #[at("/review")]
+    Review,
+    #[at("/settings")]
+    Settings,
+    #[at("/replay/:day/:grade/:date/:seed")]
+    Replay {
+        day: usize,
+        grade: u8,
+        date: String,
+        seed: u64,
+    },
+    #[at("/worksheet/:day/:grade/:count/:seed")]
+    Worksheet {
+        day: usize,
+        grade: u8,
+        count: usize,
+        seed: u64,
+    },
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -68,13 +134,21 @@ fn day_tag(day_id: usize) -> (&'static str, &'static str) {
 // Subtle audio tones (robust: avoids web-sys feature gating issues)
 // ----------------------------
 
+const KEY_SOUND: &str = "the_numbers_sound_v1";
+
 fn is_sound_enabled() -> bool {
     let Some(win) = web_sys::window() else { return true; };
     let Ok(Some(storage)) = win.local_storage() else { return true; };
-    let Ok(Some(v)) = storage.get_item("the_numbers_sound_v1") else { return true; };
+    let Ok(Some(v)) = storage.get_item(&scoped_key(KEY_SOUND)) else { return true; };
     v == "1"
 }
 
+fn set_sound_enabled(on: bool) {
+    let Some(win) = web_sys::window() else { return; };
+    let Ok(Some(storage)) = win.local_storage() else { return; };
+    let _ = storage.set_item(&scoped_key(KEY_SOUND), if on { "1" } else { "0" });
+}
+
 // Set AudioParam value via JS reflection so we don't depend on web-sys generated methods.
 fn set_audio_param(node: &wasm_bindgen::JsValue, field: &str, value: f32) {
     use js_sys::{Function, Reflect};
@@ -133,6 +207,80 @@ fn play_tone(freq: f32, ms: i32, gain: f32) {
     cb.forget();
 }
 
+// ----------------------------
+// Update banner (service worker in index.html)
+// ----------------------------
+
+/// Fired on window when a new build has installed and is waiting.
+const UPDATE_EVENT: &str = "the-numbers-update";
+
+/// Whether index.html has already seen a waiting update (it may land before the app mounts).
+fn update_waiting() -> bool {
+    web_sys::window()
+        .and_then(|win| js_sys::Reflect::get(&win, &wasm_bindgen::JsValue::from_str("theNumbersUpdateReady")).ok())
+        .is_some_and(|v| v.is_truthy())
+}
+
+/// Switch to the waiting build; the page reloads once it has taken over.
+fn apply_update() {
+    let Some(win) = web_sys::window() else { return; };
+    let Ok(f) = js_sys::Reflect::get(&win, &wasm_bindgen::JsValue::from_str("theNumbersApplyUpdate")) else { return; };
+    if f.is_function() {
+        let func: js_sys::Function = f.unchecked_into();
+        let _ = func.call0(&wasm_bindgen::JsValue::NULL);
+    }
+}
+
+#[function_component(UpdateBanner)]
+fn update_banner() -> Html {
+    let ready = use_state(update_waiting);
+    let dismissed = use_state(|| false);
+
+    {
+        let ready = ready.clone();
+        use_effect_with((), move |_| {
+            let listener = Closure::<dyn FnMut()>::new(move || ready.set(true));
+            let win = web_sys::window();
+            if let Some(win) = &win {
+                let _ = win.add_event_listener_with_callback(UPDATE_EVENT, listener.as_ref().unchecked_ref());
+            }
+            move || {
+                if let Some(win) = win {
+                    let _ = win.remove_event_listener_with_callback(UPDATE_EVENT, listener.as_ref().unchecked_ref());
+                }
+            }
+        });
+    }
+
+    if !*ready || *dismissed {
+        return html! {};
+    }
+
+    html! {
+        <div role="status" style="margin: 0 0 14px; padding: 10px 12px; border:1px solid #b9d7ff; background:#eef6ff; border-radius: 12px; display:flex; gap:10px; align-items:center; flex-wrap:wrap;">
+            <div style="flex:1; min-width: 200px;">
+                <span style="font-weight:800;">{"✨ A new version of The Numbers is ready."}</span>
+                <span style="opacity:.8;">{" Your progress is saved."}</span>
+            </div>
+            <button
+                onclick={Callback::from(|_| apply_update())}
+                style="padding:8px 10px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+            >
+                {"Reload"}
+            </button>
+            <button
+                onclick={{
+                    let dismissed = dismissed.clone();
+                    Callback::from(move |_| dismissed.set(true))
+                }}
+                style="padding:8px 10px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+            >
+                {"Later"}
+            </button>
+        </div>
+    }
+}
+
 // ----------------------------
 // Celebration banner
 // ----------------------------
@@ -209,104 +357,75 @@ fn today_ymd_local() -> String {
 }
 
 fn day_cache_key(grade: Grade, day_id: usize) -> String {
-    format!("the_numbers_day_v1_{}_{}", grade.as_u8(), day_id)
+    scoped_key(&format!("the_numbers_day_v1_{}_{}", grade.as_u8(), day_id))
 }
 
-fn load_day_from_local_storage(grade: Grade, day_id: usize) -> Option<DayResponse> {
-    let window = web_sys::window()?;
-    let storage = window.local_storage().ok()??;
-    let raw = storage.get_item(&day_cache_key(grade, day_id)).ok()??;
-    serde_json::from_str::<DayResponse>(&raw).ok()
+/// Tomorrow's set, fetched ahead.
+fn ahead_cache_key(grade: Grade, day_id: usize) -> String {
+    scoped_key(&format!("the_numbers_day_ahead_v1_{}_{}", grade.as_u8(), day_id))
 }
 
-fn save_day_to_local_storage(grade: Grade, day_id: usize, day: &DayResponse) {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(raw) = serde_json::to_string(day) {
-                let _ = storage.set_item(&day_cache_key(grade, day_id), &raw);
-            }
+// Envelope version of the cache entry (DayResponse.version is the worker's payload version).
+impl Versioned for DayResponse {
+    const VERSION: u32 = 1;
+}
+
+// A set fetched ahead becomes the day's set once its date arrives.
+fn load_day_from_local_storage(grade: Grade, day_id: usize) -> Option<DayResponse> {
+    let current = load_versioned::<DayResponse>(&day_cache_key(grade, day_id));
+    let today = today_ymd_local();
+    if current.as_ref().is_some_and(|d| d.date_ymd == today) {
+        return current;
+    }
+    match load_ahead_day(grade, day_id) {
+        Some(ahead) if ahead.date_ymd == today => {
+            save_versioned(&day_cache_key(grade, day_id), &ahead);
+            Some(ahead)
         }
+        _ => current,
     }
 }
 
-// ----------------------------
-// AI fetch: per-day endpoint
-// ----------------------------
-
-async fn fetch_day(
-    worker_base_url: &str,
-    grade: Grade,
-    day_id: usize,
-) -> Result<DayResponse, String> {
-    let url = format!(
-        "{}/api/day-questions?grade={}&day={}",
-        worker_base_url.trim_end_matches('/'),
-        grade.as_u8(),
-        day_id
-    );
-
-    gloo_net::http::Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("fetch error: {e:?}"))?
-        .json::<DayResponse>()
-        .await
-        .map_err(|e| format!("json error: {e:?}"))
+fn load_ahead_day(grade: Grade, day_id: usize) -> Option<DayResponse> {
+    load_versioned(&ahead_cache_key(grade, day_id))
 }
 
-// ----------------------------
-// Skills classifier (Parent/Educator + Weekly Summary)
-// ----------------------------
+// Sets dated after today wait in the ahead slot.
+fn save_day_to_local_storage(grade: Grade, day_id: usize, day: &DayResponse) {
+    let key = if day.date_ymd > today_ymd_local() {
+        ahead_cache_key(grade, day_id)
+    } else {
+        day_cache_key(grade, day_id)
+    };
+    save_versioned(&key, day);
+}
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Skill {
-    Addition,
-    Subtraction,
-    Multiplication,
-    Division,
-    WordProblem,
-    Unknown,
-}
-
-fn classify_skill(prompt: &str) -> Skill {
-    let p = prompt.to_lowercase();
-
-    // word-problem signals first
-    if p.contains("how many")
-        || p.contains("left")
-        || p.contains("total")
-        || p.contains("in all")
-        || p.contains("shared")
-        || p.contains("altogether")
-    {
-        return Skill::WordProblem;
+/// Forget the active learner's cached online sets (today's and ahead).
+fn clear_day_caches() {
+    let Some(win) = web_sys::window() else { return; };
+    let Ok(Some(storage)) = win.local_storage() else { return; };
+    for grade in (1..=5).map(Grade::from_u8) {
+        for day_id in 1..=12 {
+            let _ = storage.remove_item(&day_cache_key(grade, day_id));
+            let _ = storage.remove_item(&ahead_cache_key(grade, day_id));
+        }
     }
+}
 
-    if p.contains('+') || p.contains(" plus ") {
-        return Skill::Addition;
-    }
-    if p.contains('-') || p.contains(" minus ") {
-        return Skill::Subtraction;
-    }
-    if p.contains('×') || p.contains(" x ") || p.contains(" times ") {
-        return Skill::Multiplication;
+/// Answer-key checks summed over the cached online sets for a grade.
+fn cached_answer_checks(grade: Grade) -> AnswerCheck {
+    let mut out = AnswerCheck::default();
+    for day in (1..=12).filter_map(|d| load_day_from_local_storage(grade, d)) {
+        out.add(&day.check);
     }
-    if p.contains('÷') || p.contains(" per ") || p.contains(" each ") {
-        return Skill::Division;
-    }
-
-    Skill::Unknown
+    out
 }
 
-fn skill_label(s: Skill) -> &'static str {
-    match s {
-        Skill::Addition => "Addition",
-        Skill::Subtraction => "Subtraction",
-        Skill::Multiplication => "Multiplication",
-        Skill::Division => "Division",
-        Skill::WordProblem => "Word Problems",
-        Skill::Unknown => "Mixed Skills",
-    }
+fn answer_check_label(c: &AnswerCheck) -> String {
+    format!(
+        "{} confirmed • {} dropped (answer key didn't work out) • {} couldn't be checked",
+        c.confirmed, c.dropped, c.unchecked
+    )
 }
 
 // ----------------------------
@@ -323,16 +442,6 @@ fn badge_for_pct_i32(pct: i32) -> &'static str {
     }
 }
 
-fn badge_for_accuracy_opt(a: Option<f32>) -> &'static str {
-    match a {
-        None => "⚪",
-        Some(x) => {
-            let pct = (x * 100.0).round() as i32;
-            badge_for_pct_i32(pct)
-        }
-    }
-}
-
 // ----------------------------
 // Clipboard helpers (NO web-sys feature gating)
 // ----------------------------
@@ -428,6 +537,7 @@ pub fn app() -> Html {
                 font-family: system-ui, -apple-system, Segoe UI, Roboto, sans-serif;
                 max-width: 980px; margin: 0 auto; padding: 20px;
             ">
+                <UpdateBanner />
                 <Switch<Route> render={switch} />
             </div>
         </HashRouter>
@@ -438,6 +548,35 @@ fn switch(route: Route) -> Html {
     match route {
         Route::Home => html! { <Home /> },
         Route::Day { id } => html! { <DayView id={id} /> },
+        Route::Review => html! { <ReviewView /> },
+        Route::Settings => html! { <SettingsView /> },
+        Route::Replay {
+            day,
+            grade,
+            date,
+            seed,
+        } => {
+            // Pin the questions and levels the set was served with (if the record is still kept).
+            let (pinned, served) = find_session(&load_stats(), &date, day, grade, seed)
+                .map(|r| (r.levels, r.served))
+                .unwrap_or_default();
+            let replay = Session {
+                ymd: date,
+                day_id: day,
+                grade: Grade::from_u8(grade),
+                seed,
+                pinned_levels: Some(pinned),
+                online: true,
+                served,
+            };
+            html! { <DayView id={day} replay={Some(replay)} /> }
+        }
+        Route::Worksheet {
+            day,
+            grade,
+            count,
+            seed,
+        } => html! { <WorksheetView day={day} grade={Grade::from_u8(grade)} count={count} seed={seed} /> },
         Route::NotFound => html! { <h2>{"Not found"}</h2> },
     }
 }
@@ -454,18 +593,68 @@ struct SoundFlagProps {
 #[function_component(SoundFlag)]
 fn sound_flag(props: &SoundFlagProps) -> Html {
     use_effect_with(props.enabled, move |enabled| {
-        if let Some(win) = web_sys::window() {
-            if let Ok(Some(storage)) = win.local_storage() {
-                let _ =
-                    storage.set_item("the_numbers_sound_v1", if *enabled { "1" } else { "0" });
-            }
-        }
+        set_sound_enabled(*enabled);
         || ()
     });
 
     html! {}
 }
 
+// ----------------------------
+// File download / upload helpers (NO web-sys feature gating)
+// ----------------------------
+
+/// Save `text` as a file via a temporary <a download> with a data: URL.
+fn download_text_file(filename: &str, mime: &str, text: &str) -> bool {
+    use js_sys::{Function, Reflect};
+
+    let Some(win) = web_sys::window() else { return false; };
+    let Some(doc) = win.document() else { return false; };
+    let Ok(a) = doc.create_element("a") else { return false; };
+
+    let href = format!(
+        "data:{};charset=utf-8,{}",
+        mime,
+        String::from(js_sys::encode_uri_component(text))
+    );
+    let _ = a.set_attribute("href", &href);
+    let _ = a.set_attribute("download", filename);
+    let _ = a.set_attribute("style", "display:none;");
+
+    let Some(body) = doc.body() else { return false; };
+    let _ = body.append_child(&a);
+
+    let click = Reflect::get(&a, &wasm_bindgen::JsValue::from_str("click")).ok();
+    let ok = match click {
+        Some(f) if f.is_function() => {
+            let f: Function = f.unchecked_into();
+            f.call0(&a).is_ok()
+        }
+        _ => false,
+    };
+
+    let _ = body.remove_child(&a);
+    ok
+}
+
+/// Text of the first file picked in an <input type="file"> (File.text() via reflection).
+async fn read_first_file(input: &web_sys::HtmlInputElement) -> Option<String> {
+    use js_sys::{Function, Promise, Reflect};
+
+    let files = Reflect::get(input, &wasm_bindgen::JsValue::from_str("files")).ok()?;
+    let file = Reflect::get_u32(&files, 0).ok()?;
+    if file.is_undefined() || file.is_null() {
+        return None;
+    }
+    let text = Reflect::get(&file, &wasm_bindgen::JsValue::from_str("text")).ok()?;
+    if !text.is_function() {
+        return None;
+    }
+    let f: Function = text.unchecked_into();
+    let promise: Promise = f.call0(&file).ok()?.unchecked_into();
+    wasm_bindgen_futures::JsFuture::from(promise).await.ok()?.as_string()
+}
+
 // ----------------------------
 // Parent Summary (weekly breakdown) ✅ ACTIONABLE + COPY REPORT + COPY TEACHER NOTE
 // (MUST be a component to use hooks)
@@ -479,16 +668,12 @@ fn pct_label_from(sc: &stats::SkillCount) -> String {
 }
 
 fn total_attempts_for_week(t: &stats::DayCounts) -> u32 {
-    t.addition.attempts
-        + t.subtraction.attempts
-        + t.multiplication.attempts
-        + t.division.attempts
-        + t.word.attempts
-        + t.mixed.attempts
+    t.total().attempts
 }
 
 #[function_component(ParentSummaryPanel)]
 fn parent_summary_panel() -> Html {
+    let learner = load_profiles().active_profile().name;
     let weekly = load_stats();
     let last7 = last_n_days(&weekly, 7);
     let totals = sum_days(&last7);
@@ -498,6 +683,12 @@ fn parent_summary_panel() -> Html {
     let copied_weekly_toast = use_state(|| false);
     let copied_teacher_toast = use_state(|| false);
 
+    // CSV export range (inclusive YYYY-MM-DD); defaults to the same 7 days as the summary
+    let today = today_ymd_local();
+    let csv_from = use_state(|| last7.last().map(|(ymd, _)| ymd.clone()).unwrap_or_else(|| today.clone()));
+    let csv_to = use_state(|| today.clone());
+    let csv_msg = use_state(|| None::<String>);
+
     // auto-hide copied weekly toast
     {
         let copied_weekly_toast = copied_weekly_toast.clone();
@@ -540,13 +731,146 @@ fn parent_summary_panel() -> Html {
         });
     }
 
+    let file_tag = {
+        let slug: String = learner
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect();
+        format!("{}-{}-to-{}", slug, *csv_from, *csv_to)
+    };
+
+    let on_csv_skills = {
+        let csv_from = csv_from.clone();
+        let csv_to = csv_to.clone();
+        let csv_msg = csv_msg.clone();
+        let file_tag = file_tag.clone();
+        Callback::from(move |_| {
+            let days = days_in_range(&load_stats(), &csv_from, &csv_to);
+            if days.is_empty() {
+                csv_msg.set(Some("No practice in that date range.".to_string()));
+                return;
+            }
+            let ok = download_text_file(
+                &format!("the-numbers-skills-{}.csv", file_tag),
+                "text/csv",
+                &skill_csv(&days),
+            );
+            csv_msg.set((!ok).then(|| "Couldn't create the download in this browser.".to_string()));
+        })
+    };
+
+    let on_csv_attempts = {
+        let csv_from = csv_from.clone();
+        let csv_to = csv_to.clone();
+        let csv_msg = csv_msg.clone();
+        Callback::from(move |_| {
+            let Some(csv) = attempts_csv(&load_attempts(), &csv_from, &csv_to) else {
+                csv_msg.set(Some("No answer-by-answer history in that date range.".to_string()));
+                return;
+            };
+            let ok = download_text_file(&format!("the-numbers-attempts-{}.csv", file_tag), "text/csv", &csv);
+            csv_msg.set((!ok).then(|| "Couldn't create the download in this browser.".to_string()));
+        })
+    };
+
+    let range_preset = |label: &'static str, days_back: Option<i64>| {
+        let csv_from = csv_from.clone();
+        let csv_to = csv_to.clone();
+        let today = today.clone();
+        let first = weekly.days.iter().map(|(d, _)| d.clone()).min().unwrap_or_else(|| today.clone());
+        html! {
+            <button
+                onclick={Callback::from(move |_| {
+                    csv_from.set(match days_back {
+                        Some(n) => add_days(&today, -(n - 1)),
+                        None => first.clone(),
+                    });
+                    csv_to.set(today.clone());
+                })}
+                style="padding:6px 8px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer; font-size: 13px;"
+            >
+                {label}
+            </button>
+        }
+    };
+
+    // Shown even in a quiet week: older practice can still be exported.
+    let export_panel = html! {
+        <div style="margin-top: 12px; padding: 10px 12px; border-radius: 12px; border: 1px solid #eee; background: rgba(0,0,0,.02);">
+            <div style="font-weight:900;">{"📤 Export for spreadsheets / LMS (CSV)"}</div>
+            <div style="margin-top: 8px; display:flex; gap:8px; flex-wrap:wrap; align-items:center;">
+                <label style="font-size: 13px;">
+                    {"From "}
+                    <input
+                        type="date"
+                        value={(*csv_from).clone()}
+                        max={(*csv_to).clone()}
+                        onchange={{
+                            let csv_from = csv_from.clone();
+                            Callback::from(move |e: Event| {
+                                let v = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                                if !v.is_empty() {
+                                    csv_from.set(v);
+                                }
+                            })
+                        }}
+                    />
+                </label>
+                <label style="font-size: 13px;">
+                    {"To "}
+                    <input
+                        type="date"
+                        value={(*csv_to).clone()}
+                        min={(*csv_from).clone()}
+                        onchange={{
+                            let csv_to = csv_to.clone();
+                            Callback::from(move |e: Event| {
+                                let v = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                                if !v.is_empty() {
+                                    csv_to.set(v);
+                                }
+                            })
+                        }}
+                    />
+                </label>
+                { range_preset("7 days", Some(7)) }
+                { range_preset("30 days", Some(30)) }
+                { range_preset("All", None) }
+            </div>
+            <div style="margin-top: 8px; display:flex; gap:10px; flex-wrap:wrap;">
+                <button
+                    onclick={on_csv_skills}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                    title="One row per date and skill: attempts, correct, accuracy"
+                >
+                    {"📊 Skills by date (CSV)"}
+                </button>
+                <button
+                    onclick={on_csv_attempts}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                    title="One row per checked answer (kept for the last 45 practice dates)"
+                >
+                    {"🧾 Every answer (CSV)"}
+                </button>
+            </div>
+            {
+                if let Some(msg) = &*csv_msg {
+                    html! { <div style="margin-top: 6px; font-size: 13px;">{msg.clone()}</div> }
+                } else {
+                    html!{}
+                }
+            }
+        </div>
+    };
+
     if week_attempts == 0 {
         return html! {
             <div style="margin: 0 0 16px; padding: 12px 14px; border:1px solid #ddd; border-radius: 14px;">
-                <div style="font-weight: 800;">{"👨‍👩‍👧 Parent Summary (Last 7 days)"}</div>
+                <div style="font-weight: 800;">{format!("👨‍👩‍👧 Parent Summary — {} (Last 7 days)", learner)}</div>
                 <div style="margin-top: 8px; opacity:.85;">
                     {"No practice attempts recorded yet this week. Once the student checks answers, this fills in automatically."}
                 </div>
+                { export_panel }
             </div>
         };
     }
@@ -560,63 +884,47 @@ fn parent_summary_panel() -> Html {
         }
     };
 
-    let total_correct = |t: &stats::DayCounts| -> u32 {
-        t.addition.correct
-            + t.subtraction.correct
-            + t.multiplication.correct
-            + t.division.correct
-            + t.word.correct
-            + t.mixed.correct
-    };
+    let total_correct = |t: &stats::DayCounts| -> u32 { t.total().correct };
+
+    // Most-missed questions over the same days (from the attempt log)
+    let week_start = last7.last().map(|(ymd, _)| ymd.clone()).unwrap_or_default();
+    let missed = most_missed(&load_attempts(), &week_start, 5);
+
+    let (review_in, review_mastered) = load_review().counts();
+    let guided = weekly.timed.iter().filter(|t| t.ymd >= week_start).count();
 
     let week_correct = total_correct(&totals);
     let week_pct = pct_label(week_attempts, week_correct);
+    let week_solves = totals.total();
+    // Misconceptions seen more than once this week ("often …").
+    let slips = totals.frequent_misconceptions(2);
+    // Online questions whose answer keys were checked before caching.
+    let keys = cached_answer_checks(load_grade());
+    let solved_label = |c: &stats::SkillCount| format!("{} on their own • {} with hints", c.solved_alone, c.solved_with_hints);
+
+    // Pull skill stats (mastery badge + pct string) for every skill practiced this week
+    let skills: Vec<(Skill, u32, u32, String, &'static str)> = totals
+        .practiced()
+        .into_iter()
+        .map(|(skill, c)| {
+            (
+                skill,
+                c.attempts,
+                c.correct,
+                pct_label_from(&c),
+                badge_for_mastery(weekly.mastery.get(&skill)),
+            )
+        })
+        .collect();
 
-    // Pull skill stats (badge + pct string)
-    let skills: Vec<(&'static str, u32, u32, String, &'static str)> = vec![
-        (
-            "Addition",
-            totals.addition.attempts,
-            totals.addition.correct,
-            pct_label_from(&totals.addition),
-            badge_for_accuracy_opt(totals.addition.accuracy()),
-        ),
-        (
-            "Subtraction",
-            totals.subtraction.attempts,
-            totals.subtraction.correct,
-            pct_label_from(&totals.subtraction),
-            badge_for_accuracy_opt(totals.subtraction.accuracy()),
-        ),
-        (
-            "Multiplication",
-            totals.multiplication.attempts,
-            totals.multiplication.correct,
-            pct_label_from(&totals.multiplication),
-            badge_for_accuracy_opt(totals.multiplication.accuracy()),
-        ),
-        (
-            "Division",
-            totals.division.attempts,
-            totals.division.correct,
-            pct_label_from(&totals.division),
-            badge_for_accuracy_opt(totals.division.accuracy()),
-        ),
-        (
-            "Word Problems",
-            totals.word.attempts,
-            totals.word.correct,
-            pct_label_from(&totals.word),
-            badge_for_accuracy_opt(totals.word.accuracy()),
-        ),
-        (
-            "Mixed Skills",
-            totals.mixed.attempts,
-            totals.mixed.correct,
-            pct_label_from(&totals.mixed),
-            badge_for_accuracy_opt(totals.mixed.accuracy()),
-        ),
-    ];
+    // (skill, attempts, mastery %) for skills with a confident mastery estimate
+    let ranked: Vec<(Skill, u32, i32)> = skills
+        .iter()
+        .filter_map(|(skill, att, _cor, _pct_str, _badge)| {
+            let m = weekly.mastery.get(skill)?;
+            m.is_confident().then(|| (*skill, *att, m.pct()))
+        })
+        .collect();
 
     // Most practiced (index into skills so we never move owned Strings)
     let most_practiced_idx: Option<usize> = skills
@@ -626,52 +934,18 @@ fn parent_summary_panel() -> Html {
         .max_by_key(|(_, (_name, att, _cor, _pct_str, _badge))| *att)
         .map(|(i, _)| i);
 
-    // Strongest (10+ attempts): highest accuracy
-    let strongest = {
-        let mut v: Vec<(&'static str, u32, u32, i32)> = skills
-            .iter()
-            .filter_map(|(name, att, cor, _pct_str, _badge)| {
-                if *att >= 10 {
-                    let pct = ((*cor as f32) / (*att as f32) * 100.0).round() as i32;
-                    Some((*name, *att, *cor, pct))
-                } else {
-                    None
-                }
-            })
-            .collect();
-        v.sort_by(|a, b| b.3.cmp(&a.3));
-        v.into_iter().next()
-    };
-
-    // Focus next (10+ attempts): lowest accuracy under 75% if possible, otherwise lowest overall
-    let focus = {
-        let mut v: Vec<(&'static str, u32, u32, i32)> = skills
-            .iter()
-            .filter_map(|(name, att, cor, _pct_str, _badge)| {
-                if *att >= 10 {
-                    let pct = ((*cor as f32) / (*att as f32) * 100.0).round() as i32;
-                    Some((*name, *att, *cor, pct))
-                } else {
-                    None
-                }
-            })
-            .collect();
+    // Strongest: highest mastery among confident estimates
+    let strongest = ranked.iter().copied().max_by_key(|x| x.2);
 
-        v.sort_by(|a, b| a.3.cmp(&b.3));
-        let below_75 = v.iter().cloned().find(|(_, _, _, pct)| *pct < 75);
-        below_75.or_else(|| v.into_iter().next())
-    };
+    // Focus next: lowest mastery among confident estimates, unless everything is mastered
+    let mastered_pct = (MASTERED * 100.0).round() as i32;
+    let focus = ranked
+        .iter()
+        .copied()
+        .min_by_key(|x| x.2)
+        .filter(|(_, _, pct)| *pct < mastered_pct);
 
-    let plan_text = |skill: &str| -> &'static str {
-        match skill {
-            "Addition" => "Do 10 quick addition facts (0–20). Say answers out loud. Repeat misses.",
-            "Subtraction" => "Do 10 subtraction facts (0–20). For tough ones, count back slowly.",
-            "Multiplication" => "Pick one table (2s/5s/10s). Do 10 facts, then 3 mixed.",
-            "Division" => "Use fact families: 12÷3, 3×4, 12÷4… keep numbers small.",
-            "Word Problems" => "Read 2 word problems. Ask: “What are we solving for?” then “Which operation?”",
-            _ => "Do 8 mixed questions. Identify the operation first, then solve.",
-        }
-    };
+    let plan_text = |skill: Skill| -> &'static str { skill.plan_text() };
 
     // Daily breakdown rows
     let day_rows = last7.iter().map(|(ymd, d)| {
@@ -680,32 +954,26 @@ fn parent_summary_panel() -> Html {
         let day_pct = pct_label(att, cor);
 
         // Best/worst skill for the DAY (3+ attempts so it’s meaningful)
-        let mut day_skill_pcts: Vec<(&'static str, u32, u32, i32)> = vec![
-            ("Addition", d.addition.attempts, d.addition.correct, 0),
-            ("Subtraction", d.subtraction.attempts, d.subtraction.correct, 0),
-            ("Multiplication", d.multiplication.attempts, d.multiplication.correct, 0),
-            ("Division", d.division.attempts, d.division.correct, 0),
-            ("Word Problems", d.word.attempts, d.word.correct, 0),
-            ("Mixed Skills", d.mixed.attempts, d.mixed.correct, 0),
-        ]
-        .into_iter()
-        .filter_map(|(name, a, c, _)| {
-            if a >= 3 && a > 0 {
-                let p = ((c as f32) / (a as f32) * 100.0).round() as i32;
-                Some((name, a, c, p))
-            } else {
-                None
-            }
-        })
-        .collect();
+        let mut day_skill_pcts: Vec<(&'static str, u32, u32, i32)> = d
+            .practiced()
+            .into_iter()
+            .filter_map(|(skill, c)| {
+                if c.attempts >= 3 {
+                    let p = ((c.correct as f32) / (c.attempts as f32) * 100.0).round() as i32;
+                    Some((skill.label(), c.attempts, c.correct, p))
+                } else {
+                    None
+                }
+            })
+            .collect();
 
-        day_skill_pcts.sort_by(|a, b| b.3.cmp(&a.3));
+        day_skill_pcts.sort_by_key(|x| std::cmp::Reverse(x.3));
         let best = day_skill_pcts
             .first()
             .map(|(n, _, _, p)| format!("🏅 {} {} ({}%)", badge_for_pct_i32(*p), n, p))
             .unwrap_or_else(|| "—".to_string());
 
-        day_skill_pcts.sort_by(|a, b| a.3.cmp(&b.3));
+        day_skill_pcts.sort_by_key(|x| x.3);
         let worst = day_skill_pcts
             .first()
             .map(|(n, _, _, p)| format!("🎯 {} {} ({}%)", badge_for_pct_i32(*p), n, p))
@@ -722,11 +990,72 @@ fn parent_summary_panel() -> Html {
         }
     });
 
+    // One row per skill practiced this week
+    let skill_rows = skills.iter().map(|(skill, att, cor, pct, badge)| {
+        html! {
+            <tr>
+                <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge, skill)}</td>
+                <td style="padding:8px 6px; text-align:right;">{*att}</td>
+                <td style="padding:8px 6px; text-align:right;">{*cor}</td>
+                <td style="padding:8px 6px; text-align:right;">{pct.clone()}</td>
+                <td style="padding:8px 6px; text-align:right; white-space:nowrap;">
+                    {
+                        match totals.skills.get(skill) {
+                            Some(c) => format!("🧠 {} • 💡 {}", c.solved_alone, c.solved_with_hints),
+                            None => "—".to_string(),
+                        }
+                    }
+                </td>
+                <td style="padding:8px 6px; text-align:right;">{mastery_label(weekly.mastery.get(skill))}</td>
+            </tr>
+        }
+    });
+
+    let missed_rows = missed.iter().map(|m| {
+        let (emoji, _) = day_tag(m.day_id);
+        html! {
+            <tr style="border-top: 1px solid rgba(0,0,0,.06);">
+                <td style="padding:8px 6px;">
+                    <div style="font-weight:700;">{format!("{} {}", emoji, m.prompt)}</div>
+                    <div style="font-size: 13px; opacity:.75;">
+                        {format!("Answer: {} • Last typed: {}", m.expected, if m.last_wrong.is_empty() { "(blank)" } else { &m.last_wrong })}
+                    </div>
+                </td>
+                <td style="padding:8px 6px; text-align:right; white-space:nowrap;">{format!("❌ {} of {}", m.misses, m.attempts)}</td>
+            </tr>
+        }
+    });
+
+    // Recent seeded sets (replayable)
+    let session_rows = recent_sessions(&weekly, 6).into_iter().map(|r| {
+        let (emoji, _) = day_tag(r.day_id);
+        html! {
+            <tr style="border-top: 1px solid rgba(0,0,0,.06);">
+                <td style="padding:8px 6px; font-weight:700;">{r.ymd.clone()}</td>
+                <td style="padding:8px 6px;">{format!("{} Day {} • Grade {}", emoji, r.day_id, r.grade)}</td>
+                <td style="padding:8px 6px; text-align:right;">
+                    {
+                        match &r.result {
+                            Some(res) => format!("🏁 {}/{} first try", res.first_try_correct, res.questions),
+                            None => format!("{} Qs • {}", r.questions, pct_label(r.attempts, r.correct)),
+                        }
+                    }
+                </td>
+                <td style="padding:8px 6px; text-align:right;">
+                    <Link<Route> to={Route::Replay { day: r.day_id, grade: r.grade, date: r.ymd.clone(), seed: r.seed }}>
+                        {"🔁 Replay"}
+                    </Link<Route>>
+                </td>
+            </tr>
+        }
+    });
+
     // Build plain-text weekly report
     let report_text = {
         let mut lines: Vec<String> = vec![];
-        lines.push("THE NUMBERS — Weekly Report (Last 7 days)".to_string());
+        lines.push(format!("THE NUMBERS — Weekly Report for {} (Last 7 days)", learner));
         lines.push(format!("Total: {} attempts • {} accuracy", week_attempts, week_pct));
+        lines.push(format!("Solved: {}", solved_label(&week_solves)));
         lines.push("".to_string());
 
         if let Some(i) = most_practiced_idx {
@@ -738,18 +1067,18 @@ fn parent_summary_panel() -> Html {
                 pct_label(*att, *cor)
             ));
         }
-        if let Some((name, att, _cor, pct)) = strongest {
+        if let Some((name, att, pct)) = strongest {
             lines.push(format!(
-                "Strongest (10+ tries): {} {} ({}% • {} tries)",
+                "Strongest: {} {} ({}% mastery • {} tries)",
                 badge_for_pct_i32(pct),
                 name,
                 pct,
                 att
             ));
         }
-        if let Some((name, att, _cor, pct)) = focus {
+        if let Some((name, att, pct)) = focus {
             lines.push(format!(
-                "Focus next (10+ tries): {} {} ({}% • {} tries)",
+                "Focus next: {} {} ({}% mastery • {} tries)",
                 badge_for_pct_i32(pct),
                 name,
                 pct,
@@ -757,13 +1086,22 @@ fn parent_summary_panel() -> Html {
             ));
             lines.push(format!("2-minute plan: {}", plan_text(name)));
         } else {
-            lines.push("Focus next: Balanced week (no clear weak spot with 10+ tries)".to_string());
+            lines.push("Focus next: Balanced week (no clear weak spot yet)".to_string());
         }
 
         lines.push("".to_string());
         lines.push("By skill:".to_string());
         for (name, att, cor, pct, badge) in skills.iter() {
-            lines.push(format!("• {} {}: {} tries, {} correct ({})", badge, name, att, cor, pct));
+            lines.push(format!(
+                "• {} {}: {} tries, {} correct ({}) • solved {} • mastery {}",
+                badge,
+                name,
+                att,
+                cor,
+                pct,
+                totals.skills.get(name).map(solved_label).unwrap_or_default(),
+                mastery_label(weekly.mastery.get(name))
+            ));
         }
 
         lines.push("".to_string());
@@ -775,6 +1113,32 @@ fn parent_summary_panel() -> Html {
             lines.push(format!("• {}: {} tries • {}", ymd, a, p));
         }
 
+        lines.push("".to_string());
+        lines.push(format!("Guided 15-minute sessions completed: {}", guided));
+        lines.push(format!("Review queue: {} in review • {} mastered", review_in, review_mastered));
+        if !keys.is_empty() {
+            lines.push(format!("Online questions: {}", answer_check_label(&keys)));
+        }
+
+        if !slips.is_empty() {
+            lines.push("".to_string());
+            lines.push("Mistake patterns:".to_string());
+            for (m, n) in slips.iter() {
+                lines.push(format!("• Often {} ({}×)", m.label(), n));
+            }
+        }
+
+        if !missed.is_empty() {
+            lines.push("".to_string());
+            lines.push("Most-missed questions:".to_string());
+            for m in missed.iter() {
+                lines.push(format!(
+                    "• {} (answer: {}) — missed {} of {}",
+                    m.prompt, m.expected, m.misses, m.attempts
+                ));
+            }
+        }
+
         lines.join("\n")
     };
 
@@ -789,6 +1153,7 @@ fn parent_summary_panel() -> Html {
 
         let mut lines: Vec<String> = vec![];
         lines.push("Teacher Note — THE NUMBERS".to_string());
+        lines.push(format!("Student: {}", learner));
         lines.push(format!("Student level: {}", g.label()));
         lines.push(format!("Date range: {}", date_range));
         lines.push("".to_string());
@@ -808,9 +1173,9 @@ fn parent_summary_panel() -> Html {
             ));
         }
 
-        if let Some((name, att, _cor, pct)) = strongest {
+        if let Some((name, att, pct)) = strongest {
             lines.push(format!(
-                "Strength: {} {} ({}% on {} tries)",
+                "Strength: {} {} ({}% mastery after {} tries)",
                 badge_for_pct_i32(pct),
                 name,
                 pct,
@@ -818,9 +1183,9 @@ fn parent_summary_panel() -> Html {
             ));
         }
 
-        if let Some((name, att, _cor, pct)) = focus {
+        if let Some((name, att, pct)) = focus {
             lines.push(format!(
-                "Focus next: {} {} ({}% on {} tries)",
+                "Focus next: {} {} ({}% mastery after {} tries)",
                 badge_for_pct_i32(pct),
                 name,
                 pct,
@@ -828,10 +1193,26 @@ fn parent_summary_panel() -> Html {
             ));
             lines.push(format!("Suggested quick practice (2 min): {}", plan_text(name)));
         } else {
-            lines.push("Focus next: Balanced week (no clear weak spot with 10+ tries)".to_string());
+            lines.push("Focus next: Balanced week (no clear weak spot yet)".to_string());
             lines.push("Suggested quick practice (2 min): Do 8 mixed questions and explain ONE answer out loud.".to_string());
         }
 
+        if !slips.is_empty() {
+            lines.push("".to_string());
+            lines.push("Mistake patterns:".to_string());
+            for (m, n) in slips.iter() {
+                lines.push(format!("• Often {} ({}×)", m.label(), n));
+            }
+        }
+
+        if keys.dropped > 0 {
+            lines.push("".to_string());
+            lines.push(format!(
+                "Online questions: {} removed before practice because their answer key didn't work out.",
+                keys.dropped
+            ));
+        }
+
         lines.push("".to_string());
         lines.push("Skill snapshot:".to_string());
         for (name, att, cor, pct, badge) in skills.iter() {
@@ -868,12 +1249,12 @@ fn parent_summary_panel() -> Html {
         <div style="margin: 0 0 16px; padding: 12px 14px; border:1px solid #ddd; border-radius: 14px;">
             <div style="display:flex; justify-content:space-between; align-items:flex-start; gap: 10px; flex-wrap:wrap;">
                 <div>
-                    <div style="font-weight: 900;">{"👨‍👩‍👧 Parent Summary (Last 7 days)"}</div>
+                    <div style="font-weight: 900;">{format!("👨‍👩‍👧 Parent Summary — {} (Last 7 days)", learner)}</div>
                     <div style="margin-top: 6px; opacity:.85;">
                         {format!("{} attempts • {} accuracy", week_attempts, week_pct)}
                     </div>
                     <div style="margin-top: 6px; font-size: 13px; opacity:.75;">
-                        {"Badges: 🟢 strong • 🟡 improving • 🔴 focus next"}
+                        {"Badges track mastery: 🟢 mastered • 🟡 improving • 🔴 focus next • ⚪ not enough tries yet"}
                     </div>
                 </div>
 
@@ -932,29 +1313,29 @@ fn parent_summary_panel() -> Html {
                 }
 
                 {
-                    if let Some((name, att, _cor, pct)) = strongest {
+                    if let Some((name, att, pct)) = strongest {
                         html!{
                             <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
                                 <div style="font-weight:900;">{"🏅 Strongest"}</div>
-                                <div style="margin-top:4px;">{format!("{} {} ({}% • {} tries)", badge_for_pct_i32(pct), name, pct, att)}</div>
+                                <div style="margin-top:4px;">{format!("{} {} ({}% mastery • {} tries)", badge_for_pct_i32(pct), name, pct, att)}</div>
                             </div>
                         }
                     } else { html!{} }
                 }
 
                 {
-                    if let Some((name, att, _cor, pct)) = focus {
+                    if let Some((name, att, pct)) = focus {
                         html!{
                             <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(255,165,0,.12);">
                                 <div style="font-weight:900;">{"🎯 Focus next"}</div>
-                                <div style="margin-top:4px;">{format!("{} {} ({}% • {} tries)", badge_for_pct_i32(pct), name, pct, att)}</div>
+                                <div style="margin-top:4px;">{format!("{} {} ({}% mastery • {} tries)", badge_for_pct_i32(pct), name, pct, att)}</div>
                             </div>
                         }
                     } else {
                         html!{
                             <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,128,0,.10);">
                                 <div style="font-weight:900;">{"✅ Balanced week"}</div>
-                                <div style="margin-top:4px;">{"No clear weak spot (10+ tries) — nice!"}</div>
+                                <div style="margin-top:4px;">{"No clear weak spot yet — nice!"}</div>
                             </div>
                         }
                     }
@@ -965,7 +1346,7 @@ fn parent_summary_panel() -> Html {
                 <div style="font-weight:900;">{"🧭 Do this next (2 minutes)"}</div>
                 <div style="margin-top:6px; opacity:.9;">
                     {
-                        if let Some((name, _, _, _)) = focus {
+                        if let Some((name, _, _)) = focus {
                             plan_text(name).to_string()
                         } else {
                             "Do 8 mixed questions and explain ONE answer out loud.".to_string()
@@ -1001,108 +1382,321 @@ fn parent_summary_panel() -> Html {
                             <th style="padding:6px; text-align:right;">{"Attempts"}</th>
                             <th style="padding:6px; text-align:right;">{"Correct"}</th>
                             <th style="padding:6px; text-align:right;">{"Accuracy"}</th>
+                            <th style="padding:6px; text-align:right;" title="Solved on their own • solved after a hint">{"Solved"}</th>
+                            <th style="padding:6px; text-align:right;">{"Mastery"}</th>
                         </tr>
                     </thead>
                     <tbody>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.addition.accuracy()), "Addition")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.addition.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.addition.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.addition)}</td>
-                        </tr>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.subtraction.accuracy()), "Subtraction")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.subtraction.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.subtraction.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.subtraction)}</td>
-                        </tr>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.multiplication.accuracy()), "Multiplication")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.multiplication.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.multiplication.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.multiplication)}</td>
-                        </tr>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.division.accuracy()), "Division")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.division.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.division.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.division)}</td>
-                        </tr>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.word.accuracy()), "Word Problems")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.word.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.word.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.word)}</td>
-                        </tr>
-                        <tr>
-                            <td style="padding:8px 6px; font-weight:700;">{format!("{} {}", badge_for_accuracy_opt(totals.mixed.accuracy()), "Mixed Skills")}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.mixed.attempts}</td>
-                            <td style="padding:8px 6px; text-align:right;">{totals.mixed.correct}</td>
-                            <td style="padding:8px 6px; text-align:right;">{pct_label_from(&totals.mixed)}</td>
-                        </tr>
+                        { for skill_rows }
                     </tbody>
                 </table>
             </div>
 
-            <div style="margin-top: 10px; font-size: 13px; opacity:.75;">
-                {"Parent tip: ask your student to explain ONE answer out loud. That’s where understanding locks in."}
+            {
+                if missed.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <>
+                            <div style="margin-top: 12px; font-weight:900;">{"❗ Most-missed questions"}</div>
+                            <div style="margin-top: 8px; overflow-x:auto;">
+                                <table style="width:100%; border-collapse: collapse;">
+                                    <tbody>
+                                        { for missed_rows }
+                                    </tbody>
+                                </table>
+                            </div>
+                        </>
+                    }
+                }
+            }
+
+            <div style="margin-top: 12px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                <span style="font-weight:900;">{"🗂️ Review queue: "}</span>
+                {format!("{} in review • {} mastered", review_in, review_mastered)}
             </div>
-        </div>
-    }
-}
 
-// ----------------------------
-// Home
-// ----------------------------
+            <div style="margin-top: 8px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                <span style="font-weight:900;">{"⏱️ Guided sessions: "}</span>
+                {format!("{} completed", guided)}
+            </div>
 
-#[function_component(Home)]
-fn home() -> Html {
-    let progress = use_state(load_progress);
+            <div style="margin-top: 8px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                <span style="font-weight:900;">{"🧠 Solved: "}</span>
+                {solved_label(&week_solves)}
+            </div>
+
+            {
+                if keys.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div style="margin-top: 8px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                            <span style="font-weight:900;">{"🛡️ Online answer keys: "}</span>
+                            {answer_check_label(&keys)}
+                        </div>
+                    }
+                }
+            }
+
+            {
+                if slips.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div style="margin-top: 8px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(255,165,0,.10);">
+                            <div style="font-weight:900;">{"🧩 Mistake patterns"}</div>
+                            { for slips.iter().map(|(m, n)| html! {
+                                <div style="margin-top: 4px;">{format!("Often {} ({}×)", m.label(), n)}</div>
+                            }) }
+                        </div>
+                    }
+                }
+            }
+
+            <div style="margin-top: 12px; font-weight:900;">{"🔁 Recent sets"}</div>
+            <div style="margin-top: 4px; font-size: 13px; opacity:.75;">
+                {"Each set is seeded, so Replay serves the exact same questions in the same order."}
+            </div>
+
+            <div style="margin-top: 8px; overflow-x:auto;">
+                <table style="width:100%; border-collapse: collapse;">
+                    <tbody>
+                        { for session_rows }
+                    </tbody>
+                </table>
+            </div>
+
+            { export_panel }
+
+            <div style="margin-top: 10px; font-size: 13px; opacity:.75;">
+                {"Parent tip: ask your student to explain ONE answer out loud. That’s where understanding locks in."}
+            </div>
+        </div>
+    }
+}
+
+// ----------------------------
+// Backup & restore (one learner, one JSON file)
+// ----------------------------
+
+#[derive(Properties, PartialEq)]
+struct BackupPanelProps {
+    learner_name: String,
+    /// Fired after an import is written so Home can reload the learner.
+    on_imported: Callback<()>,
+}
+
+#[function_component(BackupPanel)]
+fn backup_panel(props: &BackupPanelProps) -> Html {
+    let pending = use_state(|| None::<Backup>);
+    let mode = use_state(|| ImportMode::Merge);
+    let message = use_state(|| None::<String>);
+
+    let on_export = {
+        let message = message.clone();
+        let name = props.learner_name.clone();
+        Callback::from(move |_| {
+            let today = today_ymd_local();
+            let slug: String = name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+                .collect();
+            let ok = export_backup(&name, &today)
+                .map(|json| {
+                    download_text_file(
+                        &format!("the-numbers-{}-{}.json", slug, today),
+                        "application/json",
+                        &json,
+                    )
+                })
+                .unwrap_or(false);
+            message.set(Some(if ok {
+                "⬇️ Backup downloaded.".to_string()
+            } else {
+                "Couldn't create the download in this browser.".to_string()
+            }));
+        })
+    };
+
+    let on_file = {
+        let pending = pending.clone();
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            let pending = pending.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                let Some(raw) = read_first_file(&input).await else {
+                    message.set(Some("Couldn't read that file.".to_string()));
+                    return;
+                };
+                input.set_value("");
+                match parse_backup(&raw) {
+                    Ok(b) => {
+                        message.set(None);
+                        pending.set(Some(b));
+                    }
+                    Err(err) => {
+                        pending.set(None);
+                        message.set(Some(format!("❌ {}", err)));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_apply = {
+        let pending = pending.clone();
+        let message = message.clone();
+        let mode = *mode;
+        let on_imported = props.on_imported.clone();
+        Callback::from(move |_| {
+            if let Some(b) = &*pending {
+                apply_import(b, mode);
+                pending.set(None);
+                message.set(Some(match mode {
+                    ImportMode::Merge => "✅ Backup merged.".to_string(),
+                    ImportMode::Replace => "✅ Data replaced from backup.".to_string(),
+                }));
+                on_imported.emit(());
+            }
+        })
+    };
+
+    let mode_button = |m: ImportMode, label: &'static str| {
+        let mode = mode.clone();
+        let active = *mode == m;
+        html! {
+            <button
+                onclick={Callback::from(move |_| mode.set(m))}
+                style={format!(
+                    "padding:8px 10px; border-radius:10px; border:1px solid {}; background:{}; cursor:pointer; font-weight:{};",
+                    if active { "#222" } else { "#ddd" },
+                    if active { "rgba(0,0,0,.06)" } else { "#fff" },
+                    if active { 800 } else { 400 }
+                )}
+            >
+                {label}
+            </button>
+        }
+    };
+
+    html! {
+        <div style="margin: 0 0 16px; padding: 12px 14px; border:1px solid #ddd; border-radius: 14px;">
+            <div style="font-weight: 800;">{format!("💾 Backup & restore — {}", props.learner_name)}</div>
+            <div style="margin-top: 4px; font-size: 13px; opacity:.75;">
+                {"Progress lives in this browser only. Export a backup before clearing site data or switching devices."}
+            </div>
+
+            <div style="margin-top: 10px; display:flex; gap:10px; flex-wrap:wrap; align-items:center;">
+                <button
+                    onclick={on_export}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                >
+                    {"⬇️ Export data"}
+                </button>
+                <label style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;">
+                    {"⬆️ Import data"}
+                    <input type="file" accept=".json,application/json" onchange={on_file} style="display:none;" />
+                </label>
+            </div>
+
+            {
+                if let Some(b) = &*pending {
+                    let current = load_learner_data();
+                    html! {
+                        <div style="margin-top: 10px; padding: 10px 12px; border-radius: 12px; border: 1px solid #eee; background: rgba(0,0,0,.02);">
+                            <div style="display:flex; gap:8px; flex-wrap:wrap;">
+                                { mode_button(ImportMode::Merge, "Merge with this device") }
+                                { mode_button(ImportMode::Replace, "Replace this device's data") }
+                            </div>
+                            <ul style="margin: 8px 0 0; padding-left: 18px;">
+                                { for preview(&current, b, *mode).into_iter().map(|l| html!{ <li>{l}</li> }) }
+                            </ul>
+                            <div style="margin-top: 8px; display:flex; gap:10px;">
+                                <button
+                                    onclick={on_apply}
+                                    style="padding:8px 10px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+                                >
+                                    {"Apply import"}
+                                </button>
+                                <button
+                                    onclick={{
+                                        let pending = pending.clone();
+                                        Callback::from(move |_| pending.set(None))
+                                    }}
+                                    style="padding:8px 10px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                                >
+                                    {"Cancel"}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html!{}
+                }
+            }
+
+            {
+                if let Some(msg) = &*message {
+                    html! { <div style="margin-top: 8px;">{msg.clone()}</div> }
+                } else {
+                    html!{}
+                }
+            }
+        </div>
+    }
+}
+
+// ----------------------------
+// Home
+// ----------------------------
+
+#[function_component(Home)]
+fn home() -> Html {
+    let profiles = use_state(load_profiles);
+    let progress = use_state(load_progress);
     let grade = use_state(load_grade);
 
     let toast = use_state(|| None::<String>);
-    let sound_on = use_state(|| is_sound_enabled());
+    // Days whose online set couldn't be loaded on the last prefetch, and why.
+    let fetch_problems = use_state(Vec::<(usize, WorkerError)>::new);
+    let sound_on = use_state(is_sound_enabled);
+    let new_profile_name = use_state(|| "".to_string());
 
-    // Prefetch all 12 days in background whenever grade changes (best effort)
+    // Prefetch all 12 days in background whenever grade or learner changes (best effort)
     {
         let toast = toast.clone();
+        let fetch_problems = fetch_problems.clone();
         let g = *grade;
 
-        use_effect_with(g, move |_| {
+        use_effect_with((g, profiles.active.clone()), move |_| {
             let today = today_ymd_local();
-
-            spawn_local(async move {
-                let mut any_ai_success = false;
-
-                for day_id in 1..=12 {
-                    let cached = load_day_from_local_storage(g, day_id);
-                    let needs = match &cached {
-                        Some(d) => d.date_ymd != today,
-                        None => true,
-                    };
-                    if !needs {
-                        continue;
+            // Switching grade or learner cancels this run before its answers are cached.
+            let cancel = Cancel::default();
+
+            spawn_local({
+                let cancel = cancel.clone();
+                async move {
+                    let config = ready_config().await;
+                    if !config.may_fetch() || config.prefetch != Prefetch::AllDays {
+                        return;
                     }
+                    let days: Vec<usize> = DAYS.iter().map(|d| d.id).collect();
+                    let Some(outcome) = prefetch(&config, g, &days, &today, true, &cancel).await else {
+                        return;
+                    };
 
-                    match fetch_day(WORKER_BASE_URL, g, day_id).await {
-                        Ok(day) => {
-                            save_day_to_local_storage(g, day_id, &day);
-                            if day.source == "ai" {
-                                any_ai_success = true;
-                            }
-                        }
-                        Err(_) => {
-                            // Worker down/offline; local fallback still works.
-                        }
+                    // Local questions cover the missing days; the status area says why.
+                    fetch_problems.set(outcome.problems);
+                    if outcome.fresh_ai {
+                        toast.set(Some("Fresh questions loaded ✅".to_string()));
                     }
                 }
-
-                if any_ai_success {
-                    toast.set(Some("Fresh questions loaded ✅".to_string()));
-                }
             });
 
-            || ()
+            move || cancel.cancel()
         });
     }
 
@@ -1129,8 +1723,57 @@ fn home() -> Html {
 
     let completed_count = progress.completed.len();
     let all_done = completed_count >= DAYS.len();
+    let review_due = load_review().due(&today_ymd_local()).len();
     let pct = ((completed_count as f32) / (DAYS.len() as f32) * 100.0).round() as i32;
 
+    // Everything on Home that belongs to the active learner.
+    let reload_learner = {
+        let profiles = profiles.clone();
+        let progress = progress.clone();
+        let grade = grade.clone();
+        let sound_on = sound_on.clone();
+        Callback::from(move |_: ()| {
+            profiles.set(load_profiles());
+            progress.set(load_progress());
+            grade.set(load_grade());
+            sound_on.set(is_sound_enabled());
+        })
+    };
+
+    let on_profile_change = {
+        let reload_learner = reload_learner.clone();
+        Callback::from(move |e: Event| {
+            let id = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            set_active_profile(&id);
+            reload_learner.emit(());
+        })
+    };
+
+    let on_add_profile = {
+        let reload_learner = reload_learner.clone();
+        let new_profile_name = new_profile_name.clone();
+        Callback::from(move |_| {
+            add_profile(&new_profile_name);
+            new_profile_name.set("".to_string());
+            reload_learner.emit(());
+        })
+    };
+
+    let on_remove_profile = {
+        let reload_learner = reload_learner.clone();
+        let active = profiles.active_profile();
+        Callback::from(move |_| {
+            let msg = format!("Remove {} and all of their progress on this device?", active.name);
+            let ok = web_sys::window()
+                .and_then(|w| w.confirm_with_message(&msg).ok())
+                .unwrap_or(false);
+            if ok {
+                remove_profile(&active.id);
+                reload_learner.emit(());
+            }
+        })
+    };
+
     let on_grade_change = {
         let grade = grade.clone();
         Callback::from(move |e: Event| {
@@ -1177,6 +1820,49 @@ fn home() -> Html {
                 }
             }
 
+            <div style="display:flex; gap:10px; align-items:center; flex-wrap: wrap; margin: 10px 0 0;">
+                <label style="display:flex; align-items:center; gap:8px; padding:6px 10px; border:1px solid #ddd; border-radius:999px;">
+                    <span>{"👤 Learner:"}</span>
+                    <select onchange={on_profile_change} style="padding:6px 8px; border-radius:10px; border:1px solid #ddd;">
+                        { for profiles.list.iter().map(|p| html!{
+                            <option value={p.id.clone()} selected={p.id == profiles.active}>{p.name.clone()}</option>
+                        }) }
+                    </select>
+                </label>
+
+                <input
+                    value={(*new_profile_name).clone()}
+                    oninput={{
+                        let new_profile_name = new_profile_name.clone();
+                        Callback::from(move |e: InputEvent| {
+                            new_profile_name.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+                        })
+                    }}
+                    placeholder="New learner name"
+                    style="padding:8px 10px; border:1px solid #ddd; border-radius:10px; min-width: 140px;"
+                />
+                <button
+                    onclick={on_add_profile}
+                    style="padding:8px 10px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                    title="Add a learner with their own grade, progress and stats"
+                >
+                    {"➕ Add learner"}
+                </button>
+                {
+                    if profiles.list.len() > 1 {
+                        html!{
+                            <button
+                                onclick={on_remove_profile}
+                                style="padding:8px 10px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                                title="Remove this learner and their data from this device"
+                            >
+                                {"🗑️ Remove"}
+                            </button>
+                        }
+                    } else { html!{} }
+                }
+            </div>
+
             <div style="display:flex; gap:12px; align-items:center; flex-wrap: wrap; margin: 10px 0 12px;">
                 <span style="padding:6px 10px; border:1px solid #ddd; border-radius:999px;">
                     {format!("✅ Completed: {}/{}", completed_count, DAYS.len())}
@@ -1218,6 +1904,8 @@ fn home() -> Html {
                 >
                     { if *sound_on { "🔊 Sound: On" } else { "🔇 Sound: Off" } }
                 </button>
+
+                <Link<Route> to={Route::Settings}>{"⚙️ Settings"}</Link<Route>>
             </div>
 
             <div style="margin: 0 0 16px;">
@@ -1232,7 +1920,29 @@ fn home() -> Html {
                 </div>
             </div>
 
-            <ParentSummaryPanel />
+            {
+                if review_due > 0 {
+                    html! {
+                        <div style="margin: 0 0 16px;">
+                            <Link<Route> to={Route::Review}>
+                                <span style="display:inline-block; padding:10px 14px; border-radius:10px; border:1px solid #222; background:#fff; color:#222;">
+                                    {format!("🗂️ Review ({} due)", review_due)}
+                                </span>
+                            </Link<Route>>
+                        </div>
+                    }
+                } else {
+                    html!{}
+                }
+            }
+
+            <ParentSummaryPanel key={profiles.active.clone()} />
+
+            <BackupPanel
+                key={profiles.active.clone()}
+                learner_name={profiles.active_profile().name}
+                on_imported={reload_learner.clone()}
+            />
 
             { if all_done { celebration_banner() } else { html!{} } }
 
@@ -1240,8 +1950,16 @@ fn home() -> Html {
                 { for DAYS.iter().map(|d| day_card(d, &progress)) }
             </div>
 
+            { fetch_status(&fetch_problems) }
+
             <div style="margin-top: 14px; opacity:.7; font-size: 13px;">
-                {"Daily refresh + caching enabled. If the AI service is flaky, the app still runs on built-in questions."}
+                {
+                    if current_config().may_fetch() {
+                        "Daily refresh + caching enabled. If the AI service is flaky, the app still runs on built-in questions."
+                    } else {
+                        "Offline mode: built-in questions only, nothing is fetched."
+                    }
+                }
             </div>
 
             <SoundFlag enabled={*sound_on} />
@@ -1249,6 +1967,36 @@ fn home() -> Html {
     }
 }
 
+/// Which days fell back to built-in questions, grouped by reason.
+fn fetch_status(problems: &[(usize, WorkerError)]) -> Html {
+    if problems.is_empty() {
+        return html! {};
+    }
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (day_id, e) in problems {
+        // One "paused" line, however many days it covered.
+        let msg = match e {
+            WorkerError::CircuitOpen { .. } => "paused after repeated failures".to_string(),
+            e => e.message(),
+        };
+        match groups.iter_mut().find(|(m, _)| *m == msg) {
+            Some((_, days)) => days.push(*day_id),
+            None => groups.push((msg, vec![*day_id])),
+        }
+    }
+
+    html! {
+        <div role="status" style="margin-top: 14px; padding: 10px 12px; border:1px solid #f0d58a; background:#fffbea; border-radius: 12px; font-size: 13px;">
+            <div style="font-weight:600;">{"📡 Some online questions didn't load — those days use built-in questions."}</div>
+            { for groups.iter().map(|(msg, days)| {
+                let days = days.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ");
+                let label = if days.contains(',') { "Days" } else { "Day" };
+                html!{ <div style="margin-top: 4px;">{format!("• {} {}: {}", label, days, msg)}</div> }
+            }) }
+        </div>
+    }
+}
+
 fn day_card(day: &Day, progress: &UseStateHandle<Progress>) -> Html {
     let done = progress.completed.contains(&day.id);
     let (emoji, label) = day_tag(day.id);
@@ -1290,12 +2038,18 @@ fn day_card(day: &Day, progress: &UseStateHandle<Progress>) -> Html {
 #[derive(Properties, PartialEq)]
 struct DayViewProps {
     id: usize,
+    /// Replay a recorded set instead of today's.
+    #[prop_or_default]
+    replay: Option<Session>,
 }
 
 #[function_component(DayView)]
 fn day_view(props: &DayViewProps) -> Html {
     let progress = use_state(load_progress);
-    let grade = load_grade();
+    let grade = match &props.replay {
+        Some(r) => r.grade,
+        None => load_grade(),
+    };
 
     let day = DAYS.iter().find(|d| d.id == props.id).cloned();
     if day.is_none() {
@@ -1307,30 +2061,65 @@ fn day_view(props: &DayViewProps) -> Html {
     let done = progress.completed.contains(&day.id);
 
     let show_edu = use_state(|| false);
+    let run = use_state(load_timed);
 
-    let mark_done = {
-        let progress = progress.clone();
-        let id = day.id;
-        Callback::from(move |_| {
-            let mut p = (*progress).clone();
-            if !p.completed.contains(&id) {
-                p.completed.push(id);
+    // Compute skills for today from cached AI (if fresh)
+    let today = today_ymd_local();
+    let cached = load_day_from_local_storage(grade, day.id);
+
+    // Fetch this day's set if it isn't cached for today (the "on open" prefetch policy,
+    // or a direct link that skipped Home). The set on screen keeps the questions it
+    // started with; the next set picks the new ones up.
+    {
+        let stale = props.replay.is_none() && cached.as_ref().is_none_or(|d| d.date_ymd != today);
+        let today = today.clone();
+        use_effect_with((day.id, grade), move |(day_id, grade)| {
+            let (day_id, grade) = (*day_id, *grade);
+            let cancel = Cancel::default();
+            if stale {
+                let cancel = cancel.clone();
+                spawn_local(async move {
+                    let config = ready_config().await;
+                    let _ = prefetch(&config, grade, &[day_id], &today, false, &cancel).await;
+                });
             }
-            save_progress(&p);
-            progress.set(p);
+            move || cancel.cancel()
+        });
+    }
+
+    // Memoized so finishing a set (which changes the open set) doesn't remount the widget mid-summary.
+    let session = (*use_memo((day.id, grade, props.replay.clone()), {
+        let today = today.clone();
+        move |(day_id, grade, replay)| replay.clone().unwrap_or_else(|| open_set(&today, *day_id, *grade))
+    }))
+    .clone();
+
+    // A finished run for another day is just a stale summary; starting here replaces it.
+    let run_here = (*run).clone().filter(|r| r.day_id == day.id && props.replay.is_none());
+    let run_elsewhere = (*run).clone().filter(|r| r.day_id != day.id && r.phase != Phase::Done);
+
+    let on_start = {
+        let run = run.clone();
+        let session = session.clone();
+        Callback::from(move |_| {
+            let r = TimedRun::start(&session, js_sys::Date::now());
+            save_timed(&r);
+            run.set(Some(r));
         })
     };
 
-    // Compute skills for today from cached AI (if fresh)
-    let today = today_ymd_local();
-    let cached = load_day_from_local_storage(grade, day.id);
+    let on_finished = {
+        let progress = progress.clone();
+        Callback::from(move |_| progress.set(load_progress()))
+    };
 
     let skills_list: Vec<&'static str> = if let Some(d) = cached {
         if d.date_ymd == today {
             let mut skills: std::collections::BTreeSet<&'static str> =
                 std::collections::BTreeSet::new();
             for q in d.items.iter() {
-                skills.insert(skill_label(classify_skill(&q.prompt)));
+                let skill = q.skill.unwrap_or_else(|| classify_skill(&q.prompt, day.id));
+                skills.insert(skill.label());
             }
             skills.into_iter().collect()
         } else {
@@ -1359,7 +2148,11 @@ fn day_view(props: &DayViewProps) -> Html {
                     </div>
                 </div>
 
-                <div style="display:flex; gap:10px; flex-wrap:wrap;">
+                <div style="display:flex; gap:10px; flex-wrap:wrap; align-items:center;">
+                    <Link<Route> to={Route::Worksheet { day: day.id, grade: grade.as_u8(), count: DEFAULT_COUNT, seed: session.seed }}>
+                        {"🖨️ Worksheet"}
+                    </Link<Route>>
+
                     <button
                         style="padding:10px 14px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
                         onclick={{
@@ -1370,13 +2163,32 @@ fn day_view(props: &DayViewProps) -> Html {
                         { if *show_edu { "Hide Parent/Educator" } else { "Parent/Educator" } }
                     </button>
 
-                    <button
-                        style="padding:10px 14px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
-                        onclick={mark_done}
-                        disabled={done}
-                    >
-                        { if done { "Completed ✅" } else { "Mark Done" } }
-                    </button>
+                    {
+                        if done {
+                            html! {
+                                <div style="padding:10px 14px; border-radius:10px; border:1px solid #222;">
+                                    {"Completed ✅"}
+                                </div>
+                            }
+                        } else {
+                            html!{}
+                        }
+                    }
+                    {
+                        if run_here.is_none() && props.replay.is_none() {
+                            html! {
+                                <button
+                                    style="padding:10px 14px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+                                    onclick={on_start.clone()}
+                                    title="Finish a guided session to complete this day"
+                                >
+                                    { if done { "▶ Guided session again" } else { "▶ Start 15-minute session" } }
+                                </button>
+                            }
+                        } else {
+                            html!{}
+                        }
+                    }
                 </div>
             </div>
 
@@ -1407,122 +2219,1508 @@ fn day_view(props: &DayViewProps) -> Html {
 
             <hr style="margin: 18px 0;" />
 
-            <h3>{"15-minute session"}</h3>
-
-            <div style="display:grid; grid-template-columns: repeat(auto-fit, minmax(260px, 1fr)); gap: 14px;">
-                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
-                    <div style="font-weight:700;">{"1) Learn (3 min)"}</div>
-                    <p style="opacity:.85;">{day.learn}</p>
-                </section>
+            {
+                if props.replay.is_some() {
+                    html!{
+                        <div style="margin-bottom: 12px; padding: 10px 12px; border:1px solid #eee; border-radius: 12px; background: rgba(135,206,250,.14);">
+                            {format!("🔁 Replaying the set from {} (set #{:016x})", session.ymd, session.seed)}
+                        </div>
+                    }
+                } else {
+                    html!{}
+                }
+            }
 
-                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
-                    <div style="font-weight:700;">{"2) Practice (10 min)"}</div>
-                    <p style="opacity:.85;">{day.practice}</p>
+            {
+                if let Some(other) = &run_elsewhere {
+                    html!{
+                        <div style="margin-bottom: 12px; padding: 10px 12px; border:1px solid #eee; border-radius: 12px; background: rgba(255,165,0,.12);">
+                            {format!("⏸️ You have a guided session in progress for Day {}. ", other.day_id)}
+                            <Link<Route> to={Route::Day { id: other.day_id }}>{"Continue it"}</Link<Route>>
+                            {" — or start one here (that one will be dropped)."}
+                        </div>
+                    }
+                } else {
+                    html!{}
+                }
+            }
 
-                    <PracticeWidget
-                        key={format!("practice-{}-{}", day.id, grade.as_u8())}
-                        day_id={day.id}
-                        grade={grade}
-                    />
-                </section>
+            {
+                if let Some(r) = &run_here {
+                    html! {
+                        <SessionRunner
+                            key={format!("runner-{}-{}", r.day_id, r.started_ms)}
+                            run={run.clone()}
+                            on_finished={on_finished}
+                        />
+                    }
+                } else {
+                    html! {
+                        <>
+                            <h3>{"15-minute session"}</h3>
+                            {
+                                if props.replay.is_none() {
+                                    html! {
+                                        <div style="margin: -6px 0 12px; font-size: 13px; opacity:.75;">
+                                            {"Free practice below. Start the guided session to run the timer and complete the day."}
+                                        </div>
+                                    }
+                                } else {
+                                    html!{}
+                                }
+                            }
 
-                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
-                    <div style="font-weight:700;">{"3) Win (2 min)"}</div>
-                    <p style="opacity:.85;">{day.win}</p>
-                </section>
-            </div>
+                            <div style="display:grid; grid-template-columns: repeat(auto-fit, minmax(260px, 1fr)); gap: 14px;">
+                                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
+                                    <div style="font-weight:700;">{"1) Learn (3 min)"}</div>
+                                    <p style="opacity:.85;">{day.learn}</p>
+                                </section>
+
+                                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
+                                    <div style="font-weight:700;">{"2) Practice (10 min)"}</div>
+                                    <p style="opacity:.85;">{day.practice}</p>
+
+                                    <PracticeWidget
+                                        key={format!("practice-{}-{}-{}", day.id, grade.as_u8(), session.seed)}
+                                        day_id={day.id}
+                                        grade={grade}
+                                        session={session.clone()}
+                                    />
+                                </section>
+
+                                <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
+                                    <div style="font-weight:700;">{"3) Win (2 min)"}</div>
+                                    <p style="opacity:.85;">{day.win}</p>
+                                </section>
+                            </div>
+                        </>
+                    }
+                }
+            }
         </>
     }
 }
 
 // ----------------------------
-// Practice Widget (AI day cache first; fallback local)
+// Guided 15-minute session (Learn → Practice → Win, timed)
 // ----------------------------
 
-#[derive(Properties, PartialEq)]
-struct PracticeWidgetProps {
-    day_id: usize,
-    grade: Grade,
-}
+/// Store a finished run with the stats and complete its day.
+fn finish_timed_run(r: &TimedRun) {
+    let mut st = load_stats();
+    record_timed_session(&mut st, r.record());
+    save_stats(&st);
 
-fn normalize_answer(s: &str) -> String {
-    s.trim().to_lowercase().replace(' ', "")
+    let mut p = load_progress();
+    if !p.completed.contains(&r.day_id) {
+        p.completed.push(r.day_id);
+        save_progress(&p);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SessionRunnerProps {
+    /// Owned by DayView (it also decides what else to show around the runner).
+    run: UseStateHandle<Option<TimedRun>>,
+    /// Fired once when the run reaches the summary (the day is now complete).
+    on_finished: Callback<()>,
+}
+
+#[function_component(SessionRunner)]
+fn session_runner(props: &SessionRunnerProps) -> Html {
+    let now = use_state(js_sys::Date::now);
+
+    // 1s clock. The interval only bumps `now`; the effect below does the work
+    // with fresh state handles.
+    {
+        let now = now.clone();
+        use_effect_with((), move |_| {
+            let cb = Closure::<dyn FnMut()>::new(move || now.set(js_sys::Date::now()));
+            let win = web_sys::window();
+            let id = win.as_ref().and_then(|w| {
+                w.set_interval_with_callback_and_timeout_and_arguments_0(cb.as_ref().unchecked_ref(), 1000)
+                    .ok()
+            });
+            move || {
+                if let (Some(w), Some(id)) = (win, id) {
+                    w.clear_interval_with_handle(id);
+                }
+                drop(cb);
+            }
+        });
+    }
+
+    {
+        let run = props.run.clone();
+        let on_finished = props.on_finished.clone();
+        use_effect_with(*now, move |now| {
+            if let Some(mut r) = (*run).clone().filter(|r| r.running_since.is_some()) {
+                r.tick(*now);
+                save_timed(&r);
+                if r.phase == Phase::Done {
+                    finish_timed_run(&r);
+                    on_finished.emit(());
+                }
+                run.set(Some(r));
+            }
+            || ()
+        });
+    }
+
+    // Practice starts at the first unfinished set of the run's day; fixed for the
+    // runner's lifetime so finishing a set shows its summary instead of remounting.
+    let practice_session = use_memo((), {
+        let run = (*props.run).clone();
+        move |_| run.map(|r| open_set(&r.ymd, r.day_id, Grade::from_u8(r.grade)))
+    });
+
+    let Some(r) = (*props.run).clone() else { return html! {}; };
+    let Some(day) = DAYS.iter().find(|d| d.id == r.day_id).cloned() else { return html! {}; };
+
+    // Every button goes through here: change the stored run, save, re-render.
+    let update = {
+        let run = props.run.clone();
+        let on_finished = props.on_finished.clone();
+        move |f: fn(&mut TimedRun, f64)| {
+            let run = run.clone();
+            let on_finished = on_finished.clone();
+            Callback::from(move |_: MouseEvent| {
+                let Some(mut r) = load_timed() else { return; };
+                let was_done = r.phase == Phase::Done;
+                f(&mut r, js_sys::Date::now());
+                save_timed(&r);
+                if r.phase == Phase::Done && !was_done {
+                    finish_timed_run(&r);
+                    on_finished.emit(());
+                }
+                run.set(Some(r));
+            })
+        }
+    };
+
+    let on_check = {
+        let run = props.run.clone();
+        Callback::from(move |(index, ok): (u32, bool)| {
+            if let Some(mut r) = load_timed() {
+                r.record_check(index, ok);
+                save_timed(&r);
+                run.set(Some(r));
+            }
+        })
+    };
+
+    let on_close = {
+        let run = props.run.clone();
+        Callback::from(move |_| {
+            clear_timed();
+            run.set(None);
+        })
+    };
+
+    let on_quit = {
+        let run = props.run.clone();
+        Callback::from(move |_| {
+            let ok = web_sys::window()
+                .and_then(|w| w.confirm_with_message("End this session? It won't count as completed.").ok())
+                .unwrap_or(false);
+            if ok {
+                clear_timed();
+                run.set(None);
+            }
+        })
+    };
+
+    let accuracy = if r.attempts == 0 {
+        "—".to_string()
+    } else {
+        format!("{}%", ((r.correct as f32) / (r.attempts as f32) * 100.0).round() as i32)
+    };
+
+    if r.phase == Phase::Done {
+        let minutes = ((r.ended_ms.unwrap_or(r.last_seen_ms) - r.started_ms) / 60_000.0).round().max(1.0);
+        return html! {
+            <section style="border:1px solid #ddd; border-radius:14px; padding:16px;">
+                <div style="font-size: 20px; font-weight: 800;">{format!("🎉 Day {} session complete!", day.id)}</div>
+                <div style="margin-top: 10px; display:flex; gap:10px; flex-wrap:wrap;">
+                    <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                        {format!("⏱️ {} min", minutes)}
+                    </div>
+                    <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                        {format!("🧮 {} questions • {} checks", r.questions, r.attempts)}
+                    </div>
+                    <div style="padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                        {format!("🎯 {} accuracy", accuracy)}
+                    </div>
+                </div>
+                <div style="margin-top: 10px; opacity:.85;">{format!("Last step: {}", day.win)}</div>
+                <div style="margin-top: 12px; display:flex; gap:10px; flex-wrap:wrap; align-items:center;">
+                    <button
+                        onclick={on_close}
+                        style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                    >
+                        {"Close summary"}
+                    </button>
+                    <Link<Route> to={Route::Home}>{"← Back to all days"}</Link<Route>>
+                </div>
+            </section>
+        };
+    }
+
+    let remaining = r.remaining_ms(*now);
+    let total = r.phase.duration_ms();
+    let done_pct = if total > 0.0 { ((total - remaining) / total * 100.0).clamp(0.0, 100.0) } else { 100.0 };
+
+    let body = match r.phase {
+        Phase::Learn => html! { <p style="font-size: 18px; line-height: 1.5;">{day.learn}</p> },
+        Phase::Practice if r.is_paused() => html! {
+            <p style="opacity:.85;">{"⏸️ Paused — press Resume to keep practicing."}</p>
+        },
+        Phase::Practice => html! {
+            <>
+                <p style="opacity:.85;">{day.practice}</p>
+                {
+                    if let Some(ps) = &*practice_session {
+                        html! {
+                            <PracticeWidget
+                                key={format!("timed-practice-{}-{}", r.day_id, ps.seed)}
+                                day_id={r.day_id}
+                                grade={Grade::from_u8(r.grade)}
+                                session={ps.clone()}
+                                on_check={Some(on_check)}
+                            />
+                        }
+                    } else {
+                        html!{}
+                    }
+                }
+                <div style="margin-top: 10px; font-size: 13px; opacity:.75;">
+                    {format!("So far: {} questions • {} correct of {} checks", r.questions, r.correct, r.attempts)}
+                </div>
+            </>
+        },
+        Phase::Win | Phase::Done => html! { <p style="font-size: 18px; line-height: 1.5;">{day.win}</p> },
+    };
+
+    html! {
+        <section style="border:1px solid #ddd; border-radius:14px; padding:16px;">
+            <div style="display:flex; justify-content:space-between; align-items:center; gap:12px; flex-wrap:wrap;">
+                <div>
+                    <div style="font-size: 13px; opacity:.75;">{format!("Guided session • Step {} of 3", r.phase.step())}</div>
+                    <div style="font-size: 20px; font-weight: 800;">{r.phase.label()}</div>
+                </div>
+                <div style="font-size: 28px; font-weight: 800; font-variant-numeric: tabular-nums;">
+                    { if r.is_paused() { format!("⏸️ {}", clock_label(remaining)) } else { clock_label(remaining) } }
+                </div>
+            </div>
+
+            <div style="margin-top: 8px; height: 8px; border-radius: 999px; background: rgba(0,0,0,.06); overflow:hidden;">
+                <div style={format!("height:100%; width:{:.1}%; background:#222; transition: width 1s linear;", done_pct)}></div>
+            </div>
+
+            <div style="margin-top: 12px;">{body}</div>
+
+            <div style="margin-top: 12px; display:flex; gap:10px; flex-wrap:wrap;">
+                {
+                    if r.is_paused() {
+                        html! {
+                            <button
+                                onclick={update(TimedRun::resume)}
+                                style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+                            >
+                                {"▶ Resume"}
+                            </button>
+                        }
+                    } else {
+                        html! {
+                            <button
+                                onclick={update(TimedRun::pause)}
+                                style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                            >
+                                {"⏸️ Pause"}
+                            </button>
+                        }
+                    }
+                }
+                {
+                    // Reading steps can end early; practice runs the full 10 minutes.
+                    match r.phase {
+                        Phase::Learn => html! {
+                            <button
+                                onclick={update(TimedRun::advance)}
+                                style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                            >
+                                {"I'm ready to practice →"}
+                            </button>
+                        },
+                        Phase::Win => html! {
+                            <button
+                                onclick={update(TimedRun::advance)}
+                                style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                            >
+                                {"Done — finish session ✅"}
+                            </button>
+                        },
+                        _ => html! {},
+                    }
+                }
+                <button
+                    onclick={on_quit}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer; margin-left:auto;"
+                >
+                    {"✖ End session"}
+                </button>
+            </div>
+        </section>
+    }
+}
+
+// ----------------------------
+// Printable worksheet + answer key
+// ----------------------------
+
+const WORKSHEET_PRINT_CSS: &str = r#"
+.ws-page { border: 1px solid #ddd; border-radius: 14px; padding: 18px 20px; margin-top: 14px; }
+.ws-item { break-inside: avoid; page-break-inside: avoid; margin-top: 14px; }
+.ws-space { margin-top: 6px; height: 56px; border: 1px dashed #bbb; border-radius: 8px; }
+@media print {
+    @page { margin: 14mm; }
+    body { background: #fff; }
+    .no-print { display: none !important; }
+    .ws-page { border: none; border-radius: 0; padding: 0; margin: 0; }
+    .ws-key { break-before: page; page-break-before: always; }
+    .ws-space { border-color: #999; }
+    a { color: inherit; text-decoration: none; }
+}
+"#;
+
+/// window.print() via reflection (no web-sys feature needed).
+fn print_page() {
+    use js_sys::{Function, Reflect};
+
+    let Some(win) = web_sys::window() else { return; };
+    if let Ok(f) = Reflect::get(&win, &wasm_bindgen::JsValue::from_str("print")) {
+        if f.is_function() {
+            let f: Function = f.unchecked_into();
+            let _ = f.call0(&win);
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct WorksheetViewProps {
+    day: usize,
+    grade: Grade,
+    count: usize,
+    seed: u64,
+}
+
+#[function_component(WorksheetView)]
+fn worksheet_view(props: &WorksheetViewProps) -> Html {
+    let navigator = use_navigator();
+
+    let Some(day) = DAYS.iter().find(|d| d.id == props.day).cloned() else {
+        return html! { <h2>{"Day not found"}</h2> };
+    };
+    let count = props.count.clamp(COUNT_CHOICES[0], COUNT_CHOICES[COUNT_CHOICES.len() - 1]);
+    let sheet = build_worksheet(day.id, props.grade, count, props.seed);
+    let (emoji, tag) = day_tag(day.id);
+    let code = format!("{:016x}", props.seed);
+
+    let go = {
+        let navigator = navigator.clone();
+        let (day_id, grade) = (day.id, props.grade.as_u8());
+        move |count: usize, seed: u64| {
+            if let Some(nav) = &navigator {
+                nav.push(&Route::Worksheet { day: day_id, grade, count, seed });
+            }
+        }
+    };
+
+    let on_new = {
+        let go = go.clone();
+        Callback::from(move |_| go(count, rand::random::<u64>()))
+    };
+
+    let on_count = {
+        let seed = props.seed;
+        Callback::from(move |e: Event| {
+            let v = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            go(v.parse().unwrap_or(DEFAULT_COUNT), seed)
+        })
+    };
+
+    let item_rows = |items: &[worksheet::SheetItem], first: usize| {
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, q)| {
+                html! {
+                    <div class="ws-item">
+                        <div><b>{format!("{}. ", first + i)}</b>{q.prompt.clone()}</div>
+                        <VisualFigure visual={q.visual.clone()} />
+                        <div class="ws-space"></div>
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    };
+
+    let footer = format!("Sheet #{} • {} questions", code, sheet.core.len() + sheet.stretch.len());
+
+    html! {
+        <>
+            <style>{WORKSHEET_PRINT_CSS}</style>
+
+            <div class="no-print" style="display:flex; justify-content:space-between; align-items:center; gap:12px; flex-wrap:wrap;">
+                <Link<Route> to={Route::Day { id: day.id }}>{"← Back to the day"}</Link<Route>>
+
+                <div style="display:flex; gap:10px; flex-wrap:wrap; align-items:center;">
+                    <label>
+                        {"Questions: "}
+                        <select onchange={on_count} style="padding:8px 10px; border-radius:10px; border:1px solid #ddd;">
+                            { for COUNT_CHOICES.iter().map(|n| html! {
+                                <option value={n.to_string()} selected={*n == count}>{n.to_string()}</option>
+                            }) }
+                        </select>
+                    </label>
+                    <button
+                        onclick={on_new}
+                        style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                    >
+                        {"🎲 New worksheet"}
+                    </button>
+                    <button
+                        onclick={Callback::from(|_| print_page())}
+                        style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+                    >
+                        {"🖨️ Print"}
+                    </button>
+                </div>
+            </div>
+            <div class="no-print" style="margin-top: 6px; font-size: 13px; opacity:.75;">
+                {"The answer key prints on its own page. Bookmark this page to print the same sheet again."}
+            </div>
+
+            <div class="ws-page">
+                <div style="display:flex; justify-content:space-between; gap:12px; flex-wrap:wrap;">
+                    <div>
+                        <div style="font-size: 13px; opacity:.75;">{format!("THE NUMBERS • {} {} • {}", emoji, tag, props.grade.label())}</div>
+                        <h2 style="margin: 4px 0 0;">{format!("Day {} — {}", day.id, day.title)}</h2>
+                    </div>
+                    <div style="line-height: 2;">
+                        <div>{"Name: ________________"}</div>
+                        <div>{"Date: ________________"}</div>
+                    </div>
+                </div>
+
+                <div style="margin-top: 12px; padding: 10px 12px; border:1px solid #ddd; border-radius: 10px;">
+                    <b>{"Learn: "}</b>{day.learn}
+                </div>
+
+                <h3 style="margin: 16px 0 0;">{"Practice"}</h3>
+                { item_rows(&sheet.core, 1) }
+
+                {
+                    if sheet.stretch.is_empty() {
+                        html!{}
+                    } else {
+                        html! {
+                            <>
+                                <h3 style="margin: 18px 0 0;">{"⭐ Stretch (gentle challenge)"}</h3>
+                                { item_rows(&sheet.stretch, sheet.core.len() + 1) }
+                            </>
+                        }
+                    }
+                }
+
+                <div class="ws-item" style="padding: 10px 12px; border:1px solid #ddd; border-radius: 10px;">
+                    <b>{"Win: "}</b>{day.win}
+                </div>
+
+                <div style="margin-top: 12px; font-size: 12px; opacity:.7;">{footer.clone()}</div>
+            </div>
+
+            <div class="ws-page ws-key">
+                <h2 style="margin: 0;">{format!("Answer key — Day {} — {}", day.id, day.title)}</h2>
+                <div style="margin-top: 4px; font-size: 13px; opacity:.75;">{props.grade.label()}</div>
+                <ol style="margin: 12px 0 0; padding-left: 22px; columns: 2; column-gap: 28px;">
+                    { for sheet.numbered().map(|(_, q)| html! {
+                        <li style="break-inside: avoid; margin-bottom: 6px;">{q.answer.clone()}</li>
+                    }) }
+                </ol>
+                <div style="margin-top: 12px; font-size: 12px; opacity:.7;">{footer}</div>
+            </div>
+        </>
+    }
+}
+
+// ----------------------------
+// Settings (device overrides for config.json)
+// ----------------------------
+
+/// "" = use the site setting, "on" / "off" = override.
+fn tri_value(v: Option<bool>) -> &'static str {
+    match v {
+        None => "",
+        Some(true) => "on",
+        Some(false) => "off",
+    }
+}
+
+fn parse_tri(v: &str) -> Option<bool> {
+    match v {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn on_off(b: bool) -> &'static str {
+    if b {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
+#[function_component(SettingsView)]
+fn settings_view() -> Html {
+    let site = use_state(site_config);
+    let draft = use_state(load_override);
+    let url_text = use_state(|| draft.worker_base_url.clone().unwrap_or_default());
+    let timeout_text = use_state(|| {
+        draft
+            .timeout_ms
+            .map(|ms| (ms / 1000).to_string())
+            .unwrap_or_default()
+    });
+    let message = use_state(|| None::<String>);
+
+    // This page may be the first one opened; show what config.json says now.
+    {
+        let site = site.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                ready_config().await;
+                site.set(site_config());
+            });
+            || ()
+        });
+    }
+
+    let effective = (*site).clone().with_override(&draft);
+
+    let set_draft = |f: fn(&mut ConfigOverride, &str)| {
+        let draft = draft.clone();
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            let v = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            let mut d = (*draft).clone();
+            f(&mut d, &v);
+            draft.set(d);
+            message.set(None);
+        })
+    };
+    let on_ai = set_draft(|d, v| d.ai_enabled = parse_tri(v));
+    let on_offline = set_draft(|d, v| d.offline = parse_tri(v));
+    let on_prefetch = set_draft(|d, v| {
+        d.prefetch = match v {
+            "all_days" => Some(Prefetch::AllDays),
+            "on_open" => Some(Prefetch::OnOpen),
+            _ => None,
+        }
+    });
+
+    let on_url = {
+        let url_text = url_text.clone();
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            url_text.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+            message.set(None);
+        })
+    };
+    let on_timeout = {
+        let timeout_text = timeout_text.clone();
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            timeout_text.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+            message.set(None);
+        })
+    };
+
+    let on_save = {
+        let draft = draft.clone();
+        let url_text = url_text.clone();
+        let timeout_text = timeout_text.clone();
+        let message = message.clone();
+        Callback::from(move |_| {
+            let mut d = (*draft).clone();
+
+            let url = url_text.trim();
+            if !url.is_empty() && !valid_url(url) {
+                message.set(Some("⚠️ The worker URL should start with https:// (or http://).".to_string()));
+                return;
+            }
+            d.worker_base_url = (!url.is_empty()).then(|| url.to_string());
+
+            let secs = timeout_text.trim();
+            d.timeout_ms = if secs.is_empty() {
+                None
+            } else {
+                match secs.parse::<u32>() {
+                    Ok(n) if (MIN_TIMEOUT_MS / 1000..=MAX_TIMEOUT_MS / 1000).contains(&n) => Some(n * 1000),
+                    _ => {
+                        message.set(Some(format!(
+                            "⚠️ Timeout should be {}–{} seconds.",
+                            MIN_TIMEOUT_MS / 1000,
+                            MAX_TIMEOUT_MS / 1000
+                        )));
+                        return;
+                    }
+                }
+            };
+
+            save_override(&d);
+            draft.set(d);
+            message.set(Some("Saved ✅ (applies to every learner on this device)".to_string()));
+        })
+    };
+
+    let on_reset = {
+        let draft = draft.clone();
+        let url_text = url_text.clone();
+        let timeout_text = timeout_text.clone();
+        let message = message.clone();
+        Callback::from(move |_| {
+            save_override(&ConfigOverride::default());
+            draft.set(ConfigOverride::default());
+            url_text.set(String::new());
+            timeout_text.set(String::new());
+            message.set(Some("Back to the site settings ✅".to_string()));
+        })
+    };
+
+    let row = "display:flex; gap:10px; align-items:center; flex-wrap: wrap; margin-top: 12px;";
+    let field = "padding:8px 10px; border:1px solid #ddd; border-radius:10px;";
+    let prefetch_value = match draft.prefetch {
+        None => "",
+        Some(Prefetch::AllDays) => "all_days",
+        Some(Prefetch::OnOpen) => "on_open",
+    };
+
+    html! {
+        <>
+            <Link<Route> to={Route::Home}>{"← Back"}</Link<Route>>
+            <h2 style="margin: 8px 0 0;">{"⚙️ Settings"}</h2>
+            <div style="opacity:.75; margin-top: 6px; font-size: 14px;">
+                {"These override the site's config.json on this device only. Leave a field on \"Site setting\" to follow the site."}
+            </div>
+
+            <div style="margin-top: 14px; padding: 12px 14px; border:1px solid #ddd; border-radius: 12px;">
+                <div style={row}>
+                    <label for="cfg-offline" style="min-width: 160px;">{"📴 Offline only"}</label>
+                    <select id="cfg-offline" onchange={on_offline} disabled={site.offline} style={field}>
+                        <option value="" selected={tri_value(draft.offline).is_empty()}>{format!("Site setting ({})", on_off(site.offline))}</option>
+                        <option value="on" selected={tri_value(draft.offline) == "on"}>{"On"}</option>
+                        <option value="off" selected={tri_value(draft.offline) == "off"}>{"Off"}</option>
+                    </select>
+                    { if site.offline { html!{ <span style="font-size: 13px; opacity:.75;">{"Set by the site; can't be turned off here."}</span> } } else { html!{} } }
+                </div>
+
+                <div style={row}>
+                    <label for="cfg-ai" style="min-width: 160px;">{"🤖 AI questions"}</label>
+                    <select id="cfg-ai" onchange={on_ai} style={field}>
+                        <option value="" selected={tri_value(draft.ai_enabled).is_empty()}>{format!("Site setting ({})", on_off(site.ai_enabled))}</option>
+                        <option value="on" selected={tri_value(draft.ai_enabled) == "on"}>{"On"}</option>
+                        <option value="off" selected={tri_value(draft.ai_enabled) == "off"}>{"Off"}</option>
+                    </select>
+                </div>
+
+                <div style={row}>
+                    <label for="cfg-prefetch" style="min-width: 160px;">{"📥 Prefetch"}</label>
+                    <select id="cfg-prefetch" onchange={on_prefetch} style={field}>
+                        <option value="" selected={prefetch_value.is_empty()}>{format!("Site setting ({})", site.prefetch.label())}</option>
+                        <option value="all_days" selected={prefetch_value == "all_days"}>{Prefetch::AllDays.label()}</option>
+                        <option value="on_open" selected={prefetch_value == "on_open"}>{Prefetch::OnOpen.label()}</option>
+                    </select>
+                </div>
+
+                <div style={row}>
+                    <label for="cfg-url" style="min-width: 160px;">{"🌐 Worker URL"}</label>
+                    <input
+                        id="cfg-url"
+                        value={(*url_text).clone()}
+                        oninput={on_url}
+                        placeholder={site.worker_base_url.clone()}
+                        style={format!("{} min-width: 280px;", field)}
+                    />
+                </div>
+
+                <div style={row}>
+                    <label for="cfg-timeout" style="min-width: 160px;">{"⏳ Timeout (seconds)"}</label>
+                    <input
+                        id="cfg-timeout"
+                        type="number"
+                        min={(MIN_TIMEOUT_MS / 1000).to_string()}
+                        max={(MAX_TIMEOUT_MS / 1000).to_string()}
+                        value={(*timeout_text).clone()}
+                        oninput={on_timeout}
+                        placeholder={(site.timeout_ms() / 1000).to_string()}
+                        style={format!("{} width: 90px;", field)}
+                    />
+                </div>
+
+                <div style={row}>
+                    <button
+                        onclick={on_save}
+                        style="padding:10px 14px; border-radius:10px; border:1px solid #222; background:#222; color:#fff; cursor:pointer;"
+                    >
+                        {"Save"}
+                    </button>
+                    <button
+                        onclick={on_reset}
+                        style="padding:10px 14px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                    >
+                        {"Use site settings"}
+                    </button>
+                    { if let Some(m) = &*message { html!{ <span style="font-size: 13px;">{m.clone()}</span> } } else { html!{} } }
+                </div>
+            </div>
+
+            <div style="margin-top: 12px; font-size: 13px; opacity:.8;">
+                {
+                    if effective.may_fetch() {
+                        format!(
+                            "Now: AI questions from {} • {} • timeout {}s",
+                            effective.worker_base_url,
+                            effective.prefetch.label().to_lowercase(),
+                            effective.timeout_ms() / 1000
+                        )
+                    } else {
+                        "Now: offline — built-in questions only, the worker is never contacted.".to_string()
+                    }
+                }
+            </div>
+            {
+                match last_error() {
+                    Some((at, e)) => {
+                        let when = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(at));
+                        html! {
+                            <div style="margin-top: 6px; font-size: 13px; opacity:.8;">
+                                {format!(
+                                    "Last problem ({:02}:{:02}): {}",
+                                    when.get_hours(),
+                                    when.get_minutes(),
+                                    e.message()
+                                )}
+                            </div>
+                        }
+                    }
+                    None => html! {},
+                }
+            }
+        </>
+    }
+}
+
+// ----------------------------
+// Review (due items only, across all days)
+// ----------------------------
+
+#[function_component(ReviewView)]
+fn review_view() -> Html {
+    // Snapshot the due list once so items don't vanish mid-review.
+    let items = use_state(|| load_review().due(&today_ymd_local()));
+    let pos = use_state(|| 0usize);
+    let input = use_state(|| "".to_string());
+    let feedback = use_state(|| "".to_string());
+    let shown_at = use_state(js_sys::Date::now);
+    let tries = use_state(|| 0u32);
+
+    let current = items.get(*pos).cloned();
+
+    let on_next = {
+        let pos = pos.clone();
+        let input = input.clone();
+        let feedback = feedback.clone();
+        let shown_at = shown_at.clone();
+        let tries = tries.clone();
+        Callback::from(move |_| {
+            pos.set(*pos + 1);
+            input.set("".to_string());
+            feedback.set("".to_string());
+            shown_at.set(js_sys::Date::now());
+            tries.set(0);
+        })
+    };
+
+    let on_check = {
+        let input = input.clone();
+        let feedback = feedback.clone();
+        let shown_at = *shown_at;
+        let tries = tries.clone();
+        let current = current.clone();
+        Callback::from(move |_| {
+            let Some(item) = &current else { return; };
+            let qa = qa_from_review(item, 0);
+            let ok = check_answer(&input, &qa.answer, qa.answer_kind.as_ref());
+            let slip = if ok { None } else { spot(&input, &qa.known_wrong, qa.answer_kind.as_ref()) };
+
+            let try_no = *tries + 1;
+            tries.set(try_no);
+
+            let mut st = load_stats();
+            record_attempt(&mut st, qa.skill, ok, try_no == 1);
+            if let Some(w) = slip {
+                record_misconception(&mut st, w.misconception);
+            }
+            save_stats(&st);
+
+            let now = js_sys::Date::now();
+            append_attempt(Attempt {
+                ts_ms: now,
+                ymd: today_ymd_local(),
+                day_id: item.day_id,
+                grade: item.grade,
+                skill: qa.skill,
+                prompt: qa.prompt.clone(),
+                expected: qa.answer.clone(),
+                given: (*input).clone(),
+                correct: ok,
+                source: qa.source,
+                stretch: false,
+                ms_to_answer: (now - shown_at).max(0.0) as u32,
+                try_no,
+                hints_used: 0,
+                misconception: slip.map(|w| w.misconception),
+                seed: 0,
+            });
+
+            update_review_queue(&qa, item.day_id, Grade::from_u8(item.grade), ok, try_no);
+
+            if ok {
+                if is_sound_enabled() {
+                    play_tone(880.0, 80, 0.05);
+                }
+                feedback.set("✅ Got it this time! It moves to a later review.".to_string());
+            } else {
+                let msg = match slip {
+                    Some(w) => format!("🤔 {}", w.feedback()),
+                    None => "❌ Not yet — try again.".to_string(),
+                };
+                feedback.set(format!("{msg} It will come back tomorrow."));
+            }
+        })
+    };
+
+    html! {
+        <>
+            <Link<Route> to={Route::Home}>{"← Back"}</Link<Route>>
+            <h2 style="margin: 8px 0 0;">{"🗂️ Review"}</h2>
+            <div style="opacity:.75; margin-top: 6px;">
+                {"Questions missed on earlier days come back here until they stick."}
+            </div>
+
+            <hr style="margin: 18px 0;" />
+
+            {
+                if let Some(item) = &current {
+                    let (emoji, label) = day_tag(item.day_id);
+                    html! {
+                        <section style="border:1px solid #ddd; border-radius:14px; padding:14px;">
+                            <div style="font-size: 13px; opacity:.75;">
+                                {format!("{} of {} • {} Day {} ({}) • Grade {} • Box {}", *pos + 1, items.len(), emoji, item.day_id, label, item.grade, item.box_no)}
+                            </div>
+
+                            <VisualFigure visual={item.visual.clone()} />
+                            <div style="margin-top: 10px; font-weight:600;">{item.prompt.clone()}</div>
+
+                            <div style="display:flex; gap:10px; align-items:center; flex-wrap: wrap; margin-top: 10px;">
+                                <input
+                                    value={(*input).clone()}
+                                    oninput={{
+                                        let input = input.clone();
+                                        let feedback = feedback.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let v = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                                            input.set(v);
+                                            feedback.set("".to_string());
+                                        })
+                                    }}
+                                    placeholder="Your answer"
+                                    style="padding:10px 12px; border:1px solid #ddd; border-radius:10px; min-width: 200px;"
+                                />
+                                <button
+                                    onclick={on_check}
+                                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                                >
+                                    {"Check"}
+                                </button>
+                                <button
+                                    onclick={on_next}
+                                    style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                                >
+                                    {"Next"}
+                                </button>
+                            </div>
+
+                            {
+                                if !(*feedback).is_empty() {
+                                    html! { <div style="margin-top: 8px;">{(*feedback).clone()}</div> }
+                                } else {
+                                    html!{}
+                                }
+                            }
+                        </section>
+                    }
+                } else {
+                    html! {
+                        <div style="padding: 12px 14px; border:1px solid #ddd; border-radius: 14px;">
+                            { if items.is_empty() { "Nothing due right now — nice!" } else { "All caught up 🎉 Come back tomorrow for more." } }
+                        </div>
+                    }
+                }
+            }
+        </>
+    }
 }
 
-fn is_correct(guess: &str, correct: &str) -> bool {
-    let g = normalize_answer(guess);
-    correct
-        .split('|')
-        .map(|x| normalize_answer(x))
-        .any(|c| !c.is_empty() && g == c)
-}
+// ----------------------------
+// Practice Widget (AI day cache first; fallback local)
+// ----------------------------
 
-// Choose a question from AI cached day if fresh; else local questions.rs.
-fn pick_question(day_id: usize, grade: Grade) -> (String, String, bool) {
-    let today = today_ymd_local();
+#[derive(Properties, PartialEq)]
+struct PracticeWidgetProps {
+    day_id: usize,
+    grade: Grade,
+    session: Session,
+    /// Told about every check as (question index, correct) — the guided session counts these.
+    #[prop_or_default]
+    on_check: Option<Callback<(u32, bool)>>,
+}
 
-    if let Some(day) = load_day_from_local_storage(grade, day_id) {
-        if day.date_ymd == today && !day.items.is_empty() {
-            let core: Vec<_> = day
-                .items
-                .iter()
-                .filter(|q| q.difficulty == AiDifficulty::Core)
-                .collect();
-            let stretch: Vec<_> = day
+// Choose a question from the AI cached day if `online_ymd` is given and matches it; else local questions.rs.
+// All randomness comes from `rng`, so a seeded session always picks the same items.
+// `want_stretch` is the slot kind; `avoid` holds prompts already served in this set.
+// Scaffold/NextGrade levels draw from the neighbouring grade's local bank.
+fn pick_question(
+    day_id: usize,
+    grade: Grade,
+    online_ymd: Option<&str>,
+    level: Level,
+    want_stretch: bool,
+    avoid: &[String],
+    rng: &mut dyn RngCore,
+) -> QA {
+    let bank_grade = level.bank_grade(grade);
+
+    // The AI cache only holds the current grade.
+    if let Some(day) = load_day_from_local_storage(grade, day_id).filter(|_| bank_grade == grade) {
+        if online_ymd == Some(day.date_ymd.as_str()) {
+            let want = if want_stretch { AiDifficulty::Stretch } else { AiDifficulty::Core };
+            let unseen: Vec<_> = day
                 .items
                 .iter()
-                .filter(|q| q.difficulty == AiDifficulty::Stretch)
+                .filter(|q| q.difficulty == want && !avoid.contains(&q.prompt))
                 .collect();
 
-            let use_stretch = !stretch.is_empty() && (rand::random::<u8>() % 10) < 3;
-            let pick_from = if use_stretch { &stretch } else { &core };
-
-            let mut rng = rand::thread_rng();
-            if let Some(q) = pick_from.choose(&mut rng) {
-                return (
-                    q.prompt.clone(),
-                    q.answer.clone(),
-                    q.difficulty == AiDifficulty::Stretch,
-                );
+            // Once the AI items of this kind are used up, the local bank takes over.
+            if let Some(q) = unseen.choose(rng) {
+                let skill = q.skill.unwrap_or_else(|| classify_skill(&q.prompt, day_id));
+                let answer_kind = kind_for_prompt(&q.prompt, q.answer_kind.as_ref());
+                return QA {
+                    prompt: q.prompt.clone(),
+                    answer: q.answer.clone(),
+                    skill,
+                    help: help_for(&q.prompt, &q.answer, skill, &q.hints, q.solution.as_deref()),
+                    known_wrong: q.known_wrong.clone(),
+                    format: format_for(&q.prompt, &q.answer, answer_kind.as_ref(), grade, q.format.as_ref(), &q.known_wrong),
+                    answer_kind,
+                    visual: q.visual.clone(),
+                    source: AttemptSource::Ai,
+                    is_stretch: q.difficulty == AiDifficulty::Stretch,
+                    level,
+                    review: false,
+                    index: 0,
+                };
             }
         }
     }
 
-    let bank: Vec<LocalQuestion> = local_questions_for(day_id, grade);
-    let core: Vec<_> = bank
-        .iter()
-        .filter(|q| q.difficulty == LocalDifficulty::Core)
-        .collect();
-    let stretch: Vec<_> = bank
+    let bank: Vec<LocalQuestion> = local_questions_for(day_id, bank_grade, rng);
+    let want = if want_stretch { LocalDifficulty::Stretch } else { LocalDifficulty::Core };
+
+    // Unseen of the right kind, then unseen of any kind, then (tiny banks) a repeat.
+    let mut pool: Vec<_> = bank
         .iter()
-        .filter(|q| q.difficulty == LocalDifficulty::Stretch)
+        .filter(|q| q.difficulty == want && !avoid.contains(&q.prompt))
         .collect();
-
-    let use_stretch = !stretch.is_empty() && (rand::random::<u8>() % 10) < 3;
-    let pick_from = if use_stretch { &stretch } else { &core };
-
-    let mut rng = rand::thread_rng();
-    if let Some(q) = pick_from.choose(&mut rng) {
-        return (
-            q.prompt.to_string(),
-            q.answer.to_string(),
-            q.difficulty == LocalDifficulty::Stretch,
-        );
+    if pool.is_empty() {
+        pool = bank.iter().filter(|q| !avoid.contains(&q.prompt)).collect();
+    }
+    if pool.is_empty() {
+        pool = bank.iter().collect();
     }
 
-    let q = bank.choose(&mut rng).unwrap();
-    (
-        q.prompt.to_string(),
-        q.answer.to_string(),
-        q.difficulty == LocalDifficulty::Stretch,
-    )
+    let q = pool.choose(rng).copied().unwrap_or_else(|| bank.choose(rng).unwrap());
+    QA {
+        prompt: q.prompt.clone(),
+        answer: q.answer.clone(),
+        answer_kind: q.answer_kind.clone(),
+        skill: q.skill,
+        help: help_for(&q.prompt, &q.answer, q.skill, &q.hints, q.solution.as_deref()),
+        known_wrong: q.known_wrong.clone(),
+        format: format_for(&q.prompt, &q.answer, q.answer_kind.as_ref(), grade, q.format.as_ref(), &q.known_wrong),
+        visual: q.visual.clone(),
+        source: AttemptSource::Local,
+        is_stretch: q.difficulty == LocalDifficulty::Stretch,
+        level,
+        review: false,
+        index: 0,
+    }
 }
 
 #[derive(Clone, PartialEq)]
 struct QA {
     prompt: String,
     answer: String,
+    answer_kind: Option<AnswerKind>,
+    skill: Skill,
+    /// Hint steps + worked solution, revealed one at a time.
+    help: Help,
+    /// Predictable wrong answers with their own feedback.
+    known_wrong: Vec<KnownWrong>,
+    format: QuestionFormat,
+    visual: Option<Visual>,
+    source: AttemptSource,
     is_stretch: bool,
+    level: Level,
+    /// Served from the review queue rather than picked fresh.
+    review: bool,
+    /// Position in the seeded session (question N uses its own RNG stream).
+    index: u32,
+}
+
+// Every Nth question of a live session is a due review item (when any are due).
+const REVIEW_EVERY: u32 = 3;
+
+fn qa_from_review(item: &ReviewItem, index: u32) -> QA {
+    QA {
+        prompt: item.prompt.clone(),
+        answer: item.answer.clone(),
+        answer_kind: item.answer_kind.clone(),
+        skill: item.skill,
+        help: help_for(&item.prompt, &item.answer, item.skill, &item.hints, item.solution.as_deref()),
+        known_wrong: item.known_wrong.clone(),
+        format: format_for(
+            &item.prompt,
+            &item.answer,
+            item.answer_kind.as_ref(),
+            Grade::from_u8(item.grade),
+            None,
+            &item.known_wrong,
+        ),
+        visual: item.visual.clone(),
+        source: item.source,
+        is_stretch: false,
+        level: Level::default(),
+        review: true,
+        index,
+    }
+}
+
+fn qa_from_served(q: &ServedQuestion, index: u32, level: Level) -> QA {
+    QA {
+        prompt: q.prompt.clone(),
+        answer: q.answer.clone(),
+        answer_kind: q.answer_kind.clone(),
+        skill: q.skill,
+        help: help_for(&q.prompt, &q.answer, q.skill, &q.hints, q.solution.as_deref()),
+        known_wrong: q.known_wrong.clone(),
+        format: q.format.clone(),
+        visual: q.visual.clone(),
+        source: q.source,
+        is_stretch: q.stretch,
+        level,
+        review: q.review,
+        index,
+    }
+}
+
+/// The hints and solution worth storing: those help_for wouldn't build again
+/// from the prompt (the worker's or the generator's own).
+fn given_help(qa: &QA) -> (Vec<String>, Option<String>) {
+    let built = help_for(&qa.prompt, &qa.answer, qa.skill, &[], None);
+    let hints = if qa.help.hints != built.hints { qa.help.hints.clone() } else { Vec::new() };
+    let solution = (qa.help.solution != built.solution).then(|| qa.help.solution.clone());
+    (hints, solution)
+}
+
+fn served_question(qa: &QA) -> ServedQuestion {
+    let (hints, solution) = given_help(qa);
+    ServedQuestion {
+        prompt: qa.prompt.clone(),
+        answer: qa.answer.clone(),
+        answer_kind: qa.answer_kind.clone(),
+        skill: qa.skill,
+        format: qa.format.clone(),
+        visual: qa.visual.clone(),
+        known_wrong: qa.known_wrong.clone(),
+        hints,
+        solution,
+        source: qa.source,
+        stretch: qa.is_stretch,
+        review: qa.review,
+    }
+}
+
+fn qa_for(session: &Session, index: u32, avoid: &[String]) -> QA {
+    let level = match &session.pinned_levels {
+        Some(levels) => levels.get(index as usize).copied().unwrap_or_default(),
+        None => day_level(&load_adaptive(), session.grade, session.day_id),
+    };
+    // Replays show what was served; picking again could land on a different
+    // item once the online set of that date is gone.
+    if let Some(q) = session.served.get(index as usize) {
+        return qa_from_served(q, index, level);
+    }
+    let stretch = Session::slot_is_stretch(index, level);
+
+    // Live sets only: a replay gets its review slots from `served` above.
+    if session.pinned_levels.is_none() && !stretch && index % REVIEW_EVERY == REVIEW_EVERY - 1 {
+        let due = load_review().due_for_day(&today_ymd_local(), session.day_id, session.grade.as_u8());
+        if let Some(item) = due.iter().find(|r| !avoid.contains(&r.prompt)) {
+            return qa_from_review(item, index);
+        }
+    }
+
+    let mut rng = session.rng_for(index);
+    QA {
+        index,
+        ..pick_question(
+            session.day_id,
+            session.grade,
+            session.online.then_some(session.ymd.as_str()),
+            level,
+            stretch,
+            avoid,
+            &mut rng,
+        )
+    }
+}
+
+/// First set of the day that hasn't been finished yet (set 1, then 2, …).
+/// It uses the online set only if that's already cached for `ymd`.
+fn open_set(ymd: &str, day_id: usize, grade: Grade) -> Session {
+    let profile = active_profile_id();
+    let st = load_stats();
+    let set = (1..)
+        .map(|n| Session::nth_set(&profile, ymd, day_id, grade, n))
+        .find(|s| {
+            find_session(&st, ymd, day_id, grade.as_u8(), s.seed).is_none_or(|r| r.result.is_none())
+        })
+        .unwrap_or_else(|| Session::new(&profile, ymd, day_id, grade));
+    Session {
+        online: load_day_from_local_storage(grade, day_id).is_some_and(|d| d.date_ymd == ymd),
+        ..set
+    }
+}
+
+/// Queue a miss for review, or move a review item between boxes (first try only).
+fn update_review_queue(qa: &QA, day_id: usize, grade: Grade, ok: bool, try_no: u32) {
+    let today = today_ymd_local();
+    let mut queue = load_review();
+    if qa.review {
+        if try_no != 1 {
+            return;
+        }
+        queue.record_review(&qa.prompt, &qa.answer, day_id, grade.as_u8(), ok, &today);
+    } else if !ok {
+        let (hints, solution) = given_help(qa);
+        queue.add_miss(
+            ReviewItem {
+                prompt: qa.prompt.clone(),
+                answer: qa.answer.clone(),
+                answer_kind: qa.answer_kind.clone(),
+                visual: qa.visual.clone(),
+                hints,
+                solution,
+                known_wrong: qa.known_wrong.clone(),
+                skill: qa.skill,
+                day_id,
+                grade: grade.as_u8(),
+                source: qa.source,
+                box_no: 1,
+                due_ymd: String::new(),
+                added_ymd: String::new(),
+                reviews: 0,
+                mastered: false,
+            },
+            &today,
+        );
+    } else {
+        return;
+    }
+    save_review(&queue);
+}
+
+/// One question of the set being played, plus how it went.
+#[derive(Clone, PartialEq)]
+struct SetItem {
+    qa: QA,
+    /// First check: (correct, what was typed). None until checked.
+    first: Option<(bool, String)>,
+    solved: bool,
+    /// Help steps revealed so far (hints, then the solution).
+    help_shown: usize,
+    /// Solved after some help was revealed.
+    hinted: bool,
+    /// Wrong answers given so far (tapped choices are greyed out).
+    tried: Vec<String>,
+}
+
+impl SetItem {
+    fn new(qa: QA) -> SetItem {
+        SetItem {
+            qa,
+            first: None,
+            solved: false,
+            help_shown: 0,
+            hinted: false,
+            tried: Vec::new(),
+        }
+    }
+
+    fn reveal_help(&mut self) {
+        self.help_shown = (self.help_shown + 1).min(self.qa.help.steps());
+    }
+}
+
+fn set_result(items: &[SetItem]) -> SetResult {
+    let first_ok = |i: &&SetItem| i.first.as_ref().is_some_and(|(ok, _)| *ok);
+    SetResult {
+        finished_ms: js_sys::Date::now(),
+        questions: items.len() as u32,
+        first_try_correct: items.iter().filter(first_ok).count() as u32,
+        stretch: items.iter().filter(|i| i.qa.is_stretch).count() as u32,
+        stretch_wins: items.iter().filter(|i| i.qa.is_stretch).filter(first_ok).count() as u32,
+        solved_with_hints: items.iter().filter(|i| i.solved && i.hinted).count() as u32,
+        missed: items
+            .iter()
+            .filter_map(|i| match &i.first {
+                Some((false, given)) => Some(SetMiss {
+                    prompt: i.qa.prompt.clone(),
+                    answer: i.qa.answer.clone(),
+                    given: given.clone(),
+                    fixed: i.solved,
+                }),
+                _ => None,
+            })
+            .collect(),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct VisualFigureProps {
+    visual: Option<Visual>,
+}
+
+/// A question's picture as inline SVG (nothing if there is none or it can't be drawn).
+#[function_component(VisualFigure)]
+fn visual_figure(props: &VisualFigureProps) -> Html {
+    let Some(fig) = props.visual.as_ref().and_then(Visual::figure) else {
+        return html! {};
+    };
+
+    let xy = |p: (f64, f64)| (format!("{:.1}", p.0), format!("{:.1}", p.1));
+    let marks = fig.marks.iter().map(|m| match m {
+        Mark::Line { from, to, stroke, width } => {
+            let ((x1, y1), (x2, y2)) = (xy(*from), xy(*to));
+            html! { <line {x1} {y1} {x2} {y2} stroke={*stroke} stroke-width={width.to_string()} stroke-linecap="round" /> }
+        }
+        Mark::Rect { at, size, round, fill, stroke } => {
+            let (x, y) = xy(*at);
+            let (width, height) = xy(*size);
+            html! { <rect {x} {y} {width} {height} rx={round.to_string()} fill={*fill} stroke={*stroke} stroke-width="2" /> }
+        }
+        Mark::Circle { center, r, fill, stroke } => {
+            let (cx, cy) = xy(*center);
+            html! { <circle {cx} {cy} r={r.to_string()} fill={*fill} stroke={*stroke} stroke-width="2" /> }
+        }
+        Mark::Path { d, fill, stroke } => {
+            html! { <path d={d.clone()} fill={*fill} stroke={*stroke} stroke-width="2" stroke-linejoin="round" /> }
+        }
+        Mark::Text { at, text, size, bold } => {
+            let (x, y) = xy(*at);
+            html! {
+                <text {x} {y} font-size={size.to_string()} font-weight={if *bold { "700" } else { "400" }}
+                    text-anchor="middle" dominant-baseline="central" fill="#222">{text.clone()}</text>
+            }
+        }
+    });
+
+    html! {
+        <div style="margin-top: 10px;">
+            <svg
+                viewBox={format!("0 0 {:.0} {:.0}", fig.width, fig.height)}
+                width={format!("{:.0}", fig.width)}
+                style="max-width:100%; height:auto; font-family: system-ui, sans-serif;"
+                role="img"
+                aria-label={fig.title.clone()}
+            >
+                <title>{fig.title.clone()}</title>
+                { for marks }
+            </svg>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ChoicePadProps {
+    options: Vec<String>,
+    /// Draw the options as ticks on a number line instead of big buttons.
+    #[prop_or_default]
+    number_line: bool,
+    /// Already-tried wrong answers (shown crossed out).
+    tried: Vec<String>,
+    disabled: bool,
+    on_pick: Callback<String>,
+}
+
+/// Tap-to-answer: multiple choice, true/false, or a number line.
+#[function_component(ChoicePad)]
+fn choice_pad(props: &ChoicePadProps) -> Html {
+    let buttons = props.options.iter().map(|opt| {
+        let tried = props.tried.iter().any(|t| t == opt);
+        let onclick = {
+            let on_pick = props.on_pick.clone();
+            let opt = opt.clone();
+            Callback::from(move |_| on_pick.emit(opt.clone()))
+        };
+        let style = if props.number_line {
+            "min-width:30px; padding:6px 4px; border-radius:8px; border:1px solid #bbb; background:#fff; cursor:pointer; font-size:14px;"
+        } else {
+            "min-width:64px; padding:12px 18px; border-radius:12px; border:1px solid #222; background:#fff; cursor:pointer; font-size:18px; font-weight:700;"
+        };
+        html! {
+            <button
+                {onclick}
+                disabled={props.disabled || tried}
+                style={format!("{}{}", style, if tried { " opacity:.45; text-decoration: line-through;" } else { "" })}
+            >
+                {opt.clone()}
+            </button>
+        }
+    });
+
+    if props.number_line {
+        html! {
+            <div style="margin-top: 10px; overflow-x:auto;">
+                <div style="display:flex; justify-content:space-between; gap:2px; min-width: max-content; padding: 8px 0 0; border-top: 3px solid #222;">
+                    { for buttons }
+                </div>
+                <div style="margin-top: 4px; font-size: 13px; opacity:.75;">{"Tap where you land."}</div>
+            </div>
+        }
+    } else {
+        html! {
+            <div style="display:flex; gap:10px; flex-wrap: wrap; margin-top: 10px;">
+                { for buttons }
+            </div>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct OrderPadProps {
+    /// Starting (scrambled) order.
+    items: Vec<String>,
+    disabled: bool,
+    /// Gets the current order as "a,b,c".
+    on_submit: Callback<String>,
+}
+
+/// Put items in order: drag a chip onto another, or nudge it with the arrows.
+#[function_component(OrderPad)]
+fn order_pad(props: &OrderPadProps) -> Html {
+    let order = use_state(|| props.items.clone());
+    let dragging = use_state(|| None::<usize>);
+
+    let move_item = {
+        let order = order.clone();
+        move |from: usize, to: usize| {
+            let mut v = (*order).clone();
+            if from < v.len() && to < v.len() && from != to {
+                let item = v.remove(from);
+                v.insert(to, item);
+                order.set(v);
+            }
+        }
+    };
+
+    let len = order.len();
+    let chips = order.iter().enumerate().map(|(i, item)| {
+        let ondragstart = {
+            let dragging = dragging.clone();
+            Callback::from(move |_: DragEvent| dragging.set(Some(i)))
+        };
+        let ondrop = {
+            let dragging = dragging.clone();
+            let move_item = move_item.clone();
+            Callback::from(move |e: DragEvent| {
+                e.prevent_default();
+                if let Some(from) = *dragging {
+                    move_item(from, i);
+                }
+                dragging.set(None);
+            })
+        };
+        let left = {
+            let move_item = move_item.clone();
+            Callback::from(move |_| move_item(i, i.saturating_sub(1)))
+        };
+        let right = {
+            let move_item = move_item.clone();
+            Callback::from(move |_| move_item(i, i + 1))
+        };
+        html! {
+            <div
+                draggable={(!props.disabled).to_string()}
+                {ondragstart}
+                ondragover={Callback::from(|e: DragEvent| e.prevent_default())}
+                {ondrop}
+                style="display:flex; align-items:center; gap:4px; padding:6px 8px; border-radius:12px; border:1px solid #222; background:#fff; cursor:grab;"
+            >
+                <button onclick={left} disabled={props.disabled || i == 0} aria-label="Move left"
+                    style="border:none; background:none; cursor:pointer; padding:2px;">{"◀"}</button>
+                <span style="font-size:18px; font-weight:700; min-width: 24px; text-align:center;">{item.clone()}</span>
+                <button onclick={right} disabled={props.disabled || i + 1 == len} aria-label="Move right"
+                    style="border:none; background:none; cursor:pointer; padding:2px;">{"▶"}</button>
+            </div>
+        }
+    });
+
+    let onclick = {
+        let order = order.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |_| on_submit.emit(order.join(",")))
+    };
+
+    html! {
+        <div style="margin-top: 10px;">
+            <div style="display:flex; gap:8px; flex-wrap: wrap;">{ for chips }</div>
+            <div style="display:flex; gap:10px; align-items:center; margin-top: 10px;">
+                <button
+                    {onclick}
+                    disabled={props.disabled}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                >
+                    {"Check order"}
+                </button>
+                <span style="font-size: 13px; opacity:.75;">{"Drag the numbers (or use the arrows) to put them in order."}</span>
+            </div>
+        </div>
+    }
 }
 
 #[function_component(PracticeWidget)]
@@ -1530,51 +3728,169 @@ fn practice_widget(props: &PracticeWidgetProps) -> Html {
     let input = use_state(|| "".to_string());
     let feedback = use_state(|| "".to_string());
 
-    // Pick ONCE per mount so the question does not change while typing.
-    let qa = use_state(|| {
-        let (p, a, s) = pick_question(props.day_id, props.grade);
-        QA {
-            prompt: p,
-            answer: a,
-            is_stretch: s,
-        }
-    });
+    // The set being played (live sets move on to the next set of the day).
+    let session = use_state(|| props.session.clone());
+    // Served so far; the last one is on screen. Picked ONCE so it doesn't change while typing.
+    let served = use_state(|| vec![SetItem::new(qa_for(&props.session, 0, &[]))]);
+    let finished = use_state(|| None::<SetResult>);
+    // For the attempt log: when the question appeared + how many checks so far.
+    let shown_at = use_state(js_sys::Date::now);
+    let tries = use_state(|| 0u32);
+
+    let current = served.last().cloned().expect("a set always has a question");
+    let position = served.len() as u32;
 
-    let on_new_question = {
+    let reset_question = {
         let input = input.clone();
         let feedback = feedback.clone();
-        let qa = qa.clone();
+        let shown_at = shown_at.clone();
+        let tries = tries.clone();
+        move || {
+            shown_at.set(js_sys::Date::now());
+            tries.set(0);
+            input.set("".to_string());
+            feedback.set("".to_string());
+        }
+    };
 
-        let day_id = props.day_id;
-        let grade = props.grade;
+    let on_next = {
+        let served = served.clone();
+        let finished = finished.clone();
+        let session = session.clone();
+        let reset_question = reset_question.clone();
 
         Callback::from(move |_| {
-            let (p2, a2, s2) = pick_question(day_id, grade);
-            qa.set(QA {
-                prompt: p2,
-                answer: a2,
-                is_stretch: s2,
-            });
+            let mut items = (*served).clone();
+            if items.len() as u32 >= SET_LEN {
+                let result = set_result(&items);
+                // A replay's score is its own; the original set keeps its result.
+                if session.pinned_levels.is_none() {
+                    let mut st = load_stats();
+                    record_set_result(&mut st, &session, result.clone());
+                    save_stats(&st);
+                }
+                finished.set(Some(result));
+                return;
+            }
+            let avoid: Vec<String> = items.iter().map(|i| i.qa.prompt.clone()).collect();
+            items.push(SetItem::new(qa_for(&session, items.len() as u32, &avoid)));
+            served.set(items);
+            reset_question();
+        })
+    };
 
-            input.set("".to_string());
-            feedback.set("".to_string());
+    let on_another_set = {
+        let served = served.clone();
+        let finished = finished.clone();
+        let session = session.clone();
+        let reset_question = reset_question.clone();
+
+        Callback::from(move |_| {
+            let next = open_set(&session.ymd, session.day_id, session.grade);
+            served.set(vec![SetItem::new(qa_for(&next, 0, &[]))]);
+            session.set(next);
+            finished.set(None);
+            reset_question();
         })
     };
 
-    let on_check = {
-        let input = input.clone();
+    // Grade one answer, however it was entered (typed, tapped or ordered).
+    let check = {
         let feedback = feedback.clone();
-        let qa_now = (*qa).clone();
+        let served = served.clone();
+        let shown_at = *shown_at;
+        let tries = tries.clone();
+        let session = (*session).clone();
+        let on_check = props.on_check.clone();
+
+        Callback::from(move |given: String| {
+            let mut items = (*served).clone();
+            let Some(item) = items.last_mut() else { return; };
+            let qa_now = item.qa.clone();
+            let ok = check_answer(&given, &qa_now.answer, qa_now.answer_kind.as_ref());
+            let slip = if ok { None } else { spot(&given, &qa_now.known_wrong, qa_now.answer_kind.as_ref()) };
+            if item.first.is_none() {
+                item.first = Some((ok, given.clone()));
+            }
+            // Help showing at this check; a miss reveals the next step.
+            let hints_used = item.help_shown;
+            let newly_solved = ok && !item.solved;
+            if newly_solved {
+                item.hinted = hints_used > 0;
+            }
+            item.solved |= ok;
+            if !ok {
+                item.reveal_help();
+                item.tried.push(given.clone());
+            }
+            let help_note = match item.help_shown {
+                n if ok || n == hints_used => "",
+                n if n == qa_now.help.steps() => " Here's how to solve it 👇",
+                _ => " Here's a hint 👇",
+            };
+            served.set(items);
+            if let Some(cb) = &on_check {
+                cb.emit((qa_now.index, ok));
+            }
 
-        Callback::from(move |_| {
-            let ok = is_correct(&input, &qa_now.answer);
+            let try_no = *tries + 1;
+            tries.set(try_no);
 
-            // record attempts into weekly/day buckets
+            // record attempts into weekly/day buckets (+ the seeded set they came from,
+            // unless this is a replay of it)
             let mut st = load_stats();
-            let skill = classify_skill(&qa_now.prompt);
-            record_attempt(&mut st, skill, ok);
+            let skill = qa_now.skill;
+            if session.pinned_levels.is_none() {
+                record_session_attempt(&mut st, &session, qa_now.index, qa_now.level, ok);
+                record_served(&mut st, &session, qa_now.index, served_question(&qa_now));
+            }
+            record_attempt(&mut st, skill, ok, try_no == 1);
+            if newly_solved {
+                record_solve(&mut st, skill, hints_used > 0);
+            }
+            if let Some(w) = slip {
+                record_misconception(&mut st, w.misconception);
+            }
             save_stats(&st);
 
+            // ...and the full detail into the attempt log
+            let now = js_sys::Date::now();
+            append_attempt(Attempt {
+                ts_ms: now,
+                ymd: today_ymd_local(),
+                day_id: session.day_id,
+                grade: session.grade.as_u8(),
+                skill,
+                prompt: qa_now.prompt.clone(),
+                expected: qa_now.answer.clone(),
+                given,
+                correct: ok,
+                source: qa_now.source,
+                stretch: qa_now.is_stretch,
+                ms_to_answer: (now - shown_at).max(0.0) as u32,
+                try_no,
+                hints_used: hints_used as u32,
+                misconception: slip.map(|w| w.misconception),
+                seed: session.seed,
+            });
+
+            // Misses go into the review queue; reviews move through the Leitner boxes
+            // (not on a replay: that item was already reviewed when the set was played).
+            if !(qa_now.review && session.pinned_levels.is_some()) {
+                update_review_queue(&qa_now, session.day_id, session.grade, ok, try_no);
+            }
+
+            // Adapt on first tries only, so retrying one hard question doesn't count twice.
+            let level_note = if try_no == 1 && !qa_now.review {
+                match record_adaptive(session.grade, session.day_id, skill, ok) {
+                    (before, after) if after > before => " ⬆️ Leveling up — tougher ones coming!",
+                    (before, after) if after < before => " 🪜 Next ones will be a bit gentler.",
+                    _ => "",
+                }
+            } else {
+                ""
+            };
+
             if ok {
                 if is_sound_enabled() {
                     if qa_now.is_stretch {
@@ -1585,57 +3901,229 @@ fn practice_widget(props: &PracticeWidgetProps) -> Html {
                     }
                 }
 
-                feedback.set(if qa_now.is_stretch {
-                    "🌟 Stretch win! Awesome job.".to_string()
+                let msg = if qa_now.is_stretch || qa_now.level == Level::NextGrade {
+                    "🌟 Stretch win! Awesome job."
                 } else {
-                    "✅ Nice! You got it.".to_string()
-                });
+                    "✅ Nice! You got it."
+                };
+                feedback.set(format!("{}{}", msg, level_note));
             } else {
-                feedback.set("❌ Not yet — try again.".to_string());
+                let msg = match slip {
+                    Some(w) => format!("🤔 {}", w.feedback()),
+                    None => "❌ Not yet — try again, or move on.".to_string(),
+                };
+                feedback.set(format!("{}{}{}", msg, help_note, level_note));
+            }
+        })
+    };
+
+    let on_hint = {
+        let served = served.clone();
+        Callback::from(move |_| {
+            let mut items = (*served).clone();
+            if let Some(item) = items.last_mut() {
+                item.reveal_help();
             }
+            served.set(items);
         })
     };
 
+    if let Some(result) = &*finished {
+        let is_replay = session.pinned_levels.is_some();
+        return html! {
+            <div style="margin-top: 10px;">
+                <div style="font-size: 18px; font-weight: 800;">{"🏁 Set complete!"}</div>
+                <div style="margin-top: 8px; display:flex; gap:8px; flex-wrap:wrap;">
+                    <div style="padding: 6px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                        {format!("🎯 {} of {} right on the first try", result.first_try_correct, result.questions)}
+                    </div>
+                    {
+                        if result.stretch > 0 {
+                            html! {
+                                <div style="padding: 6px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(255,215,0,.14);">
+                                    {format!("🌟 Stretch wins: {} of {}", result.stretch_wins, result.stretch)}
+                                </div>
+                            }
+                        } else {
+                            html!{}
+                        }
+                    }
+                    {
+                        if result.solved_with_hints > 0 {
+                            html! {
+                                <div style="padding: 6px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(0,0,0,.03);">
+                                    {format!("💡 Solved with hints: {}", result.solved_with_hints)}
+                                </div>
+                            }
+                        } else {
+                            html!{}
+                        }
+                    }
+                </div>
+
+                {
+                    if result.missed.is_empty() {
+                        html! { <div style="margin-top: 8px;">{"No misses — amazing! 🎉"}</div> }
+                    } else {
+                        html! {
+                            <>
+                                <div style="margin-top: 10px; font-weight: 700;">{"Let's look at these again:"}</div>
+                                <ul style="margin: 6px 0 0; padding-left: 18px;">
+                                    { for result.missed.iter().map(|m| html! {
+                                        <li style="margin-bottom: 6px;">
+                                            <div>{m.prompt.clone()}</div>
+                                            <div style="font-size: 13px; opacity:.8;">
+                                                {format!(
+                                                    "Answer: {} • You typed: {}{}",
+                                                    m.answer,
+                                                    if m.given.trim().is_empty() { "(blank)" } else { m.given.as_str() },
+                                                    if m.fixed { " • ✅ fixed on a retry" } else { "" }
+                                                )}
+                                            </div>
+                                        </li>
+                                    }) }
+                                </ul>
+                            </>
+                        }
+                    }
+                }
+
+                {
+                    if is_replay {
+                        html!{}
+                    } else {
+                        html! {
+                            <button
+                                onclick={on_another_set}
+                                style="margin-top: 10px; padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                            >
+                                {"▶ Another set"}
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        };
+    }
+
+    let qa = &current.qa;
+    let checked = current.first.is_some();
+    let more_help = current.help_shown < qa.help.steps();
+    let next_is_solution = current.help_shown + 1 == qa.help.steps();
+    let dots = served.iter().map(|i| match &i.first {
+        Some((true, _)) => ("●", "#2e7d32"),
+        Some((false, _)) => ("●", "#ef6c00"),
+        None => ("●", "#222"),
+    });
+
     html! {
         <div style="margin-top: 10px;">
-            <div style="display:flex; align-items:center; justify-content:space-between; gap:10px; flex-wrap:wrap;">
-                <div style="display:flex; align-items:center; gap:8px;">
-                    <div style="font-weight:600;">{(*qa).prompt.clone()}</div>
-                    { if (*qa).is_stretch { html!{ <span title="Stretch question">{"🌟"}</span> } } else { html!{} } }
+            <div style="display:flex; align-items:center; justify-content:space-between; gap:10px; flex-wrap:wrap; font-size: 13px;">
+                <div style="opacity:.75;">{format!("Question {} of {}", position, SET_LEN)}</div>
+                <div style="letter-spacing: 2px;" aria-hidden="true">
+                    { for dots.map(|(d, color)| html!{ <span style={format!("color:{};", color)}>{d}</span> }) }
+                    { for (position..SET_LEN).map(|_| html!{ <span style="color:#ccc;">{"○"}</span> }) }
                 </div>
+            </div>
 
-                <button
-                    onclick={on_new_question}
-                    style="padding:8px 10px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
-                    title="Get a new question"
-                >
-                    {"New question"}
-                </button>
+            <VisualFigure visual={qa.visual.clone()} />
+
+            <div style="display:flex; align-items:center; gap:8px; margin-top: 8px;">
+                <div style="font-weight:600;">{qa.prompt.clone()}</div>
+                { if qa.is_stretch { html!{ <span title="Stretch question">{"🌟"}</span> } } else { html!{} } }
+                { if qa.review { html!{ <span title="Review: missed on an earlier day">{"🗂️"}</span> } } else { html!{} } }
+                {
+                    match qa.level.chip() {
+                        Some((emoji, title)) => html!{ <span title={title}>{emoji}</span> },
+                        None => html!{},
+                    }
+                }
             </div>
 
+            {
+                match &qa.format {
+                    QuestionFormat::Order { items } => html! {
+                        <OrderPad key={position} items={items.clone()} disabled={current.solved} on_submit={check.clone()} />
+                    },
+                    f if f.is_tap() => html! {
+                        <ChoicePad
+                            key={position}
+                            options={f.options(&qa.prompt)}
+                            number_line={matches!(f, QuestionFormat::NumberLine { .. })}
+                            tried={current.tried.clone()}
+                            disabled={current.solved}
+                            on_pick={check.clone()}
+                        />
+                    },
+                    _ => html! {
+                        <div style="display:flex; gap:10px; align-items:center; flex-wrap: wrap; margin-top: 10px;">
+                            <input
+                                value={(*input).clone()}
+                                oninput={{
+                                    let input = input.clone();
+                                    let feedback = feedback.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let v = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                                        input.set(v);
+                                        feedback.set("".to_string());
+                                    })
+                                }}
+                                placeholder="Your answer"
+                                style="padding:10px 12px; border:1px solid #ddd; border-radius:10px; min-width: 200px;"
+                            />
+                            <button
+                                onclick={{
+                                    let check = check.clone();
+                                    let input = input.clone();
+                                    Callback::from(move |_| check.emit((*input).clone()))
+                                }}
+                                disabled={current.solved}
+                                style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                            >
+                                {"Check"}
+                            </button>
+                        </div>
+                    },
+                }
+            }
+
             <div style="display:flex; gap:10px; align-items:center; flex-wrap: wrap; margin-top: 10px;">
-                <input
-                    value={(*input).clone()}
-                    oninput={{
-                        let input = input.clone();
-                        let feedback = feedback.clone();
-                        Callback::from(move |e: InputEvent| {
-                            let v = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
-                            input.set(v);
-                            feedback.set("".to_string());
-                        })
-                    }}
-                    placeholder="Your answer"
-                    style="padding:10px 12px; border:1px solid #ddd; border-radius:10px; min-width: 200px;"
-                />
                 <button
-                    onclick={on_check}
-                    style="padding:10px 12px; border-radius:10px; border:1px solid #222; background:#fff; cursor:pointer;"
+                    onclick={on_next}
+                    disabled={!checked}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
+                    title={if checked { "" } else { "Check an answer first" }}
+                >
+                    { if position >= SET_LEN { "See results 🏁" } else { "Next →" } }
+                </button>
+                <button
+                    onclick={on_hint}
+                    disabled={current.solved || !more_help}
+                    style="padding:10px 12px; border-radius:10px; border:1px solid #ddd; background:#fff; cursor:pointer;"
                 >
-                    {"Check"}
+                    { if next_is_solution { "📖 Show solution" } else { "💡 Hint" } }
                 </button>
             </div>
 
+            {
+                if current.help_shown > 0 {
+                    html! {
+                        <div style="margin-top: 10px; padding: 8px 10px; border-radius: 12px; border:1px solid #eee; background: rgba(255,215,0,.10);">
+                            { for (1..=current.help_shown).filter_map(|n| qa.help.step(n).map(|text| {
+                                let label = if n == qa.help.steps() { "📖 Solution".to_string() } else { format!("💡 Hint {}", n) };
+                                html! {
+                                    <div style="margin: 2px 0;">
+                                        <b>{format!("{}: ", label)}</b>{text.to_string()}
+                                    </div>
+                                }
+                            })) }
+                        </div>
+                    }
+                } else {
+                    html!{}
+                }
+            }
+
             {
                 if !(*feedback).is_empty() {
                     html! { <div style="margin-top: 8px;">{(*feedback).clone()}</div> }
@@ -1645,4 +4133,4 @@ fn practice_widget(props: &PracticeWidgetProps) -> Html {
             }
         </div>
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,228 @@
+// src/app/adaptive.rs
+//
+// Adaptive difficulty. Each (grade, day, skill) keeps a target level that
+// climbs after a run of correct answers and drops after misses. The level is
+// persisted so the next visit starts where the child left off.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::grade::Grade;
+use super::skill::Skill;
+use super::schema::{load_versioned, save_versioned, Versioned};
+use super::storage::scoped_key;
+
+const KEY: &str = "the_numbers_adaptive_v1";
+const UP_AFTER: i32 = 3; // correct in a row before stepping up
+const DOWN_AFTER: i32 = 2; // misses in a row before stepping down
+
+/// Ordered easiest -> hardest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Level {
+    /// Core items from the grade below (the scaffolded, easier variant).
+    Scaffold,
+    /// Core items only.
+    Core,
+    /// The original mix: 2 stretch items per set.
+    #[default]
+    Mixed,
+    /// Half of each set is stretch.
+    Stretch,
+    /// Items from the next grade's bank for this day.
+    NextGrade,
+}
+
+impl Level {
+    const ORDER: [Level; 5] = [
+        Level::Scaffold,
+        Level::Core,
+        Level::Mixed,
+        Level::Stretch,
+        Level::NextGrade,
+    ];
+
+    fn rank(self) -> i32 {
+        Level::ORDER.iter().position(|l| *l == self).unwrap_or(2) as i32
+    }
+
+    fn from_rank(r: i32) -> Level {
+        Level::ORDER[r.clamp(0, Level::ORDER.len() as i32 - 1) as usize]
+    }
+
+    pub fn up(self) -> Level {
+        Level::from_rank(self.rank() + 1)
+    }
+
+    pub fn down(self) -> Level {
+        Level::from_rank(self.rank() - 1)
+    }
+
+    /// How many slots of a practice set (at the end) are stretch at this level.
+    pub fn stretch_slots(self) -> u32 {
+        match self {
+            Level::Scaffold | Level::Core => 0,
+            Level::Mixed | Level::NextGrade => 2,
+            Level::Stretch => 5,
+        }
+    }
+
+    /// Which grade's bank to draw from. Falls back to `grade` at the ends.
+    pub fn bank_grade(self, grade: Grade) -> Grade {
+        let g = grade.as_u8();
+        match self {
+            Level::Scaffold if g > 1 => Grade::from_u8(g - 1),
+            Level::NextGrade if g < 5 => Grade::from_u8(g + 1),
+            _ => grade,
+        }
+    }
+
+    /// Small chip shown next to the prompt (None for the everyday levels).
+    pub fn chip(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Level::Scaffold => Some(("🪜", "Warm-up question")),
+            Level::NextGrade => Some(("🚀", "Next-grade challenge")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Target {
+    pub level: Level,
+    /// > 0: correct in a row. < 0: misses in a row.
+    pub streak: i32,
+}
+
+impl Target {
+    pub fn record(&mut self, ok: bool) {
+        if ok {
+            self.streak = self.streak.max(0) + 1;
+            if self.streak >= UP_AFTER {
+                self.level = self.level.up();
+                self.streak = 0;
+            }
+        } else {
+            self.streak = self.streak.min(0) - 1;
+            if -self.streak >= DOWN_AFTER {
+                self.level = self.level.down();
+                self.streak = 0;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AdaptiveStore {
+    /// "g{grade}-d{day}" -> per-skill targets.
+    pub days: BTreeMap<String, BTreeMap<Skill, Target>>,
+}
+
+fn day_key(grade: Grade, day_id: usize) -> String {
+    format!("g{}-d{}", grade.as_u8(), day_id)
+}
+
+impl Versioned for AdaptiveStore {
+    const VERSION: u32 = 1;
+}
+
+pub fn load_adaptive() -> AdaptiveStore {
+    load_versioned(&scoped_key(KEY)).unwrap_or_default()
+}
+
+pub fn save_adaptive(store: &AdaptiveStore) {
+    save_versioned(&scoped_key(KEY), store);
+}
+
+/// Level to serve next for a day: the average of its skill targets
+/// (a day mixes skills, and we can't know the skill before picking).
+pub fn day_level(store: &AdaptiveStore, grade: Grade, day_id: usize) -> Level {
+    let Some(skills) = store.days.get(&day_key(grade, day_id)) else {
+        return Level::default();
+    };
+    if skills.is_empty() {
+        return Level::default();
+    }
+    let sum: i32 = skills.values().map(|t| t.level.rank()).sum();
+    let mean = sum as f32 / skills.len() as f32;
+    Level::from_rank(mean.round() as i32)
+}
+
+/// Fold one checked answer into the (grade, day, skill) target and persist.
+/// Returns the day level before and after so the UI can say "leveling up".
+pub fn record_adaptive(grade: Grade, day_id: usize, skill: Skill, ok: bool) -> (Level, Level) {
+    let mut store = load_adaptive();
+    let before = day_level(&store, grade, day_id);
+    store
+        .days
+        .entry(day_key(grade, day_id))
+        .or_default()
+        .entry(skill)
+        .or_default()
+        .record(ok);
+    save_adaptive(&store);
+    (before, day_level(&store, grade, day_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_right_in_a_row_steps_up() {
+        let mut t = Target::default();
+        t.record(true);
+        t.record(true);
+        assert_eq!((t.level, t.streak), (Level::Mixed, 2));
+        t.record(true);
+        assert_eq!((t.level, t.streak), (Level::Stretch, 0));
+    }
+
+    #[test]
+    fn two_misses_in_a_row_step_down() {
+        let mut t = Target::default();
+        t.record(false);
+        assert_eq!((t.level, t.streak), (Level::Mixed, -1));
+        t.record(false);
+        assert_eq!((t.level, t.streak), (Level::Core, 0));
+    }
+
+    #[test]
+    fn a_miss_breaks_a_run_of_right_answers() {
+        let mut t = Target::default();
+        t.record(true);
+        t.record(true);
+        t.record(false);
+        assert_eq!((t.level, t.streak), (Level::Mixed, -1));
+        t.record(true);
+        assert_eq!((t.level, t.streak), (Level::Mixed, 1));
+    }
+
+    #[test]
+    fn levels_stop_at_the_ends() {
+        let mut t = Target { level: Level::NextGrade, streak: 0 };
+        for _ in 0..UP_AFTER {
+            t.record(true);
+        }
+        assert_eq!(t.level, Level::NextGrade);
+
+        let mut t = Target { level: Level::Scaffold, streak: 0 };
+        for _ in 0..DOWN_AFTER {
+            t.record(false);
+        }
+        assert_eq!(t.level, Level::Scaffold);
+    }
+
+    #[test]
+    fn the_day_level_is_the_rounded_mean_of_its_skills() {
+        let mut store = AdaptiveStore::default();
+        assert_eq!(day_level(&store, Grade::G3, 1), Level::Mixed);
+
+        let skills = store.days.entry(day_key(Grade::G3, 1)).or_default();
+        skills.insert(Skill::Addition, Target { level: Level::NextGrade, streak: 0 });
+        skills.insert(Skill::Patterns, Target { level: Level::Stretch, streak: 0 });
+        skills.insert(Skill::Subtraction, Target { level: Level::Core, streak: 0 });
+        assert_eq!(day_level(&store, Grade::G3, 1), Level::Stretch);
+    }
+}
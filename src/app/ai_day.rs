@@ -1,5 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use super::answer::AnswerKind;
+use super::formats::QuestionFormat;
+use super::misconceptions::KnownWrong;
+use super::skill::Skill;
+use super::verify::AnswerCheck;
+use super::visuals::Visual;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Difficulty {
@@ -12,6 +19,30 @@ pub struct AiQuestion {
     pub prompt: String,
     pub answer: String,
     pub difficulty: Difficulty,
+    /// Optional typed answer, e.g. {"type":"money"}. Older payloads omit it.
+    #[serde(default)]
+    pub answer_kind: Option<AnswerKind>,
+    /// Optional skill tag, e.g. "geometry". Untagged items are classified from the prompt.
+    #[serde(default)]
+    pub skill: Option<Skill>,
+    /// Optional hint steps, gentlest first. Missing hints are built locally.
+    #[serde(default)]
+    pub hints: Vec<String>,
+    /// Optional worked solution, shown after the hints.
+    #[serde(default)]
+    pub solution: Option<String>,
+    /// Optional predictable wrong answers, e.g.
+    /// {"answer":"31","misconception":"change_added_not_subtracted","feedback":"…"}.
+    #[serde(default)]
+    pub known_wrong: Vec<KnownWrong>,
+    /// Optional answer format, e.g. {"type":"multiple_choice","choices":["3","4","5"]}.
+    /// Missing or ungradable formats fall back to one picked from the prompt.
+    #[serde(default)]
+    pub format: Option<QuestionFormat>,
+    /// Optional picture, e.g. {"type":"polygon","points":[[0,0],[7,0],[7,3],[0,3]],"labels":["7","3"]}.
+    /// Specs that can't be drawn are ignored.
+    #[serde(default)]
+    pub visual: Option<Visual>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -22,4 +53,39 @@ pub struct DayResponse {
     pub date_ymd: String, // YYYY-MM-DD
     pub items: Vec<AiQuestion>,
     pub source: String, // "ai" | "fallback"
-}
\ No newline at end of file
+    /// Filled in by the client when the set is checked, before it's cached.
+    #[serde(default, skip_serializing_if = "AnswerCheck::is_empty")]
+    pub check: AnswerCheck,
+}
+
+/// GET /api/day-questions/batch: several days (and dates) for one grade.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchResponse {
+    pub version: u32,
+    pub grade: u8,
+    pub days: Vec<DayResponse>,
+}
+
+/// GET /api/capabilities. Workers without it get the defaults: one day per
+/// request, today's set only.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Capabilities {
+    /// GET /api/day-questions/batch?grade=&days=1,2,3&from=&to= is served.
+    pub batch: bool,
+    /// Requests take a date (`date=` per day, `from=`/`to=` per batch), so
+    /// tomorrow's sets can be fetched ahead.
+    pub dated: bool,
+    /// Most sets (days × dates) one batch may ask for.
+    pub max_batch: usize,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities {
+            batch: false,
+            dated: false,
+            max_batch: 12,
+        }
+    }
+}
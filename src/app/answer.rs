@@ -0,0 +1,533 @@
+// src/app/answer.rs
+//
+// Typed answers + a forgiving checker. Kid input is parsed into the same
+// kind of value as the expected answer and compared by value, so "1/2" and
+// "0.5", "$8" and "8", "six" and "6", or "3 cm" and "3" all count.
+//
+// Plain-text comparison (normalize_answer) is still the fallback for words.
+
+use serde::{Deserialize, Serialize};
+
+fn default_tolerance() -> f64 {
+    1e-9
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnswerKind {
+    Integer,
+    Decimal {
+        #[serde(default = "default_tolerance")]
+        tolerance: f64,
+    },
+    Fraction,
+    /// A fraction that has to be written in lowest terms: "3/6" doesn't
+    /// count for 1/2 even though it's the same amount.
+    LowestTerms,
+    Money,
+    TimeOfDay,
+    YesNo,
+    /// A number with a unit ("3 cm"). The unit may be left off, but if the
+    /// child writes one it has to be the right one.
+    Unit {
+        unit: String,
+    },
+    OrderedList,
+    Coordinate,
+    Text,
+}
+
+/* ---------------------------
+   Plain-text fallback
+---------------------------- */
+
+pub fn normalize_answer(s: &str) -> String {
+    s.trim().to_lowercase().replace(' ', "")
+}
+
+pub fn is_correct(guess: &str, correct: &str) -> bool {
+    let g = normalize_answer(guess);
+    correct
+        .split('|')
+        .map(normalize_answer)
+        .any(|c| !c.is_empty() && g == c)
+}
+
+/* ---------------------------
+   Typed checker
+---------------------------- */
+
+/// Check a guess against `expected` (which may hold `|`-separated alternatives).
+/// With no declared kind, the kind is inferred from each alternative.
+pub fn check_answer(guess: &str, expected: &str, kind: Option<&AnswerKind>) -> bool {
+    if guess.trim().is_empty() {
+        return false;
+    }
+
+    expected
+        .split('|')
+        .filter(|alt| !alt.trim().is_empty())
+        .any(|alt| {
+            let k = kind.cloned().unwrap_or_else(|| infer_kind(alt));
+            matches_kind(guess, alt, &k) || is_correct(guess, alt)
+        })
+}
+
+/// The declared kind, unless the prompt asks for a simplified fraction:
+/// then the child's numerator and denominator have to be the reduced ones.
+pub fn kind_for_prompt(prompt: &str, kind: Option<&AnswerKind>) -> Option<AnswerKind> {
+    let p = prompt.to_lowercase();
+    let simplify = ["simplify", "lowest terms", "simplest form"].iter().any(|w| p.contains(w));
+    match kind {
+        None | Some(AnswerKind::Fraction) if simplify => Some(AnswerKind::LowestTerms),
+        _ => kind.cloned(),
+    }
+}
+
+/// Best guess at what kind of answer a plain string is.
+pub fn infer_kind(expected: &str) -> AnswerKind {
+    let t = expected.trim().to_lowercase();
+
+    if parse_yes_no(&t).is_some() {
+        return AnswerKind::YesNo;
+    }
+    if t.starts_with('(') && parse_coordinate(&t).is_some() {
+        return AnswerKind::Coordinate;
+    }
+    // "3:2" is a ratio, not a time (nor a 3 with a ":2" unit).
+    if t.contains(':') {
+        if has_two_digit_minutes(&t) && parse_time(&t).is_some() {
+            return AnswerKind::TimeOfDay;
+        }
+        return AnswerKind::Text;
+    }
+    // "1,000" is one number, not a list.
+    if parse_thousands(&t).is_some() {
+        return if t.contains('.') {
+            AnswerKind::Decimal { tolerance: default_tolerance() }
+        } else {
+            AnswerKind::Integer
+        };
+    }
+    if t.contains(',') && parse_list(&t).len() > 1 {
+        return AnswerKind::OrderedList;
+    }
+    if (t.starts_with('$') || t.ends_with('¢')) && parse_money(&t).is_some() {
+        return AnswerKind::Money;
+    }
+    if t.contains('/') && parse_number(&t).is_some() {
+        return AnswerKind::Fraction;
+    }
+    if let Some((num, Some(unit))) = split_unit(&t) {
+        if parse_number(num).is_some() {
+            return AnswerKind::Unit { unit };
+        }
+    }
+    if t.parse::<i64>().is_ok() {
+        return AnswerKind::Integer;
+    }
+    if let Some(places) = t.split_once('.').map(|(_, frac)| frac.len()) {
+        if t.parse::<f64>().is_ok() {
+            // "2.333" is a rounded repeating value: accept 2.33 too.
+            let tolerance = if places >= 3 { 0.005 } else { default_tolerance() };
+            return AnswerKind::Decimal { tolerance };
+        }
+    }
+
+    AnswerKind::Text
+}
+
+fn matches_kind(guess: &str, expected: &str, kind: &AnswerKind) -> bool {
+    let g = guess.trim().to_lowercase();
+    let e = expected.trim().to_lowercase();
+
+    match kind {
+        AnswerKind::Integer | AnswerKind::Fraction => {
+            both(number_ignoring_unit(&g), number_ignoring_unit(&e), |a, b| close(a, b, 1e-9))
+        }
+        AnswerKind::LowestTerms => {
+            let reduced = match g.split_once('/') {
+                Some((n, d)) => match (n.trim().parse::<i64>(), d.trim().parse::<i64>()) {
+                    (Ok(n), Ok(d)) => d > 0 && gcd(n, d) == 1,
+                    _ => false,
+                },
+                // A whole number is as simple as it gets.
+                None => g.parse::<i64>().is_ok(),
+            };
+            reduced && both(parse_number(&g), parse_number(&e), |a, b| close(a, b, 1e-9))
+        }
+        AnswerKind::Decimal { tolerance } => {
+            both(number_ignoring_unit(&g), number_ignoring_unit(&e), |a, b| close(a, b, *tolerance))
+        }
+        AnswerKind::Money => both(parse_money(&g), parse_money(&e), |a, b| close(a, b, 0.005)),
+        AnswerKind::TimeOfDay => both(parse_time(&g), parse_time(&e), |a, b| {
+            // Only insist on am/pm when both sides say it.
+            match (a.1, b.1) {
+                (true, true) => a.0 == b.0,
+                _ => a.0 % 720 == b.0 % 720,
+            }
+        }),
+        AnswerKind::YesNo => both(parse_yes_no(&g), parse_yes_no(&e), |a, b| a == b),
+        AnswerKind::Unit { unit } => match split_unit(&g) {
+            Some((num, written)) => {
+                let unit_ok = written.is_none_or(|w| canonical_unit(&w) == canonical_unit(unit));
+                unit_ok && both(parse_number(num), number_ignoring_unit(&e), |a, b| close(a, b, 1e-9))
+            }
+            None => false,
+        },
+        AnswerKind::OrderedList => {
+            let (gl, el) = (parse_list(&g), parse_list(&e));
+            gl.len() == el.len() && gl.iter().zip(el.iter()).all(|(a, b)| same_item(a, b))
+        }
+        AnswerKind::Coordinate => both(parse_coordinate(&g), parse_coordinate(&e), |a, b| {
+            close(a.0, b.0, 1e-9) && close(a.1, b.1, 1e-9)
+        }),
+        AnswerKind::Text => {
+            let strip = |s: &str| -> String {
+                let s = s.trim();
+                let s = ["a ", "an ", "the "]
+                    .iter()
+                    .find_map(|art| s.strip_prefix(art))
+                    .unwrap_or(s);
+                normalize_answer(s)
+            };
+            strip(&g) == strip(&e)
+        }
+    }
+}
+
+fn both<T>(a: Option<T>, b: Option<T>, f: impl Fn(T, T) -> bool) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => f(a, b),
+        _ => false,
+    }
+}
+
+fn close(a: f64, b: f64, tolerance: f64) -> bool {
+    (a - b).abs() <= tolerance.max(1e-9)
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn same_item(a: &str, b: &str) -> bool {
+    match (parse_number(a), parse_number(b)) {
+        (Some(x), Some(y)) => close(x, y, 1e-9),
+        _ => normalize_answer(a) == normalize_answer(b),
+    }
+}
+
+/* ---------------------------
+   Parsers
+---------------------------- */
+
+/// Integers, decimals, fractions ("3/4"), mixed numbers ("1 1/2") and
+/// number words ("six", "twenty-one").
+pub fn parse_number(s: &str) -> Option<f64> {
+    let t = s.trim().replace('−', "-");
+    let t = t.strip_prefix('+').unwrap_or(&t).trim();
+    if t.is_empty() {
+        return None;
+    }
+
+    if let Ok(v) = t.parse::<f64>() {
+        return v.is_finite().then_some(v);
+    }
+    if let Some(v) = parse_thousands(t) {
+        return Some(v);
+    }
+
+    if let Some((whole, frac)) = t.split_once(' ') {
+        if let (Ok(w), Some(f)) = (whole.trim().parse::<i64>(), parse_fraction(frac.trim())) {
+            let sign = if w < 0 { -1.0 } else { 1.0 };
+            return Some(w as f64 + sign * f);
+        }
+    }
+
+    parse_fraction(t).or_else(|| parse_number_words(t))
+}
+
+/// "1,000" / "-12,345.5": commas only between groups of three digits.
+fn parse_thousands(t: &str) -> Option<f64> {
+    let body = t.strip_prefix('-').unwrap_or(t);
+    let (int, frac) = match body.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (body, None),
+    };
+    let digits = |g: &str| !g.is_empty() && g.chars().all(|c| c.is_ascii_digit());
+
+    let mut groups = int.split(',');
+    let first = groups.next()?;
+    let rest: Vec<&str> = groups.collect();
+    let ok = !rest.is_empty()
+        && first.len() <= 3
+        && digits(first)
+        && rest.iter().all(|g| g.len() == 3 && digits(g))
+        && frac.is_none_or(digits);
+    if !ok {
+        return None;
+    }
+    t.replace(',', "").parse().ok()
+}
+
+/// "3:05" / "10:30 am", but not "3:2".
+fn has_two_digit_minutes(t: &str) -> bool {
+    let Some((_, after)) = t.split_once(':') else {
+        return false;
+    };
+    let minutes: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+    minutes.len() == 2
+}
+
+fn parse_fraction(t: &str) -> Option<f64> {
+    let (n, d) = t.split_once('/')?;
+    let n: f64 = n.trim().parse().ok()?;
+    let d: f64 = d.trim().parse().ok()?;
+    (d != 0.0).then_some(n / d)
+}
+
+fn parse_number_words(t: &str) -> Option<f64> {
+    const ONES: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    let (negative, t) = match t.strip_prefix("negative ").or_else(|| t.strip_prefix("minus ")) {
+        Some(rest) => (true, rest),
+        None => (false, t),
+    };
+
+    let mut total = 0.0;
+    let mut any = false;
+    for word in t.split([' ', '-']).filter(|w| !w.is_empty() && *w != "and") {
+        if let Some(i) = ONES.iter().position(|w| *w == word) {
+            total += i as f64;
+        } else if let Some(i) = TENS.iter().position(|w| *w == word) {
+            total += (20 + 10 * i) as f64;
+        } else if word == "hundred" {
+            total = total.max(1.0) * 100.0;
+        } else {
+            return None;
+        }
+        any = true;
+    }
+
+    any.then_some(if negative { -total } else { total })
+}
+
+/// Split "3 cm" / "3cm" into ("3", Some("cm")). Plain numbers give (s, None).
+fn split_unit(s: &str) -> Option<(&str, Option<String>)> {
+    let t = s.trim();
+    let rest = t.trim_start_matches(|c: char| c.is_ascii_digit() || ".-+/ −".contains(c));
+    let num = t[..t.len() - rest.len()].trim();
+    if num.is_empty() || !num.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let unit = rest.trim().trim_end_matches('.');
+    Some((num, (!unit.is_empty()).then(|| unit.to_string())))
+}
+
+/// A number with any unit or "$" around it ignored.
+pub fn number_ignoring_unit(s: &str) -> Option<f64> {
+    parse_number(s)
+        .or_else(|| split_unit(s).and_then(|(num, _)| parse_number(num)))
+        .or_else(|| s.trim().starts_with('$').then(|| parse_money(s)).flatten())
+}
+
+fn canonical_unit(u: &str) -> String {
+    const ALIASES: [(&str, &[&str]); 12] = [
+        ("cm", &["cm", "cms", "centimeter", "centimeters", "centimetre", "centimetres"]),
+        ("m", &["m", "meter", "meters", "metre", "metres"]),
+        ("km", &["km", "kilometer", "kilometers", "kilometre", "kilometres"]),
+        ("in", &["in", "inch", "inches"]),
+        ("ft", &["ft", "foot", "feet"]),
+        ("mi", &["mi", "mile", "miles"]),
+        ("kg", &["kg", "kilogram", "kilograms"]),
+        ("g", &["g", "gram", "grams"]),
+        ("l", &["l", "liter", "liters", "litre", "litres"]),
+        ("min", &["min", "mins", "minute", "minutes"]),
+        ("h", &["h", "hr", "hrs", "hour", "hours"]),
+        ("deg", &["°", "deg", "degree", "degrees"]),
+    ];
+
+    let u = u.trim().to_lowercase();
+    ALIASES
+        .iter()
+        .find(|(_, names)| names.contains(&u.as_str()))
+        .map(|(canon, _)| canon.to_string())
+        .unwrap_or(u)
+}
+
+/// Dollars. "$8", "8 dollars", "$8.00", "50¢" and "50 cents" all parse.
+pub fn parse_money(s: &str) -> Option<f64> {
+    let t = s.trim().to_lowercase().replace(' ', "");
+    if let Some(cents) = t
+        .strip_suffix('¢')
+        .or_else(|| t.strip_suffix("cents"))
+        .or_else(|| t.strip_suffix("cent"))
+    {
+        return parse_number(cents).map(|c| c / 100.0);
+    }
+    let t = t.strip_prefix('$').unwrap_or(&t);
+    let t = t
+        .strip_suffix("dollars")
+        .or_else(|| t.strip_suffix("dollar"))
+        .unwrap_or(t);
+    parse_number(t)
+}
+
+/// Minutes after midnight, plus whether am/pm was given.
+/// Accepts "3:30", "3:30 pm", "15:30", "3pm" and "3 o'clock".
+pub fn parse_time(s: &str) -> Option<(u32, bool)> {
+    let t = s.trim().to_lowercase().replace(['.', ' '], "");
+    let t = t.strip_suffix("o'clock").unwrap_or(&t);
+    let (body, meridiem) = if let Some(b) = t.strip_suffix("am") {
+        (b, Some(false))
+    } else if let Some(b) = t.strip_suffix("pm") {
+        (b, Some(true))
+    } else {
+        (t, None)
+    };
+
+    let (h, m) = match body.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        None => (body.parse::<u32>().ok()?, 0),
+    };
+    if m >= 60 || h >= 24 || (meridiem.is_some() && !(1..=12).contains(&h)) {
+        return None;
+    }
+
+    let h = match meridiem {
+        Some(true) if h < 12 => h + 12,
+        Some(false) if h == 12 => 0,
+        _ => h,
+    };
+    Some((h * 60 + m, meridiem.is_some()))
+}
+
+pub fn parse_yes_no(s: &str) -> Option<bool> {
+    match s.trim().to_lowercase().trim_end_matches(['.', '!']) {
+        "yes" | "y" | "yeah" | "yep" | "true" | "t" => Some(true),
+        "no" | "n" | "nope" | "false" | "f" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_list(s: &str) -> Vec<String> {
+    s.split([',', ';'])
+        .flat_map(|part| part.split_whitespace())
+        .filter(|w| !w.is_empty() && *w != "and")
+        .map(|w| w.to_string())
+        .collect()
+}
+
+pub fn parse_coordinate(s: &str) -> Option<(f64, f64)> {
+    let t = s.trim().trim_start_matches('(').trim_end_matches(')');
+    let parts: Vec<&str> = t
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    match parts.as_slice() {
+        [x, y] => Some((parse_number(x)?, parse_number(y)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(guess: &str, expected: &str) -> bool {
+        check_answer(guess, expected, None)
+    }
+
+    #[test]
+    fn equal_values_in_other_forms_count() {
+        assert!(ok("0.5", "1/2"));
+        assert!(ok("2/4", "1/2"));
+        assert!(ok("1 1/2", "1.5"));
+        assert!(ok("six", "6"));
+        assert!(ok("twenty-one", "21"));
+        assert!(ok("$8", "8"));
+        assert!(ok("8 dollars", "$8.00"));
+        assert!(ok("50 cents", "$0.50"));
+        assert!(ok("15:30", "3:30 pm"));
+        assert!(ok("3:30", "3:30 pm"));
+        assert!(ok("Yes", "yes"));
+        assert!(ok("(2, 3)", "(2,3)"));
+        assert!(ok("1, 2, 3", "1,2,3"));
+        assert!(ok("2.33", "2.333"));
+    }
+
+    #[test]
+    fn wrong_or_empty_answers_dont() {
+        assert!(!ok("", "6"));
+        assert!(!ok("  ", "6"));
+        assert!(!ok("7", "6"));
+        assert!(!ok("3:30 am", "3:30 pm"));
+        assert!(!ok("no", "yes"));
+        assert!(!ok("3, 2, 1", "1,2,3"));
+        assert!(!ok("2.3", "2.333"));
+    }
+
+    #[test]
+    fn thousands_separators_make_one_number() {
+        assert_eq!(infer_kind("1,000"), AnswerKind::Integer);
+        assert!(ok("1000", "1,000"));
+        assert!(ok("1,000", "1000"));
+        assert!(ok("12345.5", "12,345.5"));
+        assert!(!ok("100", "1,000"));
+        // Groups that aren't three digits are still a list.
+        assert_eq!(infer_kind("1,2,3"), AnswerKind::OrderedList);
+        assert_eq!(infer_kind("10,20"), AnswerKind::OrderedList);
+    }
+
+    #[test]
+    fn only_two_digit_minutes_are_a_time() {
+        assert_eq!(infer_kind("3:05"), AnswerKind::TimeOfDay);
+        assert_eq!(infer_kind("10:30 am"), AnswerKind::TimeOfDay);
+        assert_eq!(infer_kind("3:2"), AnswerKind::Text);
+        assert!(ok("3:2", "3:2"));
+        assert!(!ok("3:02", "3:2"));
+    }
+
+    #[test]
+    fn alternatives_are_each_checked() {
+        assert!(ok("square", "square|rectangle"));
+        assert!(ok("a rectangle", "square|rectangle"));
+        assert!(!ok("circle", "square|rectangle"));
+    }
+
+    #[test]
+    fn units_may_be_left_off_but_not_wrong() {
+        let cm = AnswerKind::Unit { unit: "cm".to_string() };
+        assert!(check_answer("3", "3 cm", Some(&cm)));
+        assert!(check_answer("3 centimeters", "3 cm", Some(&cm)));
+        assert!(!check_answer("3 m", "3 cm", Some(&cm)));
+    }
+
+    #[test]
+    fn simplify_prompts_need_lowest_terms() {
+        let kind = kind_for_prompt("Stretch: Simplify 3/6 to lowest terms", None);
+        assert_eq!(kind, Some(AnswerKind::LowestTerms));
+        assert!(check_answer("1/2", "1/2", kind.as_ref()));
+        assert!(!check_answer("3/6", "1/2", kind.as_ref()));
+        assert!(!check_answer("0.5", "1/2", kind.as_ref()));
+        assert!(check_answer("2", "4/2", kind.as_ref()));
+
+        // Declared kinds other than a fraction are left alone.
+        let money = AnswerKind::Money;
+        assert_eq!(kind_for_prompt("Simplify: $3 + $2", Some(&money)), Some(money));
+        assert_eq!(kind_for_prompt("3/6 = __/2", None), None);
+    }
+}
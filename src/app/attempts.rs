@@ -0,0 +1,224 @@
+// src/app/attempts.rs
+//
+// Append-only log of every checked answer. WeeklyStats keeps the counters;
+// this keeps the detail (what was asked, what was typed, how long it took).
+
+use serde::{Deserialize, Serialize};
+
+use super::misconceptions::Misconception;
+use super::skill::Skill;
+use super::schema::{load_versioned, save_versioned, Versioned};
+use super::storage::scoped_key;
+
+const KEY: &str = "the_numbers_attempts_v1";
+const MAX_DAYS_TO_KEEP: usize = 45; // same window as the weekly stats
+const MAX_ATTEMPTS_TO_KEEP: usize = 1500; // ~300 KB worst case
+const MAX_TEXT_LEN: usize = 200; // prompts/answers are short; guards against pasted essays
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptSource {
+    Ai,
+    Local,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Milliseconds since the epoch (Date.now()).
+    pub ts_ms: f64,
+    pub ymd: String,
+    pub day_id: usize,
+    pub grade: u8,
+    pub skill: Skill,
+    pub prompt: String,
+    pub expected: String,
+    pub given: String,
+    pub correct: bool,
+    pub source: AttemptSource,
+    pub stretch: bool,
+    /// Time from the question appearing to this check.
+    pub ms_to_answer: u32,
+    /// 1 for the first check of a question, 2 for the second, …
+    pub try_no: u32,
+    /// Hint steps (the solution counts as one) showing at this check.
+    #[serde(default)]
+    pub hints_used: u32,
+    /// Set when the answer was one of the question's known wrong answers.
+    #[serde(default)]
+    pub misconception: Option<Misconception>,
+    /// Seed of the practice set the question came from (0 outside a set,
+    /// e.g. the review page, and in older logs).
+    #[serde(default)]
+    pub seed: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttemptLog {
+    /// Oldest first.
+    pub attempts: Vec<Attempt>,
+}
+
+/// A question that was missed at least once, grouped by prompt.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissedQuestion {
+    pub prompt: String,
+    pub expected: String,
+    pub day_id: usize,
+    pub misses: u32,
+    pub attempts: u32,
+    /// The most recent wrong answer typed.
+    pub last_wrong: String,
+}
+
+impl Versioned for AttemptLog {
+    const VERSION: u32 = 1;
+}
+
+pub fn load_attempts() -> AttemptLog {
+    load_versioned(&scoped_key(KEY)).unwrap_or_default()
+}
+
+pub fn save_attempts(log: &AttemptLog) {
+    save_versioned(&scoped_key(KEY), log);
+}
+
+fn clip(s: &str) -> String {
+    s.trim().chars().take(MAX_TEXT_LEN).collect()
+}
+
+/// Retention: drop anything older than the newest MAX_DAYS_TO_KEEP dates,
+/// then keep at most MAX_ATTEMPTS_TO_KEEP entries (newest win).
+fn compact(log: &mut AttemptLog) {
+    let mut dates: Vec<&str> = log.attempts.iter().map(|a| a.ymd.as_str()).collect();
+    dates.sort_unstable();
+    dates.dedup();
+    if dates.len() > MAX_DAYS_TO_KEEP {
+        let oldest = dates[dates.len() - MAX_DAYS_TO_KEEP].to_string();
+        log.attempts.retain(|a| a.ymd >= oldest);
+    }
+
+    if log.attempts.len() > MAX_ATTEMPTS_TO_KEEP {
+        let start = log.attempts.len() - MAX_ATTEMPTS_TO_KEEP;
+        log.attempts.drain(..start);
+    }
+}
+
+/// Append one attempt and persist (load → push → compact → save).
+pub fn append_attempt(mut a: Attempt) {
+    a.prompt = clip(&a.prompt);
+    a.expected = clip(&a.expected);
+    a.given = clip(&a.given);
+
+    let mut log = load_attempts();
+    log.attempts.push(a);
+    compact(&mut log);
+    save_attempts(&log);
+}
+
+/// Questions missed most often on or after `since_ymd`, worst first.
+/// Ties go to the question missed most recently.
+pub fn most_missed(log: &AttemptLog, since_ymd: &str, n: usize) -> Vec<MissedQuestion> {
+    let mut out: Vec<(MissedQuestion, f64)> = Vec::new();
+
+    for a in log.attempts.iter().filter(|a| a.ymd.as_str() >= since_ymd) {
+        let idx = match out.iter().position(|(m, _)| m.prompt == a.prompt) {
+            Some(i) => i,
+            None => {
+                out.push((
+                    MissedQuestion {
+                        prompt: a.prompt.clone(),
+                        expected: a.expected.clone(),
+                        day_id: a.day_id,
+                        misses: 0,
+                        attempts: 0,
+                        last_wrong: String::new(),
+                    },
+                    0.0,
+                ));
+                out.len() - 1
+            }
+        };
+
+        let (m, last_miss_ts) = &mut out[idx];
+        m.attempts += 1;
+        if !a.correct {
+            m.misses += 1;
+            m.last_wrong = a.given.clone();
+            *last_miss_ts = a.ts_ms;
+        }
+    }
+
+    out.retain(|(m, _)| m.misses > 0);
+    out.sort_by(|(a, ta), (b, tb)| {
+        b.misses
+            .cmp(&a.misses)
+            .then_with(|| tb.partial_cmp(ta).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    out.into_iter().take(n).map(|(m, _)| m).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(ymd: &str, ts_ms: f64) -> Attempt {
+        Attempt {
+            ts_ms,
+            ymd: ymd.to_string(),
+            day_id: 1,
+            grade: 3,
+            skill: Skill::Addition,
+            prompt: "2 + 3 = ?".to_string(),
+            expected: "5".to_string(),
+            given: "5".to_string(),
+            correct: true,
+            source: AttemptSource::Local,
+            stretch: false,
+            ms_to_answer: 1000,
+            try_no: 1,
+            hints_used: 0,
+            misconception: None,
+            seed: 0,
+        }
+    }
+
+    fn ymd(n: usize) -> String {
+        format!("2026-{:02}-{:02}", 1 + n / 28, 1 + n % 28)
+    }
+
+    #[test]
+    fn compact_keeps_only_the_newest_dates() {
+        let mut log = AttemptLog {
+            attempts: (0..MAX_DAYS_TO_KEEP + 5)
+                .map(|n| attempt(&ymd(n), n as f64))
+                .collect(),
+        };
+        compact(&mut log);
+
+        assert_eq!(log.attempts.len(), MAX_DAYS_TO_KEEP);
+        assert_eq!(log.attempts[0].ymd, ymd(5));
+        assert_eq!(log.attempts.last().unwrap().ymd, ymd(MAX_DAYS_TO_KEEP + 4));
+    }
+
+    #[test]
+    fn compact_caps_the_attempt_count_keeping_the_newest() {
+        let mut log = AttemptLog {
+            attempts: (0..MAX_ATTEMPTS_TO_KEEP + 10)
+                .map(|n| attempt("2026-10-18", n as f64))
+                .collect(),
+        };
+        compact(&mut log);
+
+        assert_eq!(log.attempts.len(), MAX_ATTEMPTS_TO_KEEP);
+        assert_eq!(log.attempts[0].ts_ms, 10.0);
+    }
+
+    #[test]
+    fn compact_leaves_a_small_log_alone() {
+        let mut log = AttemptLog {
+            attempts: vec![attempt("2026-10-17", 1.0), attempt("2026-10-18", 2.0)],
+        };
+        compact(&mut log);
+        assert_eq!(log.attempts.len(), 2);
+    }
+}
@@ -0,0 +1,419 @@
+// src/app/backup.rs
+//
+// Full backup of one learner as a single JSON document, plus import by
+// merge (combine with what's here) or replace (overwrite what's here).
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::adaptive::{load_adaptive, save_adaptive, AdaptiveStore};
+use super::ai_day::DayResponse;
+use super::attempts::{load_attempts, save_attempts, AttemptLog};
+use super::grade::Grade;
+use super::review::{load_review, save_review, ReviewQueue};
+use super::schema::decode;
+use super::stats::{load_stats, save_stats, WeeklyStats};
+use super::storage::{load_grade, load_progress, save_grade, save_progress, Progress};
+use super::{
+    clear_day_caches, is_sound_enabled, load_day_from_local_storage, save_day_to_local_storage, set_sound_enabled,
+};
+
+pub const BACKUP_FORMAT: &str = "the-numbers-backup";
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Everything stored for one learner.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LearnerData {
+    pub progress: Progress,
+    pub grade: u8,
+    pub stats: WeeklyStats,
+    #[serde(default)]
+    pub attempts: AttemptLog,
+    #[serde(default)]
+    pub review: ReviewQueue,
+    #[serde(default)]
+    pub adaptive: AdaptiveStore,
+    #[serde(default)]
+    pub day_caches: Vec<DayResponse>,
+    #[serde(default = "default_sound")]
+    pub sound: bool,
+}
+
+fn default_sound() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Backup {
+    pub format: String,
+    pub version: u32,
+    /// YYYY-MM-DD
+    pub exported_ymd: String,
+    pub learner_name: String,
+    pub data: LearnerData,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImportMode {
+    Merge,
+    Replace,
+}
+
+// ----------------------------
+// Load / save the active learner
+// ----------------------------
+
+pub fn load_learner_data() -> LearnerData {
+    let day_caches = (1..=5u8)
+        .flat_map(|g| (1..=12usize).map(move |d| (Grade::from_u8(g), d)))
+        .filter_map(|(g, d)| load_day_from_local_storage(g, d))
+        .collect();
+
+    LearnerData {
+        progress: load_progress(),
+        grade: load_grade().as_u8(),
+        stats: load_stats(),
+        attempts: load_attempts(),
+        review: load_review(),
+        adaptive: load_adaptive(),
+        day_caches,
+        sound: is_sound_enabled(),
+    }
+}
+
+fn save_learner_data(d: &LearnerData) {
+    save_progress(&d.progress);
+    save_grade(Grade::from_u8(d.grade));
+    save_stats(&d.stats);
+    save_attempts(&d.attempts);
+    save_review(&d.review);
+    save_adaptive(&d.adaptive);
+    for day in d.day_caches.iter() {
+        save_day_to_local_storage(Grade::from_u8(day.grade), day.day_id, day);
+    }
+    set_sound_enabled(d.sound);
+}
+
+pub fn export_backup(learner_name: &str, today: &str) -> Option<String> {
+    let b = Backup {
+        format: BACKUP_FORMAT.to_string(),
+        version: BACKUP_VERSION,
+        exported_ymd: today.to_string(),
+        learner_name: learner_name.to_string(),
+        data: load_learner_data(),
+    };
+    serde_json::to_string_pretty(&b).ok()
+}
+
+// ----------------------------
+// Validate
+// ----------------------------
+
+fn is_ymd(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && b.iter().enumerate().all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit())
+}
+
+/// Parse and sanity-check a backup file. Errors are short, parent-readable sentences.
+pub fn parse_backup(raw: &str) -> Result<Backup, String> {
+    let v: Value = serde_json::from_str(raw).map_err(|_| "This file isn't valid JSON.".to_string())?;
+    if v.get("format").and_then(Value::as_str) != Some(BACKUP_FORMAT) {
+        return Err("This isn't a THE NUMBERS backup file.".to_string());
+    }
+    let version = v.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+    if version == 0 || version > BACKUP_VERSION {
+        return Err(format!(
+            "This backup is format v{}, but this app reads up to v{}. Update the app first.",
+            version, BACKUP_VERSION
+        ));
+    }
+
+    // Stats inside the backup may be from an older schema: run them through the
+    // same migrations as localStorage (bare stats count as schema v1).
+    let mut v = v;
+    if let Some(stats) = v.pointer_mut("/data/stats") {
+        let raw_stats = serde_json::to_string(stats).map_err(|e| e.to_string())?;
+        let (st, _) = decode::<WeeklyStats>(&raw_stats)
+            .map_err(|e| format!("The stats in this backup couldn't be read ({}).", e))?;
+        *stats = serde_json::to_value(st).map_err(|e| e.to_string())?;
+    }
+
+    let b: Backup =
+        serde_json::from_value(v).map_err(|e| format!("The backup is missing data ({}).", e))?;
+
+    if !(1..=5).contains(&b.data.grade) {
+        return Err(format!("Grade {} in this backup isn't supported.", b.data.grade));
+    }
+    if let Some((bad, _)) = b.data.stats.days.iter().find(|(ymd, _)| !is_ymd(ymd)) {
+        return Err(format!("The backup has an invalid date: {}", bad));
+    }
+    if b.data.progress.completed.iter().any(|d| !(1..=12).contains(d)) {
+        return Err("The backup lists a day that doesn't exist.".to_string());
+    }
+    Ok(b)
+}
+
+// ----------------------------
+// Merge / replace
+// ----------------------------
+
+/// Combine `incoming` into `current`: a union that keeps the entry with more
+/// evidence when both sides have it. A date on both sides keeps the side with
+/// more attempts (adding them up would double-count a backup of this device).
+pub fn merge(current: &LearnerData, incoming: &LearnerData) -> LearnerData {
+    let mut out = current.clone();
+
+    for d in incoming.progress.completed.iter() {
+        if !out.progress.completed.contains(d) {
+            out.progress.completed.push(*d);
+        }
+    }
+    out.progress.completed.sort_unstable();
+
+    // Stats: per-date counts, the busier side wins
+    for (ymd, counts) in incoming.stats.days.iter() {
+        match out.stats.days.iter_mut().find(|(d, _)| d == ymd) {
+            Some((_, mine)) if counts.total().attempts > mine.total().attempts => *mine = counts.clone(),
+            Some(_) => {}
+            None => out.stats.days.push((ymd.clone(), counts.clone())),
+        }
+    }
+    out.stats.days.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for s in incoming.stats.sessions.iter() {
+        let existing = out
+            .stats
+            .sessions
+            .iter_mut()
+            .find(|m| m.seed == s.seed && m.day_id == s.day_id && m.grade == s.grade && m.ymd == s.ymd);
+        match existing {
+            Some(m) if s.attempts > m.attempts => *m = s.clone(),
+            Some(_) => {}
+            None => out.stats.sessions.push(s.clone()),
+        }
+    }
+    out.stats.sessions.sort_by(|a, b| a.ymd.cmp(&b.ymd));
+
+    for t in incoming.stats.timed.iter() {
+        if !out.stats.timed.iter().any(|m| m.started_ms == t.started_ms && m.day_id == t.day_id) {
+            out.stats.timed.push(t.clone());
+        }
+    }
+    out.stats
+        .timed
+        .sort_by(|a, b| a.started_ms.partial_cmp(&b.started_ms).unwrap_or(std::cmp::Ordering::Equal));
+
+    for (skill, m) in incoming.stats.mastery.iter() {
+        let keep_mine = out
+            .stats
+            .mastery
+            .get(skill)
+            .is_some_and(|mine| mine.observations >= m.observations);
+        if !keep_mine {
+            out.stats.mastery.insert(*skill, m.clone());
+        }
+    }
+
+    // Attempt log: union (same timestamp + prompt = same attempt)
+    for a in incoming.attempts.attempts.iter() {
+        let dup = out
+            .attempts
+            .attempts
+            .iter()
+            .any(|m| m.ts_ms == a.ts_ms && m.prompt == a.prompt);
+        if !dup {
+            out.attempts.attempts.push(a.clone());
+        }
+    }
+    out.attempts
+        .attempts
+        .sort_by(|a, b| a.ts_ms.partial_cmp(&b.ts_ms).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Review queue: union, keep the more-reviewed copy
+    for item in incoming.review.items.iter() {
+        let existing = out
+            .review
+            .items
+            .iter_mut()
+            .find(|m| m.same_question(&item.prompt, &item.answer, item.day_id, item.grade));
+        match existing {
+            Some(m) if item.reviews > m.reviews => *m = item.clone(),
+            Some(_) => {}
+            None => out.review.items.push(item.clone()),
+        }
+    }
+
+    // Adaptive targets: fill in days/skills we don't have yet
+    for (day, skills) in incoming.adaptive.days.iter() {
+        let mine = out.adaptive.days.entry(day.clone()).or_default();
+        for (skill, t) in skills.iter() {
+            mine.entry(*skill).or_insert_with(|| t.clone());
+        }
+    }
+
+    // Day caches: newer date wins
+    for day in incoming.day_caches.iter() {
+        match out
+            .day_caches
+            .iter_mut()
+            .find(|m| m.grade == day.grade && m.day_id == day.day_id)
+        {
+            Some(m) if day.date_ymd > m.date_ymd => *m = day.clone(),
+            Some(_) => {}
+            None => out.day_caches.push(day.clone()),
+        }
+    }
+
+    // Grade + settings stay as they are on this device.
+    out
+}
+
+/// What applying the import would change, as short lines for the preview.
+pub fn preview(current: &LearnerData, incoming: &Backup, mode: ImportMode) -> Vec<String> {
+    let inc = &incoming.data;
+    let mut lines = vec![format!(
+        "Backup of {} from {} • {} practice dates • {} attempts logged",
+        incoming.learner_name,
+        incoming.exported_ymd,
+        inc.stats.days.len(),
+        inc.attempts.attempts.len()
+    )];
+
+    match mode {
+        ImportMode::Replace => {
+            lines.push(format!(
+                "Replace: this learner's {} practice dates, {} completed days and {} review items will be overwritten.",
+                current.stats.days.len(),
+                current.progress.completed.len(),
+                current.review.items.len()
+            ));
+            if current.grade != inc.grade {
+                lines.push(format!("Grade changes from {} to {}.", current.grade, inc.grade));
+            }
+        }
+        ImportMode::Merge => {
+            let mine: BTreeSet<&str> = current.stats.days.iter().map(|(d, _)| d.as_str()).collect();
+            let theirs: BTreeSet<&str> = inc.stats.days.iter().map(|(d, _)| d.as_str()).collect();
+            let new_dates = theirs.difference(&mine).count();
+            let overlap = theirs.intersection(&mine).count();
+            let merged = merge(current, inc);
+
+            lines.push(format!("Merge: adds {} new practice dates.", new_dates));
+            if overlap > 0 {
+                lines.push(format!(
+                    "{} dates exist on both sides; each keeps the side with more practice that day.",
+                    overlap
+                ));
+            }
+            lines.push(format!(
+                "Completed days: {} → {} • Attempt log: {} → {} • Review items: {} → {}",
+                current.progress.completed.len(),
+                merged.progress.completed.len(),
+                current.attempts.attempts.len(),
+                merged.attempts.attempts.len(),
+                current.review.items.len(),
+                merged.review.items.len()
+            ));
+            lines.push("Grade and sound settings on this device stay as they are.".to_string());
+        }
+    }
+    lines
+}
+
+pub fn apply_import(incoming: &Backup, mode: ImportMode) {
+    let data = match mode {
+        ImportMode::Replace => {
+            // Sets the backup doesn't have would otherwise outlive the replace.
+            clear_day_caches();
+            incoming.data.clone()
+        }
+        ImportMode::Merge => merge(&load_learner_data(), &incoming.data),
+    };
+    save_learner_data(&data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::attempts::{Attempt, AttemptSource};
+    use crate::app::mastery::Mastery;
+    use crate::app::skill::Skill;
+    use crate::app::stats::{DayCounts, SessionRecord};
+
+    fn day(attempts: u32, correct: u32) -> DayCounts {
+        let mut d = DayCounts::default();
+        for i in 0..attempts {
+            d.skill_mut(Skill::Addition).record(i < correct);
+        }
+        d
+    }
+
+    fn sample() -> LearnerData {
+        let mut d = LearnerData {
+            grade: 3,
+            ..LearnerData::default()
+        };
+        d.progress.completed = vec![1, 2];
+        d.stats.days = vec![("2026-10-17".to_string(), day(5, 4)), ("2026-10-18".to_string(), day(3, 1))];
+        d.stats.sessions.push(SessionRecord {
+            ymd: "2026-10-18".to_string(),
+            day_id: 2,
+            grade: 3,
+            seed: 99,
+            questions: 3,
+            attempts: 3,
+            correct: 1,
+            ..SessionRecord::default()
+        });
+        let mut m = Mastery::default();
+        m.update(true);
+        d.stats.mastery.insert(Skill::Addition, m);
+        d.attempts.attempts.push(Attempt {
+            ts_ms: 1_000.0,
+            ymd: "2026-10-18".to_string(),
+            day_id: 2,
+            grade: 3,
+            skill: Skill::Addition,
+            prompt: "7 + 5 = __".to_string(),
+            expected: "12".to_string(),
+            given: "13".to_string(),
+            correct: false,
+            source: AttemptSource::Local,
+            stretch: false,
+            ms_to_answer: 4_000,
+            try_no: 1,
+            hints_used: 0,
+            misconception: None,
+            seed: 99,
+        });
+        d
+    }
+
+    fn json(d: &LearnerData) -> Value {
+        serde_json::to_value(d).unwrap()
+    }
+
+    #[test]
+    fn merging_a_backup_into_itself_changes_nothing() {
+        let d = sample();
+        assert_eq!(json(&merge(&d, &d)), json(&d));
+    }
+
+    #[test]
+    fn overlapping_dates_keep_the_busier_side() {
+        let mine = sample();
+        let mut theirs = sample();
+        theirs.stats.days = vec![("2026-10-18".to_string(), day(8, 8)), ("2026-10-19".to_string(), day(2, 2))];
+        theirs.stats.days.insert(0, ("2026-10-17".to_string(), day(1, 1)));
+
+        let merged = merge(&mine, &theirs);
+        let attempts: Vec<(&str, u32)> =
+            merged.stats.days.iter().map(|(ymd, c)| (ymd.as_str(), c.total().attempts)).collect();
+        assert_eq!(attempts, vec![("2026-10-17", 5), ("2026-10-18", 8), ("2026-10-19", 2)]);
+    }
+}
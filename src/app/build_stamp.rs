@@ -0,0 +1,66 @@
+// src/app/build_stamp.rs
+//
+// Trunk post_build hook (native builds only): `cargo run -- stamp-sw`.
+//
+// Replaces the BUILD placeholder in the staged sw.js with a hash of every
+// other file Trunk staged. A new build therefore gets a new cache name, and
+// browsers with the app installed see that sw.js changed and fetch the
+// update. Identical builds get the same stamp.
+
+use std::path::{Path, PathBuf};
+
+use super::session::fnv1a;
+
+const PLACEHOLDER: &str = "__BUILD__";
+const SERVICE_WORKER: &str = "sw.js";
+
+/// Entry point for `stamp-sw`; Trunk passes the staging dir in the environment.
+pub fn main() {
+    let Some(dir) = std::env::var_os("TRUNK_STAGING_DIR").map(PathBuf::from) else {
+        eprintln!("stamp-sw runs as a Trunk hook (TRUNK_STAGING_DIR isn't set)");
+        std::process::exit(2);
+    };
+    match stamp(&dir) {
+        Ok(build) => println!("{SERVICE_WORKER} stamped with build {build}"),
+        Err(why) => {
+            eprintln!("stamp-sw: {why}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn stamp(dir: &Path) -> Result<String, String> {
+    let sw_path = dir.join(SERVICE_WORKER);
+    let sw = std::fs::read_to_string(&sw_path).map_err(|e| format!("can't read {}: {e}", sw_path.display()))?;
+    if !sw.contains(PLACEHOLDER) {
+        return Err(format!("{SERVICE_WORKER} has no {PLACEHOLDER} to replace"));
+    }
+
+    let mut files = Vec::new();
+    collect(dir, &mut files).map_err(|e| format!("can't list {}: {e}", dir.display()))?;
+    files.sort();
+
+    // Names are hashed too, so a renamed file is a new build.
+    let mut bytes = Vec::new();
+    for path in files.iter().filter(|p| **p != sw_path) {
+        let name = path.strip_prefix(dir).unwrap_or(path).to_string_lossy().replace('\\', "/");
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.extend(std::fs::read(path).map_err(|e| format!("can't read {}: {e}", path.display()))?);
+    }
+    let build = format!("{:016x}", fnv1a(&bytes));
+
+    std::fs::write(&sw_path, sw.replace(PLACEHOLDER, &build)).map_err(|e| format!("can't write {}: {e}", sw_path.display()))?;
+    Ok(build)
+}
+
+fn collect(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, out)?;
+        } else {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
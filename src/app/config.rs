@@ -0,0 +1,234 @@
+// src/app/config.rs
+//
+// Runtime configuration. A `config.json` next to index.html sets the
+// defaults for a deployment: worker URL, whether AI questions are fetched,
+// the prefetch policy, the request timeout and strict offline mode. Any of
+// them can be overridden per device from the Settings page; overrides live
+// in localStorage and apply to every learner on the device.
+//
+// The last config.json that loaded is kept, so a device that starts without
+// a network still follows its school's settings. `offline: true` in
+// config.json can't be switched off by a device override.
+//
+// Everything that talks to the worker must go through `ready_config` first:
+// it waits for config.json (once per page load) before answering.
+
+use std::cell::Cell;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use super::schema::{load_versioned, save_versioned, Versioned};
+
+pub const DEFAULT_WORKER_BASE_URL: &str = "https://the-numbers-worker.mikegyver.workers.dev";
+const DEFAULT_TIMEOUT_MS: u32 = 8_000;
+pub const MIN_TIMEOUT_MS: u32 = 1_000;
+pub const MAX_TIMEOUT_MS: u32 = 60_000;
+
+/// Relative, so it resolves next to index.html (Trunk builds with public_url "./").
+const CONFIG_URL: &str = "config.json";
+/// config.json as last loaded (device-wide, not per learner).
+const KEY_SITE: &str = "the_numbers_site_config_v1";
+/// This device's overrides.
+const KEY_OVERRIDE: &str = "the_numbers_config_override_v1";
+/// How often a second caller checks whether config.json has arrived.
+const WAIT_STEP_MS: i32 = 50;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Prefetch {
+    /// Fetch every day's set for the learner's grade when Home opens.
+    #[default]
+    AllDays,
+    /// Fetch a day's set only when that day is opened.
+    OnOpen,
+}
+
+impl Prefetch {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Prefetch::AllDays => "All 12 days when Home opens",
+            Prefetch::OnOpen => "Only when a day is opened",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub worker_base_url: String,
+    /// Fetch AI-generated daily sets (off = built-in questions only).
+    pub ai_enabled: bool,
+    pub prefetch: Prefetch,
+    pub timeout_ms: u32,
+    /// Strict offline: the worker is never contacted.
+    pub offline: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            worker_base_url: DEFAULT_WORKER_BASE_URL.to_string(),
+            ai_enabled: true,
+            prefetch: Prefetch::default(),
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+            offline: false,
+        }
+    }
+}
+
+impl Versioned for AppConfig {
+    const VERSION: u32 = 1;
+}
+
+impl AppConfig {
+    /// Whether the worker may be contacted at all.
+    pub fn may_fetch(&self) -> bool {
+        !self.offline && self.ai_enabled && valid_url(&self.worker_base_url)
+    }
+
+    /// The request timeout, kept to a sane range.
+    pub fn timeout_ms(&self) -> u32 {
+        self.timeout_ms.clamp(MIN_TIMEOUT_MS, MAX_TIMEOUT_MS)
+    }
+
+    /// This (site) config with a device's overrides applied.
+    pub fn with_override(mut self, o: &ConfigOverride) -> AppConfig {
+        if let Some(url) = o.worker_base_url.as_ref().filter(|u| valid_url(u)) {
+            self.worker_base_url = url.clone();
+        }
+        if let Some(on) = o.ai_enabled {
+            self.ai_enabled = on;
+        }
+        if let Some(p) = o.prefetch {
+            self.prefetch = p;
+        }
+        if let Some(ms) = o.timeout_ms {
+            self.timeout_ms = ms;
+        }
+        // A site-wide offline setting is a promise to the school; a device can only add to it.
+        self.offline = self.offline || o.offline.unwrap_or(false);
+        self
+    }
+}
+
+/// Per-device overrides; None = use the site setting.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigOverride {
+    pub worker_base_url: Option<String>,
+    pub ai_enabled: Option<bool>,
+    pub prefetch: Option<Prefetch>,
+    pub timeout_ms: Option<u32>,
+    pub offline: Option<bool>,
+}
+
+impl Versioned for ConfigOverride {
+    const VERSION: u32 = 1;
+}
+
+pub fn valid_url(url: &str) -> bool {
+    let url = url.trim();
+    (url.starts_with("https://") || url.starts_with("http://")) && url.len() > "https://".len()
+}
+
+// ----------------------------
+// Loading
+// ----------------------------
+
+#[derive(Clone, Copy, PartialEq)]
+enum SiteLoad {
+    NotTried,
+    Loading,
+    Done,
+}
+
+thread_local! {
+    static SITE_LOAD: Cell<SiteLoad> = const { Cell::new(SiteLoad::NotTried) };
+}
+
+/// config.json as last loaded (built-in defaults if it never has).
+pub fn site_config() -> AppConfig {
+    load_versioned(KEY_SITE).unwrap_or_default()
+}
+
+pub fn load_override() -> ConfigOverride {
+    load_versioned(KEY_OVERRIDE).unwrap_or_default()
+}
+
+pub fn save_override(o: &ConfigOverride) {
+    save_versioned(KEY_OVERRIDE, o);
+}
+
+/// The effective config right now (without waiting for config.json).
+pub fn current_config() -> AppConfig {
+    site_config().with_override(&load_override())
+}
+
+/// The effective config, after config.json has been tried for this page load.
+pub async fn ready_config() -> AppConfig {
+    match SITE_LOAD.with(Cell::get) {
+        SiteLoad::NotTried => {
+            SITE_LOAD.with(|s| s.set(SiteLoad::Loading));
+            refresh_site_config().await;
+            SITE_LOAD.with(|s| s.set(SiteLoad::Done));
+        }
+        SiteLoad::Loading => {
+            // Someone else is loading it; wait rather than act on stale settings.
+            let mut waited = 0;
+            while SITE_LOAD.with(Cell::get) == SiteLoad::Loading && waited < MAX_TIMEOUT_MS as i32 {
+                sleep_ms(WAIT_STEP_MS).await;
+                waited += WAIT_STEP_MS;
+            }
+        }
+        SiteLoad::Done => {}
+    }
+    current_config()
+}
+
+/// Fetch config.json. A missing file (404) resets to the built-in defaults;
+/// a network failure keeps the last copy.
+async fn refresh_site_config() {
+    let timeout = site_config().timeout_ms();
+    let signal = abort_after(timeout);
+    let Ok(resp) = gloo_net::http::Request::get(CONFIG_URL)
+        .abort_signal(signal.as_ref())
+        .send()
+        .await
+    else {
+        return;
+    };
+    if resp.status() == 404 {
+        save_versioned(KEY_SITE, &AppConfig::default());
+    } else if resp.ok() {
+        if let Ok(site) = resp.json::<AppConfig>().await {
+            save_versioned(KEY_SITE, &site);
+        }
+    }
+}
+
+/// An AbortSignal that fires after `ms` (None if AbortController is unavailable).
+pub fn abort_after(ms: u32) -> Option<web_sys::AbortSignal> {
+    let controller = web_sys::AbortController::new().ok()?;
+    let signal = controller.signal();
+    let win = web_sys::window()?;
+    let cb = Closure::once(move || controller.abort());
+    let _ = win.set_timeout_with_callback_and_timeout_and_arguments_0(cb.as_ref().unchecked_ref(), ms as i32);
+    cb.forget();
+    Some(signal)
+}
+
+pub async fn sleep_ms(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        match web_sys::window() {
+            Some(win) => {
+                let _ = win.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms);
+            }
+            None => {
+                let _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+            }
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
@@ -0,0 +1,235 @@
+// src/app/export.rs
+//
+// CSV exports for spreadsheets and LMS gradebooks.
+//
+// The column layouts below are a contract: add new columns at the END only,
+// never rename or reorder existing ones, so saved spreadsheet formulas and
+// LMS import mappings keep working.
+//
+// Format: RFC 4180 (comma separated, CRLF line endings, fields quoted when
+// they contain a comma, quote or newline), UTF-8 with a byte-order mark so
+// Excel shows symbols like × and ÷ correctly. Booleans are 1/0.
+
+use super::attempts::{AttemptLog, AttemptSource};
+use super::stats::DayCounts;
+
+/// One row per (date, skill) with at least one attempt, oldest date first.
+///
+/// - `date`: YYYY-MM-DD (the learner's local date)
+/// - `skill`: stable machine id (e.g. `word_problem`)
+/// - `skill_name`: display name (e.g. `Word Problems`); may be reworded
+/// - `attempts`, `correct`: whole numbers
+/// - `accuracy_pct`: 0–100, rounded to a whole number
+/// - `solved_alone`, `solved_with_hints`: questions solved with no hint showing / after a hint or the solution
+pub const SKILL_CSV_COLUMNS: [&str; 8] = [
+    "date",
+    "skill",
+    "skill_name",
+    "attempts",
+    "correct",
+    "accuracy_pct",
+    "solved_alone",
+    "solved_with_hints",
+];
+
+/// One row per checked answer, oldest first.
+///
+/// - `timestamp_ms`: milliseconds since the Unix epoch (UTC)
+/// - `date`: YYYY-MM-DD (the learner's local date)
+/// - `grade`, `day`: 1–5 and 1–12
+/// - `skill`: stable machine id
+/// - `prompt`, `expected`, `given`: question text, correct answer, what was typed
+/// - `correct`: 1/0
+/// - `try`: 1 for the first check of a question, 2 for the second, …
+/// - `seconds_to_answer`: one decimal place
+/// - `source`: `ai` or `local`
+/// - `stretch`: 1/0
+/// - `hints_used`: hint steps showing at this check (the worked solution counts as one)
+/// - `misconception`: stable id when the answer was a known wrong answer (e.g. `forgot_to_share`), else empty
+pub const ATTEMPT_CSV_COLUMNS: [&str; 15] = [
+    "timestamp_ms",
+    "date",
+    "grade",
+    "day",
+    "skill",
+    "prompt",
+    "expected",
+    "given",
+    "correct",
+    "try",
+    "seconds_to_answer",
+    "source",
+    "stretch",
+    "hints_used",
+    "misconception",
+];
+
+const BOM: &str = "\u{FEFF}";
+
+/// Quote a field if needed. Free text that a spreadsheet would read as a
+/// formula ("=…", "+…", "@…", "-3 + 5") gets a leading apostrophe.
+fn field(s: &str) -> String {
+    let looks_like_formula = s.starts_with(['=', '+', '-', '@', '\t', '\r']) && s.trim().parse::<f64>().is_err();
+    let s = if looks_like_formula { format!("'{}", s) } else { s.to_string() };
+
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s
+    }
+}
+
+fn push_row(out: &mut String, cells: &[String]) {
+    let row: Vec<String> = cells.iter().map(|c| field(c)).collect();
+    out.push_str(&row.join(","));
+    out.push_str("\r\n");
+}
+
+fn bit(b: bool) -> String {
+    if b { "1" } else { "0" }.to_string()
+}
+
+/// Per-date, per-skill counts. `days` may be in any order.
+pub fn skill_csv(days: &[(String, DayCounts)]) -> String {
+    let mut out = BOM.to_string();
+    push_row(&mut out, &SKILL_CSV_COLUMNS.map(String::from));
+
+    let mut days: Vec<&(String, DayCounts)> = days.iter().collect();
+    days.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (ymd, counts) in days {
+        for (skill, c) in counts.practiced() {
+            let pct = c.accuracy().map(|a| ((a * 100.0).round() as i32).to_string()).unwrap_or_default();
+            push_row(
+                &mut out,
+                &[
+                    ymd.clone(),
+                    skill.id().to_string(),
+                    skill.label().to_string(),
+                    c.attempts.to_string(),
+                    c.correct.to_string(),
+                    pct,
+                    c.solved_alone.to_string(),
+                    c.solved_with_hints.to_string(),
+                ],
+            );
+        }
+    }
+    out
+}
+
+/// Every logged attempt with `from <= date <= to` (inclusive).
+/// None when the log has nothing in that range.
+pub fn attempts_csv(log: &AttemptLog, from: &str, to: &str) -> Option<String> {
+    let rows: Vec<_> = log
+        .attempts
+        .iter()
+        .filter(|a| a.ymd.as_str() >= from && a.ymd.as_str() <= to)
+        .collect();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let mut out = BOM.to_string();
+    push_row(&mut out, &ATTEMPT_CSV_COLUMNS.map(String::from));
+
+    for a in rows {
+        push_row(
+            &mut out,
+            &[
+                format!("{:.0}", a.ts_ms),
+                a.ymd.clone(),
+                a.grade.to_string(),
+                a.day_id.to_string(),
+                a.skill.id().to_string(),
+                a.prompt.clone(),
+                a.expected.clone(),
+                a.given.clone(),
+                bit(a.correct),
+                a.try_no.to_string(),
+                format!("{:.1}", a.ms_to_answer as f64 / 1000.0),
+                match a.source {
+                    AttemptSource::Ai => "ai",
+                    AttemptSource::Local => "local",
+                }
+                .to_string(),
+                bit(a.stretch),
+                a.hints_used.to_string(),
+                a.misconception.map(|m| m.id().to_string()).unwrap_or_default(),
+            ],
+        );
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::attempts::Attempt;
+    use crate::app::skill::Skill;
+
+    fn attempt(ymd: &str, prompt: &str, given: &str) -> Attempt {
+        Attempt {
+            ts_ms: 1_760_000_000_000.0,
+            ymd: ymd.to_string(),
+            day_id: 2,
+            grade: 3,
+            skill: Skill::Addition,
+            prompt: prompt.to_string(),
+            expected: "5".to_string(),
+            given: given.to_string(),
+            correct: false,
+            source: AttemptSource::Local,
+            stretch: false,
+            ms_to_answer: 2500,
+            try_no: 1,
+            hints_used: 0,
+            misconception: None,
+            seed: 0,
+        }
+    }
+
+    #[test]
+    fn fields_with_commas_quotes_or_newlines_are_quoted() {
+        assert_eq!(field("plain"), "plain");
+        assert_eq!(field("1, 2, 3"), "\"1, 2, 3\"");
+        assert_eq!(field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn text_that_looks_like_a_formula_gets_an_apostrophe() {
+        assert_eq!(field("=SUM(A1:A3)"), "'=SUM(A1:A3)");
+        assert_eq!(field("+5+5"), "'+5+5");
+        assert_eq!(field("@cmd"), "'@cmd");
+        assert_eq!(field("-3 + 5"), "'-3 + 5");
+        // Plain numbers stay numbers.
+        assert_eq!(field("-3"), "-3");
+        assert_eq!(field("+2.5"), "+2.5");
+        // The apostrophe goes inside the quotes.
+        assert_eq!(field("=1,2"), "\"'=1,2\"");
+    }
+
+    #[test]
+    fn attempts_csv_keeps_the_date_range_and_columns() {
+        let log = AttemptLog {
+            attempts: vec![
+                attempt("2026-10-01", "2 + 3 = ?", "6"),
+                attempt("2026-10-10", "Is 1,000 > 999?", "=yes"),
+                attempt("2026-10-20", "4 + 1 = ?", "5"),
+            ],
+        };
+        let csv = attempts_csv(&log, "2026-10-05", "2026-10-15").unwrap();
+        let lines: Vec<&str> = csv.trim_start_matches(BOM).split("\r\n").collect();
+
+        assert_eq!(lines[0], ATTEMPT_CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "1760000000000,2026-10-10,3,2,addition,\"Is 1,000 > 999?\",5,'=yes,0,1,2.5,local,0,0,"
+        );
+        assert_eq!(lines[2], "");
+        assert_eq!(lines.len(), 3);
+
+        assert!(attempts_csv(&log, "2026-11-01", "2026-11-30").is_none());
+    }
+}
@@ -0,0 +1,257 @@
+// src/app/formats.rs
+//
+// How a question is answered on screen. Free text is the default; the other
+// formats are taps (multiple choice, true/false, a number line) or putting
+// items in order. Every format still produces a plain string that goes
+// through answer::check_answer, so grading is the same for all of them.
+//
+// Questions (local or from the worker) can declare a format. Otherwise one
+// is picked from the prompt: "(yes/no)" and "(true/false)" become two
+// buttons, "(red/blue)" style tags become choices, "Put in order" becomes an
+// ordering task, number-line walks become a number line for younger grades,
+// and Grade 1 number answers become multiple choice so nobody has to type.
+
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use super::answer::{check_answer, parse_number, AnswerKind};
+use super::grade::Grade;
+use super::misconceptions::KnownWrong;
+use super::session::{prompt_seed, question_rng};
+use super::skill::has_word;
+
+/// Choices shown for an inferred multiple-choice question (answer included).
+const CHOICES: usize = 4;
+/// Widest number line drawn; longer walks stay free text.
+const MAX_TICKS: i32 = 24;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuestionFormat {
+    FreeText,
+    /// Tap one of `choices` (the answer is one of them).
+    MultipleChoice { choices: Vec<String> },
+    /// Two buttons: True/False, or Yes/No when the prompt asks "(yes/no)".
+    TrueFalse,
+    /// Put `items` (shown in this order) into the right order; answered as "a,b,c".
+    Order { items: Vec<String> },
+    /// Tap a tick on a number line from `min` to `max`.
+    NumberLine {
+        min: i32,
+        max: i32,
+        #[serde(default = "one")]
+        step: i32,
+    },
+}
+
+fn one() -> i32 {
+    1
+}
+
+impl QuestionFormat {
+    pub fn is_tap(&self) -> bool {
+        matches!(
+            self,
+            QuestionFormat::MultipleChoice { .. } | QuestionFormat::TrueFalse | QuestionFormat::NumberLine { .. }
+        )
+    }
+
+    /// Buttons for the tap formats (empty for free text / ordering).
+    pub fn options(&self, prompt: &str) -> Vec<String> {
+        match self {
+            QuestionFormat::MultipleChoice { choices } => choices.clone(),
+            QuestionFormat::TrueFalse => {
+                if prompt.to_lowercase().contains("(yes/no)") {
+                    vec!["Yes".to_string(), "No".to_string()]
+                } else {
+                    vec!["True".to_string(), "False".to_string()]
+                }
+            }
+            QuestionFormat::NumberLine { min, max, step } => {
+                let step = (*step).max(1) as usize;
+                (*min..=*max).step_by(step).map(|n| n.to_string()).collect()
+            }
+            QuestionFormat::FreeText | QuestionFormat::Order { .. } => Vec::new(),
+        }
+    }
+}
+
+/// The format to show: the declared one if it can be graded, else inferred.
+pub fn format_for(
+    prompt: &str,
+    answer: &str,
+    kind: Option<&AnswerKind>,
+    grade: Grade,
+    declared: Option<&QuestionFormat>,
+    known_wrong: &[KnownWrong],
+) -> QuestionFormat {
+    if let Some(f) = declared.filter(|f| gradable(f, prompt, answer, kind)) {
+        return f.clone();
+    }
+
+    let lower = prompt.to_lowercase();
+    if lower.contains("(yes/no)") || lower.contains("(true/false)") {
+        return QuestionFormat::TrueFalse;
+    }
+    if let Some(choices) = choice_tag(prompt).filter(|c| c.iter().any(|o| check_answer(o, answer, kind))) {
+        return QuestionFormat::MultipleChoice { choices };
+    }
+    if let Some(items) = order_items(prompt, answer) {
+        return QuestionFormat::Order { items };
+    }
+    if grade.as_u8() <= 3 {
+        if let Some(line) = number_line(prompt, answer) {
+            return line;
+        }
+    }
+    if grade == Grade::G1 {
+        if let Some(choices) = number_choices(prompt, answer, known_wrong) {
+            return QuestionFormat::MultipleChoice { choices };
+        }
+    }
+    QuestionFormat::FreeText
+}
+
+/// A declared format is only used if the right answer can be entered with it.
+fn gradable(format: &QuestionFormat, prompt: &str, answer: &str, kind: Option<&AnswerKind>) -> bool {
+    match format {
+        QuestionFormat::FreeText => true,
+        QuestionFormat::Order { items } => {
+            let mut want: Vec<&str> = answer.split(',').map(str::trim).collect();
+            let mut have: Vec<&str> = items.iter().map(|i| i.trim()).collect();
+            want.sort_unstable();
+            have.sort_unstable();
+            items.len() > 1 && want == have
+        }
+        f => f.options(prompt).iter().any(|o| check_answer(o, answer, kind)),
+    }
+}
+
+/// "… (sun/candy)" → ["sun", "candy"]. Only a short tag at the very end counts.
+fn choice_tag(prompt: &str) -> Option<Vec<String>> {
+    let body = prompt.trim().strip_suffix(')')?;
+    let (_, tag) = body.rsplit_once('(')?;
+    let options: Vec<String> = tag.split('/').map(|o| o.trim().to_string()).collect();
+    let ok = (2..=4).contains(&options.len()) && options.iter().all(|o| !o.is_empty() && o.len() <= 12);
+    ok.then_some(options)
+}
+
+/// "Put in order …" with a list answer → the items scrambled (never already in order).
+fn order_items(prompt: &str, answer: &str) -> Option<Vec<String>> {
+    // Whole words only: not "border" or "ordered pair".
+    if !has_word(&prompt.to_lowercase(), "order") {
+        return None;
+    }
+    let want: Vec<String> = answer.split(',').map(|i| i.trim().to_string()).filter(|i| !i.is_empty()).collect();
+    if want.len() < 2 || want.iter().all(|i| *i == want[0]) {
+        return None;
+    }
+    let mut items = want.clone();
+    let mut rng = question_rng(prompt_seed(prompt), 0);
+    while items == want {
+        items.shuffle(&mut rng);
+    }
+    Some(items)
+}
+
+/// "Start at 3. Move +7, then -4. Where are you?" → a line covering every stop.
+fn number_line(prompt: &str, answer: &str) -> Option<QuestionFormat> {
+    let rest = prompt.split_once("Start at ")?.1;
+    let (start, moves) = rest.split_once(". Move ")?;
+    let mut at: i32 = start.trim().parse().ok()?;
+    let mut stops = vec![at];
+    for m in moves.split_once('.')?.0.split(',') {
+        let m = m.trim().trim_start_matches("then ").trim_start_matches('+');
+        at += m.parse::<i32>().ok()?;
+        stops.push(at);
+    }
+    if parse_number(answer) != Some(at as f64) {
+        return None;
+    }
+    let min = stops.iter().copied().min()?.min(0);
+    let max = stops.iter().copied().max()? + 2;
+    (max - min <= MAX_TICKS).then_some(QuestionFormat::NumberLine { min, max, step: 1 })
+}
+
+/// Whole-number answers: the answer, known wrong answers, then near misses.
+fn number_choices(prompt: &str, answer: &str, known_wrong: &[KnownWrong]) -> Option<Vec<String>> {
+    let n: i32 = answer.trim().parse().ok()?;
+    let mut choices = vec![n.to_string()];
+    let wrongs = known_wrong.iter().filter_map(|w| w.answer.trim().parse::<i32>().ok());
+    let near = [n + 1, n - 1, n + 2, n - 2, n + 3];
+    for c in wrongs.chain(near).filter(|c| *c >= 0) {
+        if choices.len() == CHOICES {
+            break;
+        }
+        if !choices.contains(&c.to_string()) {
+            choices.push(c.to_string());
+        }
+    }
+    // Stable per question, so a re-render or replay shows the same order.
+    choices.shuffle(&mut question_rng(prompt_seed(prompt), 0));
+    Some(choices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inferred(prompt: &str, answer: &str, grade: Grade) -> QuestionFormat {
+        format_for(prompt, answer, None, grade, None, &[])
+    }
+
+    #[test]
+    fn put_in_order_prompts_become_ordering_tasks() {
+        let f = inferred("Put in order (small to big): 7, 2, 5", "2,5,7", Grade::G4);
+        let QuestionFormat::Order { items } = f else {
+            panic!("expected an ordering task, got {f:?}");
+        };
+        assert_ne!(items, ["2", "5", "7"]);
+        assert!(gradable(&QuestionFormat::Order { items }, "", "2,5,7", None));
+    }
+
+    #[test]
+    fn order_inside_another_word_is_not_an_ordering_task() {
+        assert_eq!(
+            inferred("The border of a garden has corners at 1,2 and 3,4. Name them.", "1,2,3,4", Grade::G4),
+            QuestionFormat::FreeText
+        );
+        assert_eq!(
+            inferred("Write the ordered pair for x = 2 and y = 3, like 2,3", "2,3", Grade::G5),
+            QuestionFormat::FreeText
+        );
+    }
+
+    #[test]
+    fn tags_and_yes_no_become_buttons() {
+        assert_eq!(inferred("Is 7 odd? (yes/no)", "yes", Grade::G4), QuestionFormat::TrueFalse);
+        assert_eq!(
+            inferred("Which is bigger? (sun/candy)", "sun", Grade::G4),
+            QuestionFormat::MultipleChoice {
+                choices: vec!["sun".to_string(), "candy".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn a_declared_format_that_cant_give_the_answer_is_replaced() {
+        let declared = QuestionFormat::MultipleChoice {
+            choices: vec!["3".to_string(), "4".to_string()],
+        };
+        assert_eq!(
+            format_for("2 + 3 = ?", "5", None, Grade::G4, Some(&declared), &[]),
+            QuestionFormat::FreeText
+        );
+    }
+
+    #[test]
+    fn grade_1_numbers_get_stable_choices() {
+        let f = inferred("2 + 3 = ?", "5", Grade::G1);
+        let QuestionFormat::MultipleChoice { choices } = &f else {
+            panic!("expected choices, got {f:?}");
+        };
+        assert_eq!(choices.len(), CHOICES);
+        assert!(choices.contains(&"5".to_string()));
+        assert_eq!(inferred("2 + 3 = ?", "5", Grade::G1), f);
+    }
+}
@@ -0,0 +1,622 @@
+// src/app/hints.rs
+//
+// Tiered help for a question: hint 1 → hint 2 → worked solution.
+//
+// Questions (local or from the worker) may carry their own hints and
+// solution. Anything missing is filled in here: the common prompt shapes
+// (sequences, missing numbers, number-line moves, "a op b", means,
+// perimeter/area) get a worked solution built from their numbers; the rest
+// fall back to a strategy hint for the skill and the plain answer.
+//
+// A built solution is only used when it lands on the expected answer, so a
+// prompt we misread can never contradict the answer key.
+
+use super::answer::parse_number;
+use super::skill::Skill;
+
+/// Hints shown before the solution (extra worker hints are dropped).
+pub const HINT_TIERS: usize = 2;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Help {
+    pub hints: Vec<String>,
+    pub solution: String,
+}
+
+impl Help {
+    /// Help steps available: the hints, then the solution.
+    pub fn steps(&self) -> usize {
+        self.hints.len() + 1
+    }
+
+    /// Text of step `n` (1-based); the last step is the solution.
+    pub fn step(&self, n: usize) -> Option<&str> {
+        match n {
+            0 => None,
+            n if n <= self.hints.len() => Some(&self.hints[n - 1]),
+            n if n == self.steps() => Some(&self.solution),
+            _ => None,
+        }
+    }
+}
+
+/// Built from the numbers in the prompt.
+struct Worked {
+    hints: Vec<String>,
+    solution: String,
+}
+
+pub fn help_for(prompt: &str, answer: &str, skill: Skill, hints: &[String], solution: Option<&str>) -> Help {
+    let worked = worked_steps(prompt, answer);
+
+    let mut hints: Vec<String> = hints.iter().map(|h| h.trim().to_string()).filter(|h| !h.is_empty()).collect();
+    if hints.is_empty() {
+        hints = match &worked {
+            Some(w) => w.hints.clone(),
+            None => skill_hints(skill).iter().map(|h| h.to_string()).collect(),
+        };
+    }
+    hints.truncate(HINT_TIERS);
+
+    let solution = solution
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .or(worked.map(|w| w.solution))
+        .unwrap_or_else(|| format!("The answer is {answer}."));
+
+    Help { hints, solution }
+}
+
+/// Two strategy hints per skill, for prompts we can't work out ourselves.
+fn skill_hints(skill: Skill) -> [&'static str; 2] {
+    match skill {
+        Skill::Addition => [
+            "Start from the bigger number and count on.",
+            "Make a ten first if you can (8 + 5 = 8 + 2 + 3).",
+        ],
+        Skill::Subtraction => [
+            "Count back from the first number, or count up from the smaller one.",
+            "Check it: your answer plus the number you took away should give the start.",
+        ],
+        Skill::Multiplication => [
+            "Multiplying is adding equal groups.",
+            "Use a fact you know, then add or take away one more group.",
+        ],
+        Skill::Division => [
+            "Dividing is sharing into equal groups.",
+            "Think of the times fact: what times the divisor gives the total?",
+        ],
+        Skill::WordProblem => [
+            "What is the question asking for? Find the numbers that matter.",
+            "Pick the operation: putting together, taking away, equal groups or sharing?",
+        ],
+        Skill::Patterns => [
+            "Look at how each number changes to get the next one.",
+            "Is the jump the same every time, or is the jump changing too?",
+        ],
+        Skill::Equations => [
+            "Both sides must be equal. What number makes it true?",
+            "Undo what was done to the blank, one step at a time.",
+        ],
+        Skill::Geometry => [
+            "Picture or sketch the shape. Count sides and corners.",
+            "Perimeter goes around the edge (add the sides). Area fills the inside (length × width).",
+        ],
+        Skill::Angles => [
+            "A full turn is 360°, a half turn 180°, a quarter turn 90°.",
+            "Split the turn into quarter turns and count them.",
+        ],
+        Skill::Data => [
+            "List the numbers in order first.",
+            "Most common = mode, middle = median, share equally = mean.",
+        ],
+        Skill::Coordinates => [
+            "x goes left/right first, then y goes up/down.",
+            "Right and up add; left and down take away.",
+        ],
+        Skill::Probability => [
+            "Count all the possible outcomes first.",
+            "Chance = ways to win out of all the ways.",
+        ],
+        Skill::NumberTheory => [
+            "Can it be split into equal groups with none left over?",
+            "Try small numbers that divide it: 2, 3, 5…",
+        ],
+        Skill::Combinatorics => [
+            "Count the choices for each step.",
+            "Multiply the choices together instead of listing them all.",
+        ],
+        Skill::Rates => [
+            "How much changes each time?",
+            "Make a little table: start, then add the change once per step.",
+        ],
+        Skill::Logic => [
+            "Read each part slowly. What do you know for sure?",
+            "Try an example and see if it fits every clue.",
+        ],
+        Skill::Strategy => [
+            "Think one move ahead: what will the other player do?",
+            "Work backwards from the goal.",
+        ],
+        Skill::Unknown => [
+            "Find the numbers that matter and what is being asked.",
+            "Pick an operation, then check your answer makes sense.",
+        ],
+    }
+}
+
+// ----------------------------
+// Worked solutions from the prompt
+// ----------------------------
+
+fn worked_steps(prompt: &str, answer: &str) -> Option<Worked> {
+    let text = strip_labels(prompt);
+    let expected = parse_number(answer);
+    let lands = |v: f64| expected.is_some_and(|e| (e - v).abs() < 0.01);
+
+    let numeric = [sequence, missing_number, moves, change, mean, perimeter_area]
+        .iter()
+        .find_map(|f| f(text).filter(|(_, v)| lands(*v)).map(|(w, _)| w));
+    numeric.or_else(|| arithmetic(text, answer))
+}
+
+/// The value a prompt works out to, for the shapes that read only one way
+/// (missing numbers, number-line moves, rising changes, perimeter/area).
+/// Sequences are left out: a short list can fit more than one pattern.
+pub fn computed_value(prompt: &str) -> Option<f64> {
+    let text = strip_labels(prompt);
+    [missing_number, moves, rising_change, perimeter_area]
+        .iter()
+        .find_map(|f| f(text))
+        .map(|(_, v)| v)
+}
+
+/// "Stretch: Mean: …" → "…"
+fn strip_labels(prompt: &str) -> &str {
+    let mut s = prompt.trim();
+    for label in ["Stretch:", "Mean (average):", "Mean:", "Perimeter:", "Area:"] {
+        if let Some(rest) = s.strip_prefix(label) {
+            s = rest.trim_start();
+        }
+    }
+    s
+}
+
+/// Whole numbers without ".0"; otherwise up to 3 places.
+fn num(v: f64) -> String {
+    if (v - v.round()).abs() < 1e-9 {
+        format!("{}", v.round() as i64)
+    } else {
+        let s = format!("{:.3}", v);
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+fn number(s: &str) -> Option<f64> {
+    s.trim().trim_end_matches(['.', '?', ',']).replace('−', "-").parse().ok()
+}
+
+fn op(s: &str) -> Option<char> {
+    match s {
+        "+" => Some('+'),
+        "-" | "−" => Some('-'),
+        "×" | "x" | "*" => Some('×'),
+        "÷" | "/" => Some('÷'),
+        _ => None,
+    }
+}
+
+fn apply(a: f64, op: char, b: f64) -> Option<f64> {
+    match op {
+        '+' => Some(a + b),
+        '-' => Some(a - b),
+        '×' => Some(a * b),
+        '÷' if b != 0.0 => Some(a / b),
+        _ => None,
+    }
+}
+
+fn op_name(op: char) -> &'static str {
+    match op {
+        '+' => "+",
+        '-' => "−",
+        '×' => "×",
+        _ => "÷",
+    }
+}
+
+/// "What comes next? 3, 6, 9, __"
+fn sequence(text: &str) -> Option<(Worked, f64)> {
+    let body = text.trim().strip_suffix("__")?;
+    let list = body.rsplit(['?', ':']).next()?;
+    let terms: Vec<f64> = list.split(',').map(str::trim).filter(|t| !t.is_empty()).map(number).collect::<Option<_>>()?;
+    if terms.len() < 3 {
+        return None;
+    }
+    let last = *terms.last()?;
+    let diffs: Vec<f64> = terms.windows(2).map(|w| w[1] - w[0]).collect();
+    let jumps = diffs.iter().map(|d| signed(*d)).collect::<Vec<_>>().join(", ");
+
+    if diffs.iter().all(|d| (d - diffs[0]).abs() < 1e-9) {
+        let d = diffs[0];
+        let next = last + d;
+        return Some((
+            Worked {
+                hints: vec![
+                    "How much does each number change to get the next one?".to_string(),
+                    format!("The jumps are {jumps}. Do the same jump once more."),
+                ],
+                solution: format!("Each step is {}. {} {} = {}", signed(d), num(last), spaced(d), num(next)),
+            },
+            next,
+        ));
+    }
+
+    let seconds: Vec<f64> = diffs.windows(2).map(|w| w[1] - w[0]).collect();
+    if seconds.iter().all(|s| (s - seconds[0]).abs() < 1e-9) {
+        let jump = diffs.last()? + seconds[0];
+        let next = last + jump;
+        return Some((
+            Worked {
+                hints: vec![
+                    "Write down the jump between each pair of numbers.".to_string(),
+                    format!("The jumps are {jumps}. How are the jumps changing?"),
+                ],
+                solution: format!(
+                    "The jumps grow by {} each time, so the next jump is {}. {} {} = {}",
+                    signed(seconds[0]),
+                    signed(jump),
+                    num(last),
+                    spaced(jump),
+                    num(next)
+                ),
+            },
+            next,
+        ));
+    }
+
+    let ratio = terms[1] / terms[0];
+    if terms[0] != 0.0 && terms.windows(2).all(|w| (w[1] - w[0] * ratio).abs() < 1e-9) {
+        let next = last * ratio;
+        return Some((
+            Worked {
+                hints: vec![
+                    "The jumps keep growing. Try multiplying instead of adding.".to_string(),
+                    format!("{} × ? = {}", num(terms[0]), num(terms[1])),
+                ],
+                solution: format!("Each number is × {} the one before. {} × {} = {}", num(ratio), num(last), num(ratio), num(next)),
+            },
+            next,
+        ));
+    }
+    None
+}
+
+fn signed(d: f64) -> String {
+    if d < 0.0 {
+        format!("−{}", num(-d))
+    } else {
+        format!("+{}", num(d))
+    }
+}
+
+/// "+ 3" / "− 3", for writing a step as a sum.
+fn spaced(d: f64) -> String {
+    if d < 0.0 {
+        format!("− {}", num(-d))
+    } else {
+        format!("+ {}", num(d))
+    }
+}
+
+/// "3 + __ = 5", "36 ÷ __ = 6", "2 × __ + 3 = 11", "x - 12 = 19. x = ?", "5x = 35. x = ?"
+fn missing_number(text: &str) -> Option<(Worked, f64)> {
+    let eq = match text.strip_suffix(". x = ?") {
+        Some(eq) => eq
+            .split_whitespace()
+            .map(|t| match t.strip_suffix('x') {
+                Some("") => "__".to_string(),
+                Some(k) if number(k).is_some() => format!("{k} × __"),
+                _ => t.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        None if text.contains("__") => text.to_string(),
+        None => return None,
+    };
+    let (lhs, rhs) = eq.split_once('=')?;
+    let c = number(rhs)?;
+    let t: Vec<&str> = lhs.split_whitespace().collect();
+
+    let (x, hints, solution) = match t.as_slice() {
+        [a, o, "__"] => {
+            let (a, o) = (number(a)?, op(o)?);
+            match o {
+                '+' => (
+                    c - a,
+                    [format!("What do you add to {} to make {}?", num(a), num(c)), format!("Count up from {} to {}, or work out {} − {}.", num(a), num(c), num(c), num(a))],
+                    format!("{} − {} = {}. Check: {} + {} = {} ✓", num(c), num(a), num(c - a), num(a), num(c - a), num(c)),
+                ),
+                '-' => (
+                    a - c,
+                    [format!("{} take away what leaves {}?", num(a), num(c)), format!("Work out {} − {}.", num(a), num(c))],
+                    format!("{} − {} = {}. Check: {} − {} = {} ✓", num(a), num(c), num(a - c), num(a), num(a - c), num(c)),
+                ),
+                '×' => (
+                    c / a,
+                    [format!("How many groups of {} make {}?", num(a), num(c)), format!("Work out {} ÷ {}.", num(c), num(a))],
+                    format!("{} ÷ {} = {}. Check: {} × {} = {} ✓", num(c), num(a), num(c / a), num(a), num(c / a), num(c)),
+                ),
+                _ => (
+                    a / c,
+                    [format!("Share {} into how many equal groups to get {} in each?", num(a), num(c)), format!("Work out {} ÷ {}.", num(a), num(c))],
+                    format!("{} ÷ {} = {}. Check: {} ÷ {} = {} ✓", num(a), num(c), num(a / c), num(a), num(a / c), num(c)),
+                ),
+            }
+        }
+        ["__", o, b] => {
+            let (o, b) = (op(o)?, number(b)?);
+            let (undo, undo_hint) = match o {
+                '+' => ('-', format!("Undo it: take {} away from {}.", num(b), num(c))),
+                '-' => ('+', format!("Undo it: add {} back on to {}.", num(b), num(c))),
+                '×' => ('÷', format!("Undo it: divide {} by {}.", num(c), num(b))),
+                _ => ('×', format!("Undo it: multiply {} by {}.", num(c), num(b))),
+            };
+            let x = apply(c, undo, b)?;
+            (
+                x,
+                [format!("What number {} {} makes {}?", op_name(o), num(b), num(c)), undo_hint],
+                format!(
+                    "{} {} {} = {}. Check: {} {} {} = {} ✓",
+                    num(c),
+                    op_name(undo),
+                    num(b),
+                    num(x),
+                    num(x),
+                    op_name(o),
+                    num(b),
+                    num(c)
+                ),
+            )
+        }
+        [k, "×", "__", o, b] => {
+            let (k, o, b) = (number(k)?, op(o)?, number(b)?);
+            if k == 0.0 || !matches!(o, '+' | '-') {
+                return None;
+            }
+            let undo = if o == '+' { '-' } else { '+' };
+            let m = apply(c, undo, b)?;
+            let x = m / k;
+            (
+                x,
+                [
+                    format!("First undo the {} {}: {} {} {} = {}.", op_name(o), num(b), num(c), op_name(undo), num(b), num(m)),
+                    format!("Now {} × __ = {}. How many {}s make {}?", num(k), num(m), num(k), num(m)),
+                ],
+                format!(
+                    "{} {} {} = {}, then {} ÷ {} = {}. Check: {} × {} {} {} = {} ✓",
+                    num(c),
+                    op_name(undo),
+                    num(b),
+                    num(m),
+                    num(m),
+                    num(k),
+                    num(x),
+                    num(k),
+                    num(x),
+                    op_name(o),
+                    num(b),
+                    num(c)
+                ),
+            )
+        }
+        _ => return None,
+    };
+    Some((
+        Worked {
+            hints: hints.into(),
+            solution,
+        },
+        x,
+    ))
+}
+
+/// "Start at 3. Move +7, then -4. Where are you?"
+fn moves(text: &str) -> Option<(Worked, f64)> {
+    let rest = text.strip_prefix("Start at ")?;
+    let (start, rest) = rest.split_once(". Move ")?;
+    let start = number(start)?;
+    let (list, _) = rest.split_once('.')?;
+    let steps: Vec<f64> = list
+        .split(',')
+        .map(|s| s.trim().trim_start_matches("then ").trim_start_matches('+'))
+        .map(number)
+        .collect::<Option<_>>()?;
+
+    let mut at = start;
+    let mut parts = Vec::new();
+    for s in &steps {
+        let next = at + s;
+        parts.push(format!("{} {} = {}", num(at), spaced(*s), num(next)));
+        at = next;
+    }
+    let first = parts.first()?.clone();
+    Some((
+        Worked {
+            hints: vec![
+                format!("Put your finger on {} on a number line. + moves right, − moves left.", num(start)),
+                format!("First move: {first}."),
+            ],
+            solution: format!("{}. You end at {}.", parts.join(", then "), num(at)),
+        },
+        at,
+    ))
+}
+
+/// "How much did it change? 12 → 19"
+fn change(text: &str) -> Option<(Worked, f64)> {
+    let (_, pair) = text.split_once("change?")?;
+    let (a, b) = pair.split_once('→')?;
+    let (a, b) = (number(a)?, number(b)?);
+    Some((
+        Worked {
+            hints: vec![
+                format!("How far is it from {} to {}?", num(a), num(b)),
+                format!("Count up from {} to {}, or work out {} − {}.", num(a), num(b), num(b), num(a)),
+            ],
+            solution: format!("{} − {} = {}", num(b), num(a), num(b - a)),
+        },
+        b - a,
+    ))
+}
+
+/// A change that goes up. "19 → 12" can fairly be answered 7 or −7.
+fn rising_change(text: &str) -> Option<(Worked, f64)> {
+    change(text).filter(|(_, v)| *v >= 0.0)
+}
+
+/// "(2 + 4 + 6) ÷ 3 = ?"
+fn mean(text: &str) -> Option<(Worked, f64)> {
+    let inner = text.strip_prefix('(')?;
+    let (sum_part, rest) = inner.split_once(')')?;
+    let n = number(rest.trim().strip_prefix('÷')?.split('=').next()?)?;
+    let values: Vec<f64> = sum_part.split('+').map(number).collect::<Option<_>>()?;
+    if n == 0.0 {
+        return None;
+    }
+    let total: f64 = values.iter().sum();
+    let listed = values.iter().map(|v| num(*v)).collect::<Vec<_>>().join(" + ");
+    Some((
+        Worked {
+            hints: vec![
+                "Add all the numbers first, then share the total equally.".to_string(),
+                format!("{listed} = {}", num(total)),
+            ],
+            solution: format!("{listed} = {}, and {} ÷ {} = {}", num(total), num(total), num(n), num(total / n)),
+        },
+        total / n,
+    ))
+}
+
+/// "a square has side 5. Perimeter = ?", "a rectangle is 4 by 6. Area = ?"
+fn perimeter_area(text: &str) -> Option<(Worked, f64)> {
+    let area = text.contains("Area");
+    if !area && !text.contains("Perimeter") {
+        return None;
+    }
+    if let Some((_, side)) = text.split_once("square has side ") {
+        let s = number(side.split_whitespace().next()?)?;
+        return Some(if area {
+            (
+                Worked {
+                    hints: vec!["Area = side × side.".to_string(), format!("{} × {}", num(s), num(s))],
+                    solution: format!("{} × {} = {}", num(s), num(s), num(s * s)),
+                },
+                s * s,
+            )
+        } else {
+            (
+                Worked {
+                    hints: vec![
+                        "Perimeter is the distance all the way around.".to_string(),
+                        format!("A square has 4 equal sides of {}.", num(s)),
+                    ],
+                    solution: format!("4 × {} = {}", num(s), num(4.0 * s)),
+                },
+                4.0 * s,
+            )
+        });
+    }
+
+    let (_, dims) = text.split_once("rectangle ")?;
+    let dims = dims.strip_prefix("is ").unwrap_or(dims);
+    let mut it = dims.split_whitespace();
+    let a = number(it.next()?)?;
+    if it.next()? != "by" {
+        return None;
+    }
+    let b = number(it.next()?)?;
+    Some(if area {
+        (
+            Worked {
+                hints: vec![
+                    "Area counts the squares that fill the inside.".to_string(),
+                    format!("{} rows of {} squares.", num(a), num(b)),
+                ],
+                solution: format!("{} × {} = {}", num(a), num(b), num(a * b)),
+            },
+            a * b,
+        )
+    } else {
+        (
+            Worked {
+                hints: vec![
+                    "Perimeter is the distance all the way around.".to_string(),
+                    format!("A rectangle has two sides of {} and two sides of {}.", num(a), num(b)),
+                ],
+                solution: format!("{} + {} + {} + {} = {}", num(a), num(b), num(a), num(b), num(2.0 * (a + b))),
+            },
+            2.0 * (a + b),
+        )
+    })
+}
+
+/// The first "a op b" in the prompt, e.g. "What is 180 ÷ 2?". Only used
+/// when it gives the answer itself: in "What is 2 + 3 × 4?" the first pair
+/// isn't the step to take first.
+fn arithmetic(text: &str, answer: &str) -> Option<Worked> {
+    if text.contains("__") {
+        return None;
+    }
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let (a, o, b) = tokens.windows(3).find_map(|w| {
+        let a = number(w[0].trim_start_matches(['(', ':']))?;
+        Some((a, op(w[1])?, number(w[2].trim_end_matches(')'))?))
+    })?;
+    let v = apply(a, o, b)?;
+    if !parse_number(answer).is_some_and(|e| (e - v).abs() < 0.01) {
+        return None;
+    }
+
+    let hint = match o {
+        '+' => format!("Start at {} and count on {}.", num(a.max(b)), num(a.min(b))),
+        '-' => format!("Count back {} from {}, or count up from {} to {}.", num(b), num(a), num(b), num(a)),
+        '×' => format!("Think of {} groups of {}.", num(a), num(b)),
+        _ => format!("How many groups of {} fit in {}?", num(b), num(a)),
+    };
+    let sum = format!("{} {} {} = {}", num(a), op_name(o), num(b), num(v));
+    Some(Worked {
+        hints: vec![hint, format!("Work out {} {} {} first.", num(a), op_name(o), num(b))],
+        solution: sum,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_operation_gets_a_worked_solution() {
+        let help = help_for("What is 180 ÷ 2?", "90", Skill::Division, &[], None);
+        assert_eq!(help.solution, "180 ÷ 2 = 90");
+        assert_eq!(help.hints.len(), HINT_TIERS);
+    }
+
+    #[test]
+    fn the_first_pair_isnt_worked_when_it_isnt_the_answer() {
+        let help = help_for("What is 2 + 3 × 4?", "14", Skill::Addition, &[], None);
+        assert_eq!(help.solution, "The answer is 14.");
+        assert_eq!(help.hints, skill_hints(Skill::Addition));
+    }
+
+    #[test]
+    fn given_help_wins() {
+        let hints = vec!["Add the tens first.".to_string()];
+        let help = help_for("What is 20 + 30?", "50", Skill::Addition, &hints, Some("20 + 30 = 50"));
+        assert_eq!(help.hints, hints);
+        assert_eq!(help.solution, "20 + 30 = 50");
+        assert_eq!(help.steps(), 2);
+        assert_eq!(help.step(2), Some("20 + 30 = 50"));
+    }
+}
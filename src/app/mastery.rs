@@ -0,0 +1,156 @@
+// src/app/mastery.rs
+//
+// Per-skill mastery via Bayesian Knowledge Tracing (BKT).
+// Each check nudges P(known) up or down; slips and lucky guesses are
+// expected, so one bad day no longer flips a badge the way raw accuracy did.
+
+use serde::{Deserialize, Serialize};
+
+// Classic BKT parameters, tuned loosely for short kid-sized practice sets.
+const P_INIT: f32 = 0.3; // chance the skill is already known before any practice
+const P_LEARN: f32 = 0.12; // chance of learning it on any one practice step
+const P_SLIP: f32 = 0.1; // knows it, answers wrong anyway
+const P_GUESS: f32 = 0.2; // doesn't know it, answers right anyway
+
+/// Estimates above this count as mastered.
+pub const MASTERED: f32 = 0.85;
+/// Estimates above this are "getting there".
+pub const IMPROVING: f32 = 0.6;
+/// Below this confidence the estimate is too thin to badge or rank.
+pub const MIN_CONFIDENCE: f32 = 0.5;
+/// Observations at which confidence reaches 50%.
+const HALF_CONFIDENCE_AT: f32 = 6.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mastery {
+    /// P(skill is known), 0..=1.
+    pub p_known: f32,
+    /// Checks folded into the estimate so far.
+    pub observations: u32,
+}
+
+impl Default for Mastery {
+    fn default() -> Self {
+        Mastery {
+            p_known: P_INIT,
+            observations: 0,
+        }
+    }
+}
+
+impl Mastery {
+    /// BKT step: condition on the observed answer, then allow for learning.
+    pub fn update(&mut self, ok: bool) {
+        let k = self.p_known;
+        let posterior = if ok {
+            let hit = k * (1.0 - P_SLIP);
+            hit / (hit + (1.0 - k) * P_GUESS)
+        } else {
+            let slip = k * P_SLIP;
+            slip / (slip + (1.0 - k) * (1.0 - P_GUESS))
+        };
+        self.p_known = (posterior + (1.0 - posterior) * P_LEARN).clamp(0.0, 1.0);
+        self.observations += 1;
+    }
+
+    /// 0..1 — how much evidence backs `p_known` (saturates with more checks).
+    pub fn confidence(&self) -> f32 {
+        let n = self.observations as f32;
+        n / (n + HALF_CONFIDENCE_AT)
+    }
+
+    pub fn is_confident(&self) -> bool {
+        self.confidence() >= MIN_CONFIDENCE
+    }
+
+    pub fn pct(&self) -> i32 {
+        (self.p_known * 100.0).round() as i32
+    }
+}
+
+/// ⚪ until there is enough evidence, then 🟢 mastered / 🟡 improving / 🔴 focus.
+pub fn badge_for_mastery(m: Option<&Mastery>) -> &'static str {
+    match m {
+        Some(m) if m.is_confident() => {
+            if m.p_known >= MASTERED {
+                "🟢"
+            } else if m.p_known >= IMPROVING {
+                "🟡"
+            } else {
+                "🔴"
+            }
+        }
+        _ => "⚪",
+    }
+}
+
+/// "82% (sure: 67%)" or "—" before the first check.
+pub fn mastery_label(m: Option<&Mastery>) -> String {
+    match m {
+        Some(m) if m.observations > 0 => {
+            format!("{}% (sure: {}%)", m.pct(), (m.confidence() * 100.0).round() as i32)
+        }
+        _ => "—".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn one_right_answer_follows_the_bkt_step() {
+        let mut m = Mastery::default();
+        m.update(true);
+        // 0.27 / 0.41 = 0.6585, then + 0.3415 × 0.12 for learning.
+        assert!(close(m.p_known, 0.6995), "{}", m.p_known);
+        assert_eq!(m.observations, 1);
+    }
+
+    #[test]
+    fn one_wrong_answer_follows_the_bkt_step() {
+        let mut m = Mastery::default();
+        m.update(false);
+        // 0.03 / 0.59 = 0.0508, then + 0.9492 × 0.12 for learning.
+        assert!(close(m.p_known, 0.1648), "{}", m.p_known);
+    }
+
+    #[test]
+    fn the_estimate_stays_between_zero_and_one() {
+        let mut sure = Mastery { p_known: 1.0, observations: 50 };
+        sure.update(true);
+        assert!(close(sure.p_known, 1.0));
+
+        // A value out of range (e.g. a hand-edited save) is pulled back in.
+        let mut bad = Mastery { p_known: 1.5, observations: 0 };
+        bad.update(true);
+        assert_eq!(bad.p_known, 1.0);
+
+        let mut m = Mastery::default();
+        for _ in 0..200 {
+            m.update(false);
+            assert!((0.0..=1.0).contains(&m.p_known));
+        }
+        for _ in 0..200 {
+            m.update(true);
+            assert!((0.0..=1.0).contains(&m.p_known));
+        }
+        assert!(m.p_known >= MASTERED);
+    }
+
+    #[test]
+    fn badges_wait_for_enough_evidence() {
+        let mut m = Mastery::default();
+        for _ in 0..5 {
+            m.update(true);
+        }
+        assert_eq!(badge_for_mastery(Some(&m)), "⚪");
+        m.update(true);
+        assert_eq!(badge_for_mastery(Some(&m)), "🟢");
+        assert_eq!(badge_for_mastery(None), "⚪");
+    }
+}
@@ -0,0 +1,131 @@
+// src/app/misconceptions.rs
+//
+// Predictable wrong answers. A question can list answers that come from a
+// specific misunderstanding (adding instead of subtracting in "12 → 19",
+// counting corners twice, 2 × n subsets instead of 2ⁿ…). When the child types
+// one of them, the check shows feedback about THAT mistake instead of the
+// generic "not yet", and the hit is counted so the teacher note can name the
+// pattern.
+
+use serde::{Deserialize, Serialize};
+
+use super::answer::{check_answer, AnswerKind};
+
+/// Stable ids (the serde form is stored in stats and sent by the worker).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Misconception {
+    /// "How much did it change? 12 → 19" answered 31.
+    ChangeAddedNotSubtracted,
+    /// "3 + __ = 5" answered 8: did the sum instead of undoing it.
+    UndidWrongWay,
+    /// "2 × __ + 3 = 11" answered 8: stopped after the first step.
+    StoppedAfterFirstStep,
+    /// "How many corners does a square have?" answered 8.
+    CountedCornersTwice,
+    /// Gave the area for a perimeter question, or the other way round.
+    MixedAreaAndPerimeter,
+    /// Rectangle perimeter as length + width (only two sides).
+    AddedOnlyTwoSides,
+    /// Triangle area as base × height.
+    ForgotToHalve,
+    /// Mean answered with the total.
+    ForgotToShare,
+    /// "Start at 7. Move -3" answered 10.
+    MovedWrongWay,
+    /// "3 tops and 4 bottoms" answered 7.
+    AddedChoices,
+    /// Subsets of 3 items answered 6 (2 × n instead of 2ⁿ).
+    SubsetsTimesTwo,
+    /// Subsets of 3 items answered 7.
+    ForgotEmptySet,
+    /// Any id this build doesn't know (newer worker content).
+    #[serde(other)]
+    Other,
+}
+
+impl Misconception {
+    /// Stable machine name (same as the serde form), for exports.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Misconception::ChangeAddedNotSubtracted => "change_added_not_subtracted",
+            Misconception::UndidWrongWay => "undid_wrong_way",
+            Misconception::StoppedAfterFirstStep => "stopped_after_first_step",
+            Misconception::CountedCornersTwice => "counted_corners_twice",
+            Misconception::MixedAreaAndPerimeter => "mixed_area_and_perimeter",
+            Misconception::AddedOnlyTwoSides => "added_only_two_sides",
+            Misconception::ForgotToHalve => "forgot_to_halve",
+            Misconception::ForgotToShare => "forgot_to_share",
+            Misconception::MovedWrongWay => "moved_wrong_way",
+            Misconception::AddedChoices => "added_choices",
+            Misconception::SubsetsTimesTwo => "subsets_times_two",
+            Misconception::ForgotEmptySet => "forgot_empty_set",
+            Misconception::Other => "other",
+        }
+    }
+
+    /// For parents and teachers: "often …".
+    pub fn label(&self) -> &'static str {
+        match self {
+            Misconception::ChangeAddedNotSubtracted => "mixes up add vs subtract in change problems",
+            Misconception::UndidWrongWay => "does the operation instead of undoing it in missing-number puzzles",
+            Misconception::StoppedAfterFirstStep => "stops after the first step of two-step puzzles",
+            Misconception::CountedCornersTwice => "counts corners or sides twice",
+            Misconception::MixedAreaAndPerimeter => "mixes up area and perimeter",
+            Misconception::AddedOnlyTwoSides => "adds only two sides for a perimeter",
+            Misconception::ForgotToHalve => "forgets to halve for a triangle's area",
+            Misconception::ForgotToShare => "gives the total instead of sharing it for an average",
+            Misconception::MovedWrongWay => "moves the wrong way for − on the number line",
+            Misconception::AddedChoices => "adds choices instead of multiplying them",
+            Misconception::SubsetsTimesTwo => "counts subsets as 2 × n instead of doubling for each item",
+            Misconception::ForgotEmptySet => "forgets the empty set when counting subsets",
+            Misconception::Other => "makes the same slip more than once",
+        }
+    }
+
+    /// For the child, shown in place of "Not yet".
+    pub fn feedback(&self) -> &'static str {
+        match self {
+            Misconception::ChangeAddedNotSubtracted => {
+                "Looks like you added the two numbers. “How much did it change?” asks for the gap between them."
+            }
+            Misconception::UndidWrongWay => {
+                "Looks like you did the sum as written. To find the blank, undo it: + becomes −, × becomes ÷."
+            }
+            Misconception::StoppedAfterFirstStep => "Good first step! There's one more: undo the × (or ÷) too.",
+            Misconception::CountedCornersTwice => "Careful — it looks like some were counted twice. Touch each one once.",
+            Misconception::MixedAreaAndPerimeter => {
+                "That's the other one! Perimeter goes around the edge; area fills the inside."
+            }
+            Misconception::AddedOnlyTwoSides => "That's only two sides. A rectangle has four — go all the way around.",
+            Misconception::ForgotToHalve => "A triangle is half of a rectangle. Don't forget to halve it.",
+            Misconception::ForgotToShare => "That's the total. For the average, share it equally: divide by how many numbers.",
+            Misconception::MovedWrongWay => "Check the signs: − moves left (down), + moves right (up).",
+            Misconception::AddedChoices => "Each top goes with every bottom, so multiply the choices instead of adding.",
+            Misconception::SubsetsTimesTwo => "Each item doubles the count (in or out), so it's 2 × 2 × 2…, not 2 × the number of items.",
+            Misconception::ForgotEmptySet => "So close! Remember the empty set (choosing nothing) counts too.",
+            Misconception::Other => "That's a common slip. Read the question again slowly.",
+        }
+    }
+}
+
+/// A wrong answer that points at a misconception.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KnownWrong {
+    pub answer: String,
+    pub misconception: Misconception,
+    /// Overrides the misconception's default feedback (e.g. worker wording).
+    #[serde(default)]
+    pub feedback: Option<String>,
+}
+
+impl KnownWrong {
+    pub fn feedback(&self) -> &str {
+        self.feedback.as_deref().unwrap_or_else(|| self.misconception.feedback())
+    }
+}
+
+/// The declared wrong answer the child typed, if any (compared like the real answer).
+pub fn spot<'a>(given: &str, known: &'a [KnownWrong], kind: Option<&AnswerKind>) -> Option<&'a KnownWrong> {
+    known.iter().find(|w| check_answer(given, &w.answer, kind))
+}
@@ -0,0 +1,334 @@
+// src/app/mock_worker.rs
+//
+// A stand-in for the question worker, for development and end-to-end tests
+// without network. Native builds only:
+//
+//   cargo run -- mock-worker [--port 8787] [--latency 1500] [--fail 0.3] …
+//
+// It serves GET /api/day-questions?grade=&day=[&date=] with the same
+// DayResponse JSON the real worker sends, plus /api/capabilities and the
+// /api/day-questions/batch endpoint (both off with --no-batch). Sets come from the local question bank (seeded
+// by date + grade + day, so a day's set is stable), or from a fixture file
+// when one exists for that grade and day. Switches add latency, HTTP errors,
+// truncated JSON and `source: "fallback"`, so the app's timeouts, retries,
+// circuit breaker and local fallback can all be exercised on purpose.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+
+use super::ai_day::{AiQuestion, Capabilities, DayResponse, Difficulty as AiDifficulty};
+use super::grade::Grade;
+use super::questions::{questions_for, Difficulty};
+use super::review::add_days;
+use super::session::{prompt_seed, question_rng};
+
+const DEFAULT_PORT: u16 = 8787;
+const DAY_PATH: &str = "/api/day-questions";
+const BATCH_PATH: &str = "/api/day-questions/batch";
+const CAPABILITIES_PATH: &str = "/api/capabilities";
+/// Sets (days × dates) one batch may ask for, as advertised.
+const MAX_BATCH: usize = 24;
+const MAX_BATCH_DATES: usize = 7;
+/// Payload version the mock speaks (the one the client supports).
+const VERSION: u32 = 1;
+
+const USAGE: &str = "\
+usage: cargo run -- mock-worker [options]
+
+  --port <n>           listen on 127.0.0.1:<n> (default 8787)
+  --fixtures <dir>     serve <dir>/grade<g>-day<d>.json when it exists
+  --latency <ms>       wait this long before every response
+  --fail <0..1>        answer this share of requests with 503
+  --status <code>      answer every request with this HTTP status
+  --malformed          cut every JSON body off halfway
+  --fallback           send source: \"fallback\" instead of \"ai\"
+  --no-batch           act like an older worker: no capabilities, no batches
+  --date <YYYY-MM-DD>  date_ymd to send (default: today, UTC)";
+
+#[derive(Clone, Debug, Default)]
+struct Options {
+    port: u16,
+    fixtures: Option<PathBuf>,
+    latency_ms: u64,
+    fail_rate: f64,
+    status: Option<u16>,
+    malformed: bool,
+    fallback: bool,
+    no_batch: bool,
+    date: Option<String>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut o = Options {
+            port: DEFAULT_PORT,
+            ..Options::default()
+        };
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or(format!("{flag} needs a value"));
+            match flag.as_str() {
+                "--port" => o.port = parse_value(&flag, &value()?)?,
+                "--fixtures" => o.fixtures = Some(PathBuf::from(value()?)),
+                "--latency" => o.latency_ms = parse_value(&flag, &value()?)?,
+                "--fail" => {
+                    o.fail_rate = parse_value(&flag, &value()?)?;
+                    if !(0.0..=1.0).contains(&o.fail_rate) {
+                        return Err("--fail takes a share between 0 and 1".to_string());
+                    }
+                }
+                "--status" => o.status = Some(parse_value(&flag, &value()?)?),
+                "--malformed" => o.malformed = true,
+                "--fallback" => o.fallback = true,
+                "--no-batch" => o.no_batch = true,
+                "--date" => o.date = Some(value()?),
+                "-h" | "--help" => return Err(String::new()),
+                other => return Err(format!("unknown option {other}")),
+            }
+        }
+        Ok(o)
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, v: &str) -> Result<T, String> {
+    v.parse().map_err(|_| format!("{flag}: can't read {v:?}"))
+}
+
+/// Entry point for `mock-worker`; `args` are the ones after it.
+pub fn main(args: impl Iterator<Item = String>) {
+    let opts = match Options::parse(args) {
+        Ok(o) => Arc::new(o),
+        Err(why) => {
+            if !why.is_empty() {
+                eprintln!("{why}\n");
+            }
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    let addr = ("127.0.0.1", opts.port);
+    let listener = match TcpListener::bind(addr) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("can't listen on 127.0.0.1:{}: {e}", opts.port);
+            std::process::exit(1);
+        }
+    };
+    println!("mock worker on http://127.0.0.1:{} (set this as the Worker URL in ⚙️ Settings)", opts.port);
+
+    for stream in listener.incoming().flatten() {
+        let opts = Arc::clone(&opts);
+        // One thread per request so a slow (latency) reply doesn't hold up the others.
+        std::thread::spawn(move || {
+            if let Err(e) = handle(stream, &opts) {
+                eprintln!("connection error: {e}");
+            }
+        });
+    }
+}
+
+// ----------------------------
+// HTTP
+// ----------------------------
+
+fn handle(mut stream: TcpStream, opts: &Options) -> std::io::Result<()> {
+    let mut buf = [0u8; 8192];
+    let n = stream.read(&mut buf)?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let mut line = request.lines().next().unwrap_or_default().split_whitespace();
+    let (method, target) = (line.next().unwrap_or_default(), line.next().unwrap_or_default());
+
+    let (status, body) = match method {
+        // CORS preflight: the app runs on another port (trunk serve).
+        "OPTIONS" => (204, String::new()),
+        "GET" => respond(target, opts),
+        _ => (405, error_body("only GET is served")),
+    };
+    println!("{method} {target} → {status} ({} bytes)", body.len());
+
+    if opts.latency_ms > 0 {
+        std::thread::sleep(Duration::from_millis(opts.latency_ms));
+    }
+    let head = format!(
+        "HTTP/1.1 {status} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Methods: GET, OPTIONS\r\n\
+         Access-Control-Allow-Headers: *\r\n\
+         Connection: close\r\n\r\n",
+        reason(status),
+        body.len()
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+/// (status, body) for a GET.
+fn respond(target: &str, opts: &Options) -> (u16, String) {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|kv| kv.split_once('='))
+            .find(|(k, _)| *k == name)
+            .map(|(_, v)| v.to_string())
+    };
+    let number = |name: &str| param(name).and_then(|v| v.parse::<u32>().ok());
+
+    let batch = match path {
+        DAY_PATH => false,
+        BATCH_PATH if !opts.no_batch => true,
+        CAPABILITIES_PATH if !opts.no_batch => {
+            let caps = Capabilities {
+                batch: true,
+                dated: true,
+                max_batch: MAX_BATCH,
+            };
+            return (200, serde_json::to_string(&caps).unwrap_or_default());
+        }
+        _ => return (404, error_body("not found")),
+    };
+
+    let Some(grade @ 1..=5) = number("grade") else {
+        return (400, error_body("grade must be 1–5"));
+    };
+    let grade = Grade::from_u8(grade as u8);
+    let today = opts.date.clone().unwrap_or_else(today_ymd_utc);
+
+    // (day, date) pairs asked for.
+    let sets: Vec<(usize, String)> = if !batch {
+        let Some(day_id @ 1..=12) = number("day") else {
+            return (400, error_body("day must be 1–12"));
+        };
+        vec![(day_id as usize, param("date").unwrap_or(today))]
+    } else {
+        let days: Option<Vec<usize>> = param("days")
+            .unwrap_or_default()
+            .split(',')
+            .map(|d| d.parse().ok().filter(|d| (1..=12).contains(d)))
+            .collect();
+        let (Some(days), Some(from), Some(to)) = (days, param("from"), param("to")) else {
+            return (400, error_body("days must be a list of 1–12, with from and to dates"));
+        };
+        let mut dates = vec![from];
+        while dates.len() < MAX_BATCH_DATES && *dates.last().unwrap_or(&to) < to {
+            dates.push(add_days(&dates[dates.len() - 1], 1));
+        }
+        if days.len() * dates.len() > MAX_BATCH {
+            return (400, error_body("too many sets for one batch"));
+        }
+        dates.iter().flat_map(|ymd| days.iter().map(move |d| (*d, ymd.clone()))).collect()
+    };
+
+    if let Some(code) = opts.status {
+        return (code, error_body("forced by --status"));
+    }
+    if opts.fail_rate > 0.0 && rand::thread_rng().gen_bool(opts.fail_rate) {
+        return (503, error_body("random failure (--fail)"));
+    }
+
+    let mut bodies = sets.iter().map(|(day_id, ymd)| {
+        // --date pins every set to one date (e.g. an old one, to test stale sets).
+        let ymd = opts.date.as_deref().unwrap_or(ymd);
+        fixture(opts, grade, *day_id, ymd).unwrap_or_else(|| {
+            serde_json::to_string(&bank_day(grade, *day_id, ymd, opts.fallback)).unwrap_or_default()
+        })
+    });
+    let body = if !batch {
+        bodies.next().unwrap_or_default()
+    } else {
+        // Spliced as text so broken fixtures stay broken inside a batch too.
+        let days = bodies.collect::<Vec<_>>().join(",");
+        format!(r#"{{"version":{VERSION},"grade":{},"days":[{days}]}}"#, grade.as_u8())
+    };
+
+    if opts.malformed {
+        let cut = body.char_indices().nth(body.chars().count() / 2).map_or(0, |(i, _)| i);
+        return (200, body[..cut].to_string());
+    }
+    (200, body)
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Status",
+    }
+}
+
+// ----------------------------
+// Content
+// ----------------------------
+
+/// `<dir>/grade<g>-day<d>.json`, dated `ymd` so it stays fresh day after day.
+/// A fixture that isn't valid JSON is sent as-is (so broken fixtures stay broken).
+fn fixture(opts: &Options, grade: Grade, day_id: usize, ymd: &str) -> Option<String> {
+    let dir = opts.fixtures.as_ref()?;
+    let text = std::fs::read_to_string(dir.join(format!("grade{}-day{}.json", grade.as_u8(), day_id))).ok()?;
+    let Ok(mut json) = serde_json::from_str::<serde_json::Value>(&text) else {
+        return Some(text);
+    };
+    if let Some(day) = json.as_object_mut() {
+        day.insert("date_ymd".to_string(), ymd.into());
+    }
+    Some(json.to_string())
+}
+
+/// A day's set from the local bank, the same for every request that day.
+fn bank_day(grade: Grade, day_id: usize, ymd: &str, fallback: bool) -> DayResponse {
+    let mut rng = question_rng(prompt_seed(&format!("mock|{ymd}|{}|{day_id}", grade.as_u8())), 0);
+    let items = questions_for(day_id, grade, &mut rng)
+        .into_iter()
+        .map(|q| AiQuestion {
+            prompt: q.prompt,
+            answer: q.answer,
+            difficulty: match q.difficulty {
+                Difficulty::Core => AiDifficulty::Core,
+                Difficulty::Stretch => AiDifficulty::Stretch,
+            },
+            answer_kind: q.answer_kind,
+            skill: Some(q.skill),
+            hints: q.hints,
+            solution: q.solution,
+            known_wrong: q.known_wrong,
+            format: q.format,
+            visual: q.visual,
+        })
+        .collect();
+
+    DayResponse {
+        version: VERSION,
+        grade: grade.as_u8(),
+        day_id,
+        date_ymd: ymd.to_string(),
+        items,
+        source: if fallback { "fallback" } else { "ai" }.to_string(),
+        check: Default::default(),
+    }
+}
+
+/// Today's date in UTC as YYYY-MM-DD.
+fn today_ymd_utc() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    add_days("1970-01-01", (secs / 86_400) as i64)
+}
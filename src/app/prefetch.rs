@@ -0,0 +1,183 @@
+// src/app/prefetch.rs
+//
+// Background loading of a grade's online sets. When the worker serves
+// batches, the missing days come in one request (today's and, for dated
+// workers, tomorrow's too); anything a batch didn't cover, or every day on
+// an older worker, is fetched one day at a time with at most MAX_IN_FLIGHT
+// requests open.
+//
+// Tomorrow's sets go to a separate cache slot and take over at midnight, so
+// the first session of a day doesn't wait on the network.
+//
+// Every run carries a Cancel. Home and DayView cancel it when the grade or
+// learner changes; after that, nothing the run receives is cached (the cache
+// keys follow the active learner, so a late response could land in the
+// wrong learner's slot).
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use futures::stream::{self, StreamExt};
+
+use super::ai_day::DayResponse;
+use super::config::AppConfig;
+use super::grade::Grade;
+use super::review::add_days;
+use super::worker::{capabilities, fetch_batch, fetch_day, WorkerError};
+use super::{load_ahead_day, load_day_from_local_storage, save_day_to_local_storage};
+
+/// Per-day requests open at once (phones on slow links choke on 12).
+const MAX_IN_FLIGHT: usize = 3;
+
+/// Shared "stop" flag for one prefetch run.
+#[derive(Clone, Default)]
+pub struct Cancel(Rc<Cell<bool>>);
+
+impl Cancel {
+    pub fn cancel(&self) {
+        self.0.set(true);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.get()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Outcome {
+    /// Days whose set for today couldn't be loaded, and why (by day).
+    pub problems: Vec<(usize, WorkerError)>,
+    /// At least one fresh AI set for today was cached.
+    pub fresh_ai: bool,
+}
+
+/// Load the `days` of `grade` that aren't cached for `today` (and, with
+/// `ahead`, for tomorrow). None if the run was cancelled part way.
+pub async fn prefetch(
+    config: &AppConfig,
+    grade: Grade,
+    days: &[usize],
+    today: &str,
+    ahead: bool,
+    cancel: &Cancel,
+) -> Option<Outcome> {
+    let caps = capabilities(config).await;
+    if cancel.is_cancelled() {
+        return None;
+    }
+    let tomorrow = add_days(today, 1);
+
+    let mut need_today: Vec<usize> = days
+        .iter()
+        .copied()
+        .filter(|d| load_day_from_local_storage(grade, *d).is_none_or(|c| c.date_ymd != today))
+        .collect();
+    let mut need_tomorrow: Vec<usize> = if ahead && caps.dated {
+        days.iter()
+            .copied()
+            .filter(|d| load_ahead_day(grade, *d).is_none_or(|c| c.date_ymd != tomorrow))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut outcome = Outcome::default();
+    let keep = |day: &DayResponse, outcome: &mut Outcome| {
+        save_day_to_local_storage(grade, day.day_id, day);
+        if day.date_ymd == today && day.source == "ai" {
+            outcome.fresh_ai = true;
+        }
+    };
+
+    if caps.batch && !(need_today.is_empty() && need_tomorrow.is_empty()) {
+        let from = if need_today.is_empty() { tomorrow.as_str() } else { today };
+        let to = if need_tomorrow.is_empty() { today } else { tomorrow.as_str() };
+        for chunk in batch_chunks(&need_today, &need_tomorrow, caps.max_batch) {
+            // A failed batch leaves the rest to the per-day requests below.
+            let Ok(sets) = fetch_batch(config, grade, &chunk, from, to).await else {
+                break;
+            };
+            if cancel.is_cancelled() {
+                return None;
+            }
+            for day in sets {
+                let need = if day.date_ymd == today { &mut need_today } else { &mut need_tomorrow };
+                // The batch asks for every wanted day on both dates; a day that was
+                // already cached keeps its set (a child may be partway through it).
+                if !need.contains(&day.day_id) {
+                    continue;
+                }
+                need.retain(|d| *d != day.day_id);
+                keep(&day, &mut outcome);
+            }
+        }
+        // Tomorrow can wait for the next visit rather than cost a request per day.
+        need_tomorrow.clear();
+    }
+
+    // Dated workers get today's date too, so a worker in another time zone
+    // still sends the set this device calls today.
+    let today_param = caps.dated.then_some(today);
+    let jobs = need_today
+        .iter()
+        .map(|d| (*d, today_param))
+        .chain(need_tomorrow.iter().map(|d| (*d, Some(tomorrow.as_str()))));
+    let mut results = stream::iter(jobs)
+        .map(|(day_id, date)| async move { (day_id, date, fetch_day(config, grade, day_id, date).await) })
+        .buffer_unordered(MAX_IN_FLIGHT);
+
+    while let Some((day_id, date, result)) = results.next().await {
+        if cancel.is_cancelled() {
+            return None;
+        }
+        match result {
+            Ok(day) => keep(&day, &mut outcome),
+            // Local questions cover the day; only today's misses are worth mentioning.
+            Err(e) if date != Some(tomorrow.as_str()) => outcome.problems.push((day_id, e)),
+            Err(_) => {}
+        }
+    }
+
+    outcome.problems.sort_by_key(|(d, _)| *d);
+    Some(outcome)
+}
+
+/// Days for each batch request. A batch covers every wanted day on each
+/// date asked for, so with both dates a request holds half as many days.
+fn batch_chunks(need_today: &[usize], need_tomorrow: &[usize], max_batch: usize) -> Vec<Vec<usize>> {
+    let mut wanted: Vec<usize> = need_today.iter().chain(need_tomorrow).copied().collect();
+    wanted.sort_unstable();
+    wanted.dedup();
+
+    let dates = if need_today.is_empty() || need_tomorrow.is_empty() { 1 } else { 2 };
+    wanted.chunks((max_batch / dates).max(1)).map(<[usize]>::to_vec).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_date_fills_each_batch() {
+        let days: Vec<usize> = (1..=12).collect();
+        assert_eq!(
+            batch_chunks(&days, &[], 5),
+            vec![vec![1, 2, 3, 4, 5], vec![6, 7, 8, 9, 10], vec![11, 12]]
+        );
+        assert_eq!(batch_chunks(&[], &[3, 1, 2], 12), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn two_dates_halve_the_days_per_batch() {
+        assert_eq!(
+            batch_chunks(&[1, 2, 3], &[3, 4, 5], 4),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn a_tiny_limit_still_asks_for_one_day_at_a_time() {
+        assert_eq!(batch_chunks(&[2, 1], &[1], 1), vec![vec![1], vec![2]]);
+        assert!(batch_chunks(&[], &[], 12).is_empty());
+    }
+}
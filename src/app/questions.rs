@@ -3,10 +3,26 @@
 // Kid-friendly question bank for Grades 1–5.
 // Each day provides Core questions + Stretch questions (gentle challenge).
 //
+// The hand-written items below are "seeds". Every day also has a small
+// generator that builds fresh, correct items with grade-appropriate numbers,
+// so the offline bank doesn't repeat itself after two sessions.
+//
 // NOTE: Names and comments intentionally avoid scary academic labels.
 // The UI uses titles/subtitles from data.rs.
 
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+use super::answer::AnswerKind;
+use super::formats::QuestionFormat;
 use super::grade::Grade;
+use super::misconceptions::{KnownWrong, Misconception};
+use super::skill::Skill;
+use super::visuals::{Bar, GridPoint, Visual};
+
+/// How many generated items get mixed in next to the seeds, per difficulty.
+const FRESH_CORE: usize = 4;
+const FRESH_STRETCH: usize = 2;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Difficulty {
@@ -16,13 +32,86 @@ pub enum Difficulty {
 
 #[derive(Clone, Debug)]
 pub struct Question {
-    pub prompt: &'static str,
-    pub answer: &'static str,
+    pub prompt: String,
+    pub answer: String,
     pub difficulty: Difficulty,
+    /// How to compare the child's answer; None = infer from `answer`.
+    pub answer_kind: Option<AnswerKind>,
+    /// Explicit skill tag (defaults to the day's topic).
+    pub skill: Skill,
+    /// Hint steps, gentlest first. Empty = built by hints::help_for.
+    pub hints: Vec<String>,
+    /// Worked solution; None = built by hints::help_for.
+    pub solution: Option<String>,
+    /// Predictable wrong answers and the misunderstanding behind each.
+    pub known_wrong: Vec<KnownWrong>,
+    /// How it's answered on screen; None = picked by formats::format_for.
+    pub format: Option<QuestionFormat>,
+    /// Picture drawn above the prompt.
+    pub visual: Option<Visual>,
 }
 
-pub fn questions_for(day_id: usize, grade: Grade) -> Vec<Question> {
-    match day_id {
+impl Question {
+    fn kind(mut self, kind: AnswerKind) -> Question {
+        self.answer_kind = Some(kind);
+        self
+    }
+
+    fn format(mut self, format: QuestionFormat) -> Question {
+        self.format = Some(format);
+        self
+    }
+
+    fn visual(mut self, visual: Visual) -> Question {
+        self.visual = Some(visual);
+        self
+    }
+
+    /// Declare a predictable wrong answer (skipped if it happens to be right).
+    fn wrong(mut self, answer: impl ToString, misconception: Misconception) -> Question {
+        let answer = answer.to_string();
+        if answer != self.answer && !self.known_wrong.iter().any(|w| w.answer == answer) {
+            self.known_wrong.push(KnownWrong {
+                answer,
+                misconception,
+                feedback: None,
+            });
+        }
+        self
+    }
+
+    fn hints<const N: usize>(mut self, hints: [String; N], solution: String) -> Question {
+        self.hints = hints.into();
+        self.solution = Some(solution);
+        self
+    }
+}
+
+/// Seeds for the day plus a handful of freshly generated items.
+pub fn questions_for(day_id: usize, grade: Grade, rng: &mut dyn RngCore) -> Vec<Question> {
+    let mut bank = seed_questions_for(day_id, grade);
+
+    for (difficulty, n) in [
+        (Difficulty::Core, FRESH_CORE),
+        (Difficulty::Stretch, FRESH_STRETCH),
+    ] {
+        for _ in 0..n {
+            let Some(fresh) = fresh_question(day_id, grade, difficulty, rng) else {
+                return bank;
+            };
+            // Small ranges can produce the same item twice; skip repeats.
+            if !bank.iter().any(|b| b.prompt == fresh.prompt) {
+                bank.push(fresh);
+            }
+        }
+    }
+
+    bank
+}
+
+/// The hand-written items for a day (no randomness).
+pub fn seed_questions_for(day_id: usize, grade: Grade) -> Vec<Question> {
+    let bank = match day_id {
         1 => day1_patterns_and_change(grade),
         2 => day2_missing_number_puzzles(grade),
         3 => day3_shapes_around_us(grade),
@@ -36,7 +125,41 @@ pub fn questions_for(day_id: usize, grade: Grade) -> Vec<Question> {
         11 => day11_how_we_know(grade),
         12 => day12_choosing_wisely(grade),
         _ => vec![q("Try another day!", "ok", Difficulty::Core)],
+    };
+    bank.into_iter().map(|item| tag_topic(item, day_id)).collect()
+}
+
+/// One generated item for a day, or None for an unknown day.
+pub fn fresh_question(
+    day_id: usize,
+    grade: Grade,
+    difficulty: Difficulty,
+    rng: &mut dyn RngCore,
+) -> Option<Question> {
+    let make: fn(Grade, Difficulty, &mut dyn RngCore) -> Question = match day_id {
+        1 => day1_fresh,
+        2 => day2_fresh,
+        3 => day3_fresh,
+        4 => day4_fresh,
+        5 => day5_fresh,
+        6 => day6_fresh,
+        7 => day7_fresh,
+        8 => day8_fresh,
+        9 => day9_fresh,
+        10 => day10_fresh,
+        11 => day11_fresh,
+        12 => day12_fresh,
+        _ => return None,
+    };
+    Some(tag_topic(make(grade, difficulty, rng), day_id))
+}
+
+/// Items that weren't tagged more specifically get the day's topic skill.
+fn tag_topic(mut item: Question, day_id: usize) -> Question {
+    if item.skill == Skill::Unknown {
+        item.skill = Skill::for_day(day_id);
     }
+    item
 }
 
 /* ---------------------------
@@ -56,88 +179,520 @@ fn day1_patterns_and_change(grade: Grade) -> Vec<Question> {
         ],
         Grade::G3 => vec![
             q("What comes next? 3, 6, 9, __", "12", Difficulty::Core),
-            q("How much did it change? 12 → 19", "7", Difficulty::Core),
+            q("How much did it change? 12 → 19", "7", Difficulty::Core)
+                .wrong("31", Misconception::ChangeAddedNotSubtracted),
             q("Stretch: A plant grows 2 cm each day. How much in 5 days?", "10", Difficulty::Stretch),
         ],
         Grade::G4 => vec![
-            q("How much did it change? 45 → 60", "15", Difficulty::Core),
+            q("How much did it change? 45 → 60", "15", Difficulty::Core)
+                .wrong("105", Misconception::ChangeAddedNotSubtracted),
             q("What comes next? 100, 90, 80, __", "70", Difficulty::Core),
             q("Stretch: You save $4 per week. How much after 6 weeks?", "24", Difficulty::Stretch),
         ],
         Grade::G5 => vec![
-            q("How much did it change? 2.5 → 4.0", "1.5", Difficulty::Core),
+            q("How much did it change? 2.5 → 4.0", "1.5", Difficulty::Core)
+                .wrong("6.5", Misconception::ChangeAddedNotSubtracted),
             q("What comes next? 1, 4, 9, 16, __", "25", Difficulty::Core),
             q("Stretch: A car goes 30 miles in 1 hour. How far in 2.5 hours?", "75", Difficulty::Stretch),
         ],
     }
 }
 
+fn day1_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (a, d) = (rng.gen_range(0..=5), rng.gen_range(1..=2));
+            next_in_sequence(a, d)
+        }
+        (Grade::G1, Stretch) => {
+            let (d, n) = (rng.gen_range(2..=3), rng.gen_range(2..=4));
+            q(
+                format!("Stretch: You hop {d} spaces each time. Starting at 0, where are you after {n} hops?"),
+                (d * n).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G2, Core) => {
+            if rng.gen_bool(0.5) {
+                let d = *[2, 5, 10].choose(rng).unwrap();
+                next_in_sequence(d * rng.gen_range(1..=3), d)
+            } else {
+                let (s, d) = (rng.gen_range(14..=20), rng.gen_range(2..=3));
+                q(
+                    format!("Count down: {}, {}, {}, __", s, s - d, s - 2 * d),
+                    (s - 3 * d).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G2, Stretch) => {
+            let (d, s, n) = (rng.gen_range(2..=4), rng.gen_range(0..=3), rng.gen_range(3..=5));
+            q(
+                format!("Stretch: You earn {d} points each turn. If you start at {s}, what after {n} turns?"),
+                (s + d * n).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                let d = rng.gen_range(3..=9);
+                next_in_sequence(d, d)
+            } else {
+                let a = rng.gen_range(5..=30);
+                change_between(a, a + rng.gen_range(2..=15))
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let (d, n) = (rng.gen_range(2..=4), rng.gen_range(3..=7));
+            q(
+                format!("Stretch: A plant grows {d} cm each day. How much in {n} days?"),
+                (d * n).to_string(),
+                Stretch,
+            )
+            .kind(unit("cm"))
+        }
+        (Grade::G4, Core) => {
+            if rng.gen_bool(0.5) {
+                let a = rng.gen_range(20..=80);
+                change_between(a, a + rng.gen_range(5..=40))
+            } else {
+                let d = *[5, 10, 20, 25].choose(rng).unwrap();
+                next_in_sequence(d * rng.gen_range(6..=10), -d)
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let (d, n) = (rng.gen_range(3..=9), rng.gen_range(4..=9));
+            q(
+                format!("Stretch: You save ${d} per week. How much after {n} weeks?"),
+                (d * n).to_string(),
+                Stretch,
+            )
+            .kind(AnswerKind::Money)
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                // Work in tenths so the answer is exact.
+                let a = rng.gen_range(10..=50);
+                let b = a + rng.gen_range(5..=30);
+                q(
+                    format!("How much did it change? {} → {}", ratio_str(a, 10, 1), ratio_str(b, 10, 1)),
+                    ratio_str(b - a, 10, 0),
+                    Core,
+                )
+                .wrong(ratio_str(a + b, 10, 0), Misconception::ChangeAddedNotSubtracted)
+            } else {
+                let k: i32 = rng.gen_range(1..=4);
+                let sq = |i: i32| (k + i) * (k + i);
+                q(
+                    format!("What comes next? {}, {}, {}, {}, __", sq(0), sq(1), sq(2), sq(3)),
+                    sq(4).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let s = *[20, 30, 40, 50, 60].choose(rng).unwrap();
+            let halves = rng.gen_range(3..=7);
+            q(
+                format!(
+                    "Stretch: A car goes {s} miles in 1 hour. How far in {} hours?",
+                    ratio_str(halves, 2, 0)
+                ),
+                (s * halves / 2).to_string(),
+                Stretch,
+            )
+        }
+    }
+}
+
+fn next_in_sequence(start: i32, step: i32) -> Question {
+    q(
+        format!(
+            "What comes next? {}, {}, {}, __",
+            start,
+            start + step,
+            start + 2 * step
+        ),
+        (start + 3 * step).to_string(),
+        Difficulty::Core,
+    )
+}
+
+fn change_between(from: i32, to: i32) -> Question {
+    q(
+        format!("How much did it change? {from} → {to}"),
+        (to - from).to_string(),
+        Difficulty::Core,
+    )
+    .wrong(from + to, Misconception::ChangeAddedNotSubtracted)
+}
+
 /* ---------------------------
    DAY 2 — Find the Missing Number
 ---------------------------- */
 fn day2_missing_number_puzzles(grade: Grade) -> Vec<Question> {
     match grade {
         Grade::G1 => vec![
-            q("3 + __ = 5", "2", Difficulty::Core),
-            q("__ + 4 = 7", "3", Difficulty::Core),
-            q("Stretch: 9 - __ = 6", "3", Difficulty::Stretch),
+            q("3 + __ = 5", "2", Difficulty::Core)
+                .wrong("8", Misconception::UndidWrongWay),
+            q("__ + 4 = 7", "3", Difficulty::Core)
+                .wrong("11", Misconception::UndidWrongWay),
+            q("Stretch: 9 - __ = 6", "3", Difficulty::Stretch)
+                .wrong("15", Misconception::UndidWrongWay),
         ],
         Grade::G2 => vec![
-            q("__ + 6 = 10", "4", Difficulty::Core),
-            q("12 - __ = 5", "7", Difficulty::Core),
-            q("Stretch: 3 × __ = 12", "4", Difficulty::Stretch),
+            q("__ + 6 = 10", "4", Difficulty::Core)
+                .wrong("16", Misconception::UndidWrongWay),
+            q("12 - __ = 5", "7", Difficulty::Core)
+                .wrong("17", Misconception::UndidWrongWay),
+            q("Stretch: 3 × __ = 12", "4", Difficulty::Stretch)
+                .wrong("36", Misconception::UndidWrongWay),
         ],
         Grade::G3 => vec![
-            q("__ + 14 = 20", "6", Difficulty::Core),
+            q("__ + 14 = 20", "6", Difficulty::Core)
+                .wrong("34", Misconception::UndidWrongWay),
             q("36 ÷ __ = 6", "6", Difficulty::Core),
-            q("Stretch: 2 × __ + 3 = 11", "4", Difficulty::Stretch),
+            q("Stretch: 2 × __ + 3 = 11", "4", Difficulty::Stretch)
+                .wrong("8", Misconception::StoppedAfterFirstStep),
         ],
         Grade::G4 => vec![
-            q("x + 18 = 40. x = ?", "22", Difficulty::Core),
+            q("x + 18 = 40. x = ?", "22", Difficulty::Core)
+                .wrong("58", Misconception::UndidWrongWay),
             q("5x = 35. x = ?", "7", Difficulty::Core),
-            q("Stretch: 3x + 2 = 20. x = ?", "6", Difficulty::Stretch),
+            q("Stretch: 3x + 2 = 20. x = ?", "6", Difficulty::Stretch)
+                .wrong("18", Misconception::StoppedAfterFirstStep),
         ],
         Grade::G5 => vec![
-            q("x - 12 = 19. x = ?", "31", Difficulty::Core),
+            q("x - 12 = 19. x = ?", "31", Difficulty::Core)
+                .wrong("7", Misconception::UndidWrongWay),
             q("4x = 48. x = ?", "12", Difficulty::Core),
-            q("Stretch: 2x + 5 = 29. x = ?", "12", Difficulty::Stretch),
+            q("Stretch: 2x + 5 = 29. x = ?", "12", Difficulty::Stretch)
+                .wrong("24", Misconception::StoppedAfterFirstStep),
         ],
     }
 }
 
+fn day2_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (a, x) = (rng.gen_range(1..=5), rng.gen_range(1..=5));
+            let item = if rng.gen_bool(0.5) {
+                q(format!("{a} + __ = {}", a + x), x.to_string(), Core)
+            } else {
+                q(format!("__ + {a} = {}", a + x), x.to_string(), Core)
+            };
+            item.wrong(2 * a + x, Misconception::UndidWrongWay)
+        }
+        (Grade::G1, Stretch) => {
+            let c = rng.gen_range(6..=10);
+            let x = rng.gen_range(1..c);
+            q(format!("Stretch: {c} - __ = {}", c - x), x.to_string(), Stretch)
+                .wrong(2 * c - x, Misconception::UndidWrongWay)
+        }
+        (Grade::G2, Core) => {
+            let x = rng.gen_range(2..=9);
+            if rng.gen_bool(0.5) {
+                let b = rng.gen_range(2..=9);
+                q(format!("__ + {b} = {}", x + b), x.to_string(), Core).wrong(x + 2 * b, Misconception::UndidWrongWay)
+            } else {
+                let c = rng.gen_range(10..=18);
+                q(format!("{c} - __ = {}", c - x), x.to_string(), Core).wrong(2 * c - x, Misconception::UndidWrongWay)
+            }
+        }
+        (Grade::G2, Stretch) => {
+            let (k, x) = (rng.gen_range(2..=5), rng.gen_range(2..=6));
+            q(format!("Stretch: {k} × __ = {}", k * x), x.to_string(), Stretch)
+                .wrong(k * k * x, Misconception::UndidWrongWay)
+        }
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                let (b, x) = (rng.gen_range(5..=20), rng.gen_range(3..=15));
+                q(format!("__ + {b} = {}", x + b), x.to_string(), Core).wrong(x + 2 * b, Misconception::UndidWrongWay)
+            } else {
+                let (x, quotient) = (rng.gen_range(2..=9), rng.gen_range(2..=9));
+                q(format!("{} ÷ __ = {quotient}", x * quotient), x.to_string(), Core)
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let (a, x, b) = (rng.gen_range(2..=4), rng.gen_range(2..=6), rng.gen_range(1..=9));
+            q(
+                format!("Stretch: {a} × __ + {b} = {}", a * x + b),
+                x.to_string(),
+                Stretch,
+            )
+            .wrong(a * x, Misconception::StoppedAfterFirstStep)
+        }
+        (Grade::G4, Core) => {
+            if rng.gen_bool(0.5) {
+                let (b, x) = (rng.gen_range(10..=40), rng.gen_range(5..=40));
+                q(format!("x + {b} = {}. x = ?", x + b), x.to_string(), Core).wrong(x + 2 * b, Misconception::UndidWrongWay)
+            } else {
+                let (k, x) = (rng.gen_range(2..=9), rng.gen_range(2..=12));
+                q(format!("{k}x = {}. x = ?", k * x), x.to_string(), Core)
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let (a, x, b) = (rng.gen_range(2..=5), rng.gen_range(2..=10), rng.gen_range(1..=12));
+            q(
+                format!("Stretch: {a}x + {b} = {}. x = ?", a * x + b),
+                x.to_string(),
+                Stretch,
+            )
+            .wrong(a * x, Misconception::StoppedAfterFirstStep)
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let (b, c) = (rng.gen_range(5..=30), rng.gen_range(5..=40));
+                q(format!("x - {b} = {c}. x = ?"), (b + c).to_string(), Core).wrong(c - b, Misconception::UndidWrongWay)
+            } else {
+                let (k, x) = (rng.gen_range(3..=12), rng.gen_range(3..=15));
+                q(format!("{k}x = {}. x = ?", k * x), x.to_string(), Core)
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let (a, x) = (rng.gen_range(2..=9), rng.gen_range(3..=15));
+            if rng.gen_bool(0.5) {
+                let b = rng.gen_range(1..=20);
+                q(
+                    format!("Stretch: {a}x + {b} = {}. x = ?", a * x + b),
+                    x.to_string(),
+                    Stretch,
+                )
+                .wrong(a * x, Misconception::StoppedAfterFirstStep)
+            } else {
+                let b = rng.gen_range(1..a * x);
+                q(
+                    format!("Stretch: {a}x - {b} = {}. x = ?", a * x - b),
+                    x.to_string(),
+                    Stretch,
+                )
+                .wrong(a * x, Misconception::StoppedAfterFirstStep)
+            }
+        }
+    }
+}
+
 /* ---------------------------
    DAY 3 — Shapes Around Us
 ---------------------------- */
 fn day3_shapes_around_us(grade: Grade) -> Vec<Question> {
     match grade {
         Grade::G1 => vec![
-            q("How many sides does a triangle have?", "3", Difficulty::Core),
-            q("How many corners does a rectangle have?", "4", Difficulty::Core),
-            q("Stretch: A square has 4 sides. Are they all the same length? (yes/no)", "yes", Difficulty::Stretch),
+            q("How many sides does a triangle have?", "3", Difficulty::Core)
+                .wrong("6", Misconception::CountedCornersTwice)
+                .visual(shape_picture("triangle")),
+            q("How many corners does a rectangle have?", "4", Difficulty::Core)
+                .wrong("8", Misconception::CountedCornersTwice)
+                .visual(shape_picture("rectangle")),
+            q("Stretch: A square has 4 sides. Are they all the same length? (yes/no)", "yes", Difficulty::Stretch)
+                .visual(shape_picture("square")),
         ],
         Grade::G2 => vec![
-            q("How many sides does a hexagon have?", "6", Difficulty::Core),
-            q("A rectangle has how many sides total?", "4", Difficulty::Core),
-            q("Stretch: A shape with 8 sides is an __.", "octagon", Difficulty::Stretch),
+            q("How many sides does a hexagon have?", "6", Difficulty::Core)
+                .wrong("12", Misconception::CountedCornersTwice)
+                .visual(shape_picture("hexagon")),
+            q("A rectangle has how many sides total?", "4", Difficulty::Core)
+                .wrong("8", Misconception::CountedCornersTwice)
+                .visual(shape_picture("rectangle")),
+            q("Stretch: A shape with 8 sides is an __.", "octagon", Difficulty::Stretch)
+                .format(shape_choices()),
         ],
         Grade::G3 => vec![
-            q("Perimeter: a square has side 5. Perimeter = ?", "20", Difficulty::Core),
-            q("Perimeter: a rectangle is 3 by 7. Perimeter = ?", "20", Difficulty::Core),
-            q("Stretch: Area: a rectangle is 4 by 6. Area = ?", "24", Difficulty::Stretch),
+            q("Perimeter: a square has side 5. Perimeter = ?", "20", Difficulty::Core)
+                .wrong("25", Misconception::MixedAreaAndPerimeter)
+                .visual(Visual::rectangle(5, 5)),
+            q("Perimeter: a rectangle is 3 by 7. Perimeter = ?", "20", Difficulty::Core)
+                .wrong("21", Misconception::MixedAreaAndPerimeter)
+                .wrong("10", Misconception::AddedOnlyTwoSides)
+                .visual(Visual::rectangle(7, 3)),
+            q("Stretch: Area: a rectangle is 4 by 6. Area = ?", "24", Difficulty::Stretch)
+                .wrong("20", Misconception::MixedAreaAndPerimeter)
+                .visual(Visual::rectangle(6, 4)),
         ],
         Grade::G4 => vec![
-            q("Area: a rectangle is 8 by 3. Area = ?", "24", Difficulty::Core),
-            q("Perimeter: rectangle 10 by 2. Perimeter = ?", "24", Difficulty::Core),
-            q("Stretch: A right triangle has legs 3 and 4. Long side = ?", "5", Difficulty::Stretch),
+            q("Area: a rectangle is 8 by 3. Area = ?", "24", Difficulty::Core)
+                .wrong("22", Misconception::MixedAreaAndPerimeter)
+                .visual(Visual::rectangle(8, 3)),
+            q("Perimeter: rectangle 10 by 2. Perimeter = ?", "24", Difficulty::Core)
+                .wrong("20", Misconception::MixedAreaAndPerimeter)
+                .wrong("12", Misconception::AddedOnlyTwoSides)
+                .visual(Visual::rectangle(10, 2)),
+            q("Stretch: A right triangle has legs 3 and 4. Long side = ?", "5", Difficulty::Stretch)
+                .visual(Visual::right_triangle(4, 3, "?")),
         ],
         Grade::G5 => vec![
-            q("Area: a triangle has base 10 and height 6. Area = ?", "30", Difficulty::Core),
+            q("Area: a triangle has base 10 and height 6. Area = ?", "30", Difficulty::Core)
+                .wrong("60", Misconception::ForgotToHalve)
+                .visual(Visual::right_triangle(10, 6, "")),
             q("A circle’s distance across is called the __.", "diameter", Difficulty::Core),
-            q("Stretch: A right triangle has legs 6 and 8. Long side = ?", "10", Difficulty::Stretch),
+            q("Stretch: A right triangle has legs 6 and 8. Long side = ?", "10", Difficulty::Stretch)
+                .visual(Visual::right_triangle(8, 6, "?")),
         ],
     }
 }
 
+const SHAPE_SIDES: [(&str, i32); 6] = [
+    ("triangle", 3),
+    ("square", 4),
+    ("rectangle", 4),
+    ("pentagon", 5),
+    ("hexagon", 6),
+    ("octagon", 8),
+];
+
+/// An unlabeled outline of a named shape from SHAPE_SIDES.
+fn shape_picture(name: &str) -> Visual {
+    if name == "rectangle" {
+        return Visual::Polygon {
+            points: vec![[0.0, 0.0], [3.0, 0.0], [3.0, 2.0], [0.0, 2.0]],
+            labels: Vec::new(),
+        };
+    }
+    let sides = SHAPE_SIDES.iter().find(|(n, _)| *n == name).map_or(3, |(_, s)| *s);
+    Visual::regular(sides as u32)
+}
+
+/// The 5+ sided names as choices (so "octagon" doesn't have to be spelled).
+fn shape_choices() -> QuestionFormat {
+    QuestionFormat::MultipleChoice {
+        choices: SHAPE_SIDES[3..].iter().map(|(name, _)| name.to_string()).collect(),
+    }
+}
+
+const RIGHT_TRIANGLES: [(i32, i32, i32); 5] =
+    [(3, 4, 5), (6, 8, 10), (5, 12, 13), (9, 12, 15), (8, 15, 17)];
+
+fn day3_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (name, n) = *SHAPE_SIDES[..3].choose(rng).unwrap();
+            let part = if rng.gen_bool(0.5) { "sides" } else { "corners" };
+            q(format!("How many {part} does a {name} have?"), n.to_string(), Core)
+                .wrong(2 * n, Misconception::CountedCornersTwice)
+                .visual(shape_picture(name))
+        }
+        (Grade::G1, Stretch) => {
+            let k = rng.gen_range(2..=3);
+            q(
+                format!("Stretch: How many sides do {k} triangles have altogether?"),
+                (3 * k).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G2, Core) => {
+            let (name, n) = *SHAPE_SIDES.choose(rng).unwrap();
+            q(
+                format!("How many sides does {} {name} have?", a_or_an(name)),
+                n.to_string(),
+                Core,
+            )
+            .wrong(2 * n, Misconception::CountedCornersTwice)
+            .visual(shape_picture(name))
+        }
+        (Grade::G2, Stretch) => {
+            let (name, n) = *SHAPE_SIDES[3..].choose(rng).unwrap();
+            q(
+                format!("Stretch: What do we call a shape with {n} sides?"),
+                name,
+                Stretch,
+            )
+            .format(shape_choices())
+        }
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                let s = rng.gen_range(2..=12);
+                q(
+                    format!("Perimeter: a square has side {s}. Perimeter = ?"),
+                    (4 * s).to_string(),
+                    Core,
+                )
+                .wrong(s * s, Misconception::MixedAreaAndPerimeter)
+                .visual(Visual::rectangle(s, s))
+            } else {
+                let (a, b) = (rng.gen_range(2..=10), rng.gen_range(2..=10));
+                q(
+                    format!("Perimeter: a rectangle is {a} by {b}. Perimeter = ?"),
+                    (2 * (a + b)).to_string(),
+                    Core,
+                )
+                .wrong(a * b, Misconception::MixedAreaAndPerimeter)
+                .wrong(a + b, Misconception::AddedOnlyTwoSides)
+                .visual(Visual::rectangle(a, b))
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let (a, b) = (rng.gen_range(2..=9), rng.gen_range(2..=9));
+            q(
+                format!("Stretch: Area: a rectangle is {a} by {b}. Area = ?"),
+                (a * b).to_string(),
+                Stretch,
+            )
+            .wrong(2 * (a + b), Misconception::MixedAreaAndPerimeter)
+            .visual(Visual::rectangle(a, b))
+        }
+        (Grade::G4, Core) => {
+            let (a, b) = (rng.gen_range(3..=12), rng.gen_range(2..=9));
+            if rng.gen_bool(0.5) {
+                q(format!("Area: a rectangle is {a} by {b}. Area = ?"), (a * b).to_string(), Core)
+                    .wrong(2 * (a + b), Misconception::MixedAreaAndPerimeter)
+                    .visual(Visual::rectangle(a, b))
+            } else {
+                q(
+                    format!("Perimeter: rectangle {a} by {b}. Perimeter = ?"),
+                    (2 * (a + b)).to_string(),
+                    Core,
+                )
+                .wrong(a * b, Misconception::MixedAreaAndPerimeter)
+                .wrong(a + b, Misconception::AddedOnlyTwoSides)
+                .visual(Visual::rectangle(a, b))
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let k = rng.gen_range(1..=3);
+            q(
+                format!("Stretch: A right triangle has legs {} and {}. Long side = ?", 3 * k, 4 * k),
+                (5 * k).to_string(),
+                Stretch,
+            )
+            .visual(Visual::right_triangle(4 * k, 3 * k, "?"))
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let (b, h) = (2 * rng.gen_range(2..=8), rng.gen_range(3..=12));
+                q(
+                    format!("Area: a triangle has base {b} and height {h}. Area = ?"),
+                    (b * h / 2).to_string(),
+                    Core,
+                )
+                .wrong(b * h, Misconception::ForgotToHalve)
+                .visual(Visual::right_triangle(b, h, ""))
+            } else {
+                let r = rng.gen_range(2..=15);
+                q(
+                    format!("A circle has radius {r}. Its diameter = ?"),
+                    (2 * r).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let (a, b, c) = *RIGHT_TRIANGLES.choose(rng).unwrap();
+            q(
+                format!("Stretch: A right triangle has legs {a} and {b}. Long side = ?"),
+                c.to_string(),
+                Stretch,
+            )
+            .visual(Visual::right_triangle(b, a, "?"))
+        }
+    }
+}
+
 /* ---------------------------
    DAY 4 — Angles & Turns
 ---------------------------- */
@@ -150,12 +705,13 @@ fn day4_angles_and_turns(grade: Grade) -> Vec<Question> {
         ],
         Grade::G2 => vec![
             q("How many quarter turns make a full turn?", "4", Difficulty::Core),
-            q("Is a right angle like an L shape? (yes/no)", "yes", Difficulty::Core),
+            q("Is a right angle like an L shape? (yes/no)", "yes", Difficulty::Core).visual(angle_picture(90, false)),
             q("Stretch: Two right angles together make a __ turn (half/full).", "half", Difficulty::Stretch),
         ],
         Grade::G3 => vec![
-            q("A right angle is 90 degrees. Write the number: __", "90", Difficulty::Core),
-            q("A straight angle is 180 degrees. Write the number: __", "180", Difficulty::Core),
+            q("A right angle is 90 degrees. Write the number: __", "90", Difficulty::Core).visual(angle_picture(90, false)),
+            q("A straight angle is 180 degrees. Write the number: __", "180", Difficulty::Core)
+                .visual(angle_picture(180, false)),
             q("Stretch: Two right angles together is __ degrees.", "180", Difficulty::Stretch),
         ],
         Grade::G4 => vec![
@@ -171,39 +727,319 @@ fn day4_angles_and_turns(grade: Grade) -> Vec<Question> {
     }
 }
 
+fn day4_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let k = rng.gen_range(1..=2);
+            q(
+                format!("How many quarter turns make {}?", plural(k, "full turn", "full turns")),
+                (4 * k).to_string(),
+                Core,
+            )
+        }
+        (Grade::G1, Stretch) => {
+            let k = rng.gen_range(1..=3);
+            q(
+                format!("Stretch: You make {} quarter turns. How many half turns is that?", 2 * k),
+                k.to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G2, Core) => {
+            let k = rng.gen_range(1..=3);
+            let (part, per) = if rng.gen_bool(0.5) { ("quarter", 4) } else { ("half", 2) };
+            q(
+                format!("How many {part} turns make {}?", plural(k, "full turn", "full turns")),
+                (per * k).to_string(),
+                Core,
+            )
+        }
+        (Grade::G2, Stretch) => {
+            let (n, turn) = *[(2, "half"), (4, "full")].choose(rng).unwrap();
+            q(
+                format!("Stretch: {n} right angles together make a __ turn (half/full)."),
+                turn,
+                Stretch,
+            )
+        }
+        (Grade::G3, Core) => {
+            let k = rng.gen_range(1..=4);
+            q(
+                format!("{} together is __ degrees.", plural(k, "right angle", "right angles")),
+                (90 * k).to_string(),
+                Core,
+            )
+        }
+        (Grade::G3, Stretch) => {
+            let n = *[2, 3, 5, 6, 9].choose(rng).unwrap();
+            q(
+                format!("Stretch: A right angle is split into {n} equal parts. Each part is __ degrees."),
+                (90 / n).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G4, Core) => {
+            let a = 5 * rng.gen_range(2..=34);
+            q(
+                format!("Two angles make a straight line (180 degrees). One is {a} degrees. The other is __ degrees."),
+                (180 - a).to_string(),
+                Core,
+            )
+            .visual(angle_picture(a, true))
+        }
+        (Grade::G4, Stretch) => {
+            let a = 10 * rng.gen_range(3..=33);
+            q(
+                format!("Stretch: A full turn is 360 degrees. You turned {a} degrees. How many more degrees finish the turn?"),
+                (360 - a).to_string(),
+                Stretch,
+            )
+            .visual(angle_picture(a, true))
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let (a, name) = match rng.gen_range(0..3) {
+                    0 => (5 * rng.gen_range(1..=17), "acute"),
+                    1 => (90, "right"),
+                    _ => (5 * rng.gen_range(19..=35), "obtuse"),
+                };
+                q(
+                    format!("An angle of {a} degrees is called __ (acute/right/obtuse)."),
+                    name,
+                    Core,
+                )
+                .visual(angle_picture(a, true))
+            } else {
+                let n = *[2, 3, 4, 5, 6, 9].choose(rng).unwrap();
+                q(format!("What is 180 ÷ {n}?"), (180 / n).to_string(), Core)
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let a = *[15, 30, 45, 60].choose(rng).unwrap();
+            let n = rng.gen_range(2..=6);
+            q(
+                format!("Stretch: If you turn {a} degrees {n} times, how many degrees is that?"),
+                (a * n).to_string(),
+                Stretch,
+            )
+        }
+    }
+}
+
+/// An angle, optionally labeled with its size.
+fn angle_picture(degrees: i32, labeled: bool) -> Visual {
+    Visual::Angle {
+        degrees: degrees as f64,
+        label: labeled.then(|| format!("{degrees}°")),
+    }
+}
+
 /* ---------------------------
    DAY 5 — Making Sense of Numbers (Groups & Averages)
 ---------------------------- */
 fn day5_groups_and_averages(grade: Grade) -> Vec<Question> {
     match grade {
         Grade::G1 => vec![
-            q("Which number shows up the most? 2, 3, 2, 1", "2", Difficulty::Core),
+            q("Which number shows up the most? 2, 3, 2, 1", "2", Difficulty::Core).visual(dots(&[2, 3, 2, 1])),
             q("What is the biggest number? 5, 1, 4", "5", Difficulty::Core),
             q("Stretch: Put in order (small to big): 3, 1, 2. Write as 1,2,3", "1,2,3", Difficulty::Stretch),
         ],
         Grade::G2 => vec![
-            q("Which number shows up the most? 4, 2, 4, 3, 4", "4", Difficulty::Core),
+            q("Which number shows up the most? 4, 2, 4, 3, 4", "4", Difficulty::Core).visual(dots(&[4, 2, 4, 3, 4])),
             q("What is the middle number? 1, 3, 5", "3", Difficulty::Core),
             q("Stretch: Put in order: 6, 2, 4 (write 2,4,6)", "2,4,6", Difficulty::Stretch),
         ],
         Grade::G3 => vec![
-            q("Mean (average): (2 + 4 + 6) ÷ 3 = ?", "4", Difficulty::Core),
+            q("Mean (average): (2 + 4 + 6) ÷ 3 = ?", "4", Difficulty::Core)
+                .wrong("12", Misconception::ForgotToShare)
+                .visual(bars(&[2, 4, 6])),
             q("Median of 2, 9, 5 (in order 2,5,9) is ?", "5", Difficulty::Core),
-            q("Stretch: Mode of 1, 2, 2, 3, 3, 3 is ?", "3", Difficulty::Stretch),
+            q("Stretch: Mode of 1, 2, 2, 3, 3, 3 is ?", "3", Difficulty::Stretch).visual(dots(&[1, 2, 2, 3, 3, 3])),
         ],
         Grade::G4 => vec![
-            q("Mean: (10 + 20 + 30) ÷ 3 = ?", "20", Difficulty::Core),
+            q("Mean: (10 + 20 + 30) ÷ 3 = ?", "20", Difficulty::Core)
+                .wrong("60", Misconception::ForgotToShare)
+                .visual(bars(&[10, 20, 30])),
             q("Median of 4, 8, 1, 9, 2 is ? (order 1,2,4,8,9)", "4", Difficulty::Core),
             q("Stretch: Range of 2, 10, 7, 5 is ? (max-min)", "8", Difficulty::Stretch),
         ],
         Grade::G5 => vec![
-            q("Mean: (6 + 7 + 9 + 8) ÷ 4 = ?", "7.5", Difficulty::Core),
+            q("Mean: (6 + 7 + 9 + 8) ÷ 4 = ?", "7.5", Difficulty::Core)
+                .wrong("30", Misconception::ForgotToShare)
+                .visual(bars(&[6, 7, 9, 8])),
             q("Range of 3, 12, 8, 5 is ?", "9", Difficulty::Core),
-            q("Stretch: Mean: (1.5 + 2.5 + 3.0) ÷ 3 = ?", "2.333", Difficulty::Stretch),
+            q("Stretch: Mean: (1.5 + 2.5 + 3.0) ÷ 3 = ?", "2.333", Difficulty::Stretch)
+                .wrong("7", Misconception::ForgotToShare),
         ],
     }
 }
 
+fn day5_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            if rng.gen_bool(0.5) {
+                let v = distinct(rng, 1..=9, 3);
+                let max = *v.iter().max().unwrap();
+                q(format!("What is the biggest number? {}", join(&v)), max.to_string(), Core)
+            } else {
+                let v = distinct(rng, 1..=5, 3);
+                let mut list = vec![v[0], v[1], v[0], v[2]];
+                list.shuffle(rng);
+                q(
+                    format!("Which number shows up the most? {}", join(&list)),
+                    v[0].to_string(),
+                    Core,
+                )
+                .visual(dots(&list))
+            }
+        }
+        (Grade::G1, Stretch) => put_in_order(rng, 1..=9, Stretch),
+        (Grade::G2, Core) => {
+            if rng.gen_bool(0.5) {
+                let v = distinct(rng, 1..=9, 3);
+                let mut list = vec![v[0], v[0], v[0], v[1], v[2]];
+                list.shuffle(rng);
+                q(
+                    format!("Which number shows up the most? {}", join(&list)),
+                    v[0].to_string(),
+                    Core,
+                )
+                .visual(dots(&list))
+            } else {
+                let mut v = distinct(rng, 1..=15, 3);
+                v.sort();
+                q(format!("What is the middle number? {}", join(&v)), v[1].to_string(), Core)
+            }
+        }
+        (Grade::G2, Stretch) => put_in_order(rng, 1..=20, Stretch),
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                let m = rng.gen_range(3..=9);
+                let d = rng.gen_range(1..m);
+                let mut v = [m - d, m, m + d];
+                v.shuffle(rng);
+                q(
+                    format!("Mean (average): ({} + {} + {}) ÷ 3 = ?", v[0], v[1], v[2]),
+                    m.to_string(),
+                    Core,
+                )
+                .wrong(3 * m, Misconception::ForgotToShare)
+                .visual(bars(&v))
+            } else {
+                let v = distinct(rng, 1..=20, 3);
+                q(format!("Median of {} is ?", join(&v)), median(&v).to_string(), Core)
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let v = distinct(rng, 1..=6, 3);
+            let mut list = vec![v[0], v[0], v[0], v[1], v[1], v[2]];
+            list.shuffle(rng);
+            q(format!("Stretch: Mode of {} is ?", join(&list)), v[0].to_string(), Stretch).visual(dots(&list))
+        }
+        (Grade::G4, Core) => {
+            if rng.gen_bool(0.5) {
+                let m = 10 * rng.gen_range(2..=8);
+                let d = 10 * rng.gen_range(1..m / 10);
+                let mut v = [m - d, m, m + d];
+                v.shuffle(rng);
+                q(
+                    format!("Mean: ({} + {} + {}) ÷ 3 = ?", v[0], v[1], v[2]),
+                    m.to_string(),
+                    Core,
+                )
+                .wrong(3 * m, Misconception::ForgotToShare)
+                .visual(bars(&v))
+            } else {
+                let v = distinct(rng, 1..=30, 5);
+                q(format!("Median of {} is ?", join(&v)), median(&v).to_string(), Core)
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let v = distinct(rng, 1..=20, 4);
+            let range = v.iter().max().unwrap() - v.iter().min().unwrap();
+            q(
+                format!("Stretch: Range of {} is ? (max-min)", join(&v)),
+                range.to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let v: Vec<i32> = (0..4).map(|_| rng.gen_range(3..=12)).collect();
+                q(
+                    format!("Mean: ({} + {} + {} + {}) ÷ 4 = ?", v[0], v[1], v[2], v[3]),
+                    ratio_str(v.iter().sum(), 4, 0),
+                    Core,
+                )
+                .wrong(v.iter().sum::<i32>(), Misconception::ForgotToShare)
+                .visual(bars(&v))
+            } else {
+                let v = distinct(rng, 1..=30, 4);
+                let range = v.iter().max().unwrap() - v.iter().min().unwrap();
+                q(format!("Range of {} is ?", join(&v)), range.to_string(), Core)
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let v: Vec<i32> = (0..5).map(|_| rng.gen_range(1..=12)).collect();
+            q(
+                format!(
+                    "Stretch: Mean: ({} + {} + {} + {} + {}) ÷ 5 = ?",
+                    v[0], v[1], v[2], v[3], v[4]
+                ),
+                ratio_str(v.iter().sum(), 5, 0),
+                Stretch,
+            )
+            .wrong(v.iter().sum::<i32>(), Misconception::ForgotToShare)
+            .visual(bars(&v))
+        }
+    }
+}
+
+fn put_in_order(
+    rng: &mut dyn RngCore,
+    range: std::ops::RangeInclusive<i32>,
+    difficulty: Difficulty,
+) -> Question {
+    let v = distinct(rng, range, 3);
+    let mut sorted = v.clone();
+    sorted.sort();
+    let answer = sorted.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    q(
+        format!("Stretch: Put in order (small to big): {}. Use commas, like 1,2,3", join(&v)),
+        answer,
+        difficulty,
+    )
+    .kind(AnswerKind::OrderedList)
+}
+
+/// Dot plot of a list (one dot per number).
+fn dots(v: &[i32]) -> Visual {
+    Visual::DotPlot { values: v.to_vec() }
+}
+
+/// Bar chart of a list, in the order given (for sharing out a mean).
+fn bars(v: &[i32]) -> Visual {
+    Visual::BarChart {
+        bars: v
+            .iter()
+            .map(|n| Bar {
+                label: String::new(),
+                value: *n as f64,
+            })
+            .collect(),
+    }
+}
+
+fn median(v: &[i32]) -> i32 {
+    let mut sorted = v.to_vec();
+    sorted.sort();
+    sorted[sorted.len() / 2]
+}
+
 /* ---------------------------
    DAY 6 — Arrows & Grids (Movement)
 ---------------------------- */
@@ -216,27 +1052,160 @@ fn day6_arrows_and_grids(grade: Grade) -> Vec<Question> {
         ],
         Grade::G2 => vec![
             q("Start at 0. Move +5. Where are you?", "5", Difficulty::Core),
-            q("Start at 7. Move -3. Where are you?", "4", Difficulty::Core),
-            q("Stretch: Start at 2. Move +4, then -1. Where are you?", "5", Difficulty::Stretch),
+            q("Start at 7. Move -3. Where are you?", "4", Difficulty::Core)
+                .wrong("10", Misconception::MovedWrongWay),
+            q("Stretch: Start at 2. Move +4, then -1. Where are you?", "5", Difficulty::Stretch)
+                .wrong("7", Misconception::MovedWrongWay),
         ],
         Grade::G3 => vec![
             q("Coordinates: Which comes first (x,y): left/right or up/down? (x/y)", "x", Difficulty::Core),
-            q("Start at 10. Move -6. Where are you?", "4", Difficulty::Core),
-            q("Stretch: Start at 3. Move +7, then -4. Where are you?", "6", Difficulty::Stretch),
+            q("Start at 10. Move -6. Where are you?", "4", Difficulty::Core)
+                .wrong("16", Misconception::MovedWrongWay),
+            q("Stretch: Start at 3. Move +7, then -4. Where are you?", "6", Difficulty::Stretch)
+                .wrong("14", Misconception::MovedWrongWay),
         ],
         Grade::G4 => vec![
-            q("A point (2,5) has x = ? ", "2", Difficulty::Core),
-            q("A point (2,5) has y = ? ", "5", Difficulty::Core),
-            q("Stretch: Start at 0. Move +12, then -5, then +3. Where are you?", "10", Difficulty::Stretch),
+            q("A point (2,5) has x = ? ", "2", Difficulty::Core).visual(grid_picture((0, 10), (0, 10), &[(2, 5, "")])),
+            q("A point (2,5) has y = ? ", "5", Difficulty::Core).visual(grid_picture((0, 10), (0, 10), &[(2, 5, "")])),
+            q("Stretch: Start at 0. Move +12, then -5, then +3. Where are you?", "10", Difficulty::Stretch)
+                .wrong("20", Misconception::MovedWrongWay),
         ],
         Grade::G5 => vec![
             q("Start at -2. Move +7. Where are you?", "5", Difficulty::Core),
-            q("Start at 6. Move -9. Where are you?", "-3", Difficulty::Core),
-            q("Stretch: A point (x,y) = (4, -1). y = ?", "-1", Difficulty::Stretch),
+            q("Start at 6. Move -9. Where are you?", "-3", Difficulty::Core)
+                .wrong("15", Misconception::MovedWrongWay),
+            q("Stretch: A point (x,y) = (4, -1). y = ?", "-1", Difficulty::Stretch)
+                .visual(grid_picture((-5, 5), (-5, 5), &[(4, -1, "")])),
         ],
     }
 }
 
+fn day6_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (dir, other) = *[("left", "right"), ("right", "left"), ("up", "down"), ("down", "up")]
+                .choose(rng)
+                .unwrap();
+            let n = rng.gen_range(1..=4);
+            let (a, b) = if rng.gen_bool(0.5) { (dir, other) } else { (other, dir) };
+            q(
+                format!("If you move {} {dir}, did you go {a} or {b}?", plural(n, "step", "steps")),
+                dir,
+                Core,
+            )
+        }
+        (Grade::G1, Stretch) => walk(rng, grade, 0, &[1..=5, 1..=5], Stretch),
+        (Grade::G2, Core) => {
+            let start = rng.gen_range(0..=10);
+            walk(rng, grade, start, &[-5..=5], Core)
+        }
+        (Grade::G2, Stretch) => {
+            let start = rng.gen_range(0..=5);
+            walk(rng, grade, start, &[1..=5, -3..=3], Stretch)
+        }
+        (Grade::G3, Core) => {
+            let start = rng.gen_range(6..=20);
+            walk(rng, grade, start, &[-9..=9], Core)
+        }
+        (Grade::G3, Stretch) => {
+            let start = rng.gen_range(0..=5);
+            walk(rng, grade, start, &[1..=9, -6..=6], Stretch)
+        }
+        (Grade::G4, Core) => {
+            let (x, y) = (rng.gen_range(0..=10), rng.gen_range(0..=10));
+            let picture = grid_picture((0, 10), (0, 10), &[(x, y, "")]);
+            if rng.gen_bool(0.5) {
+                q(format!("A point ({x},{y}) has x = ?"), x.to_string(), Core).visual(picture)
+            } else {
+                q(format!("A point ({x},{y}) has y = ?"), y.to_string(), Core).visual(picture)
+            }
+        }
+        (Grade::G4, Stretch) => walk(rng, grade, 0, &[5..=15, -5..=5, -5..=5], Stretch),
+        (Grade::G5, Core) => {
+            let start = rng.gen_range(-5..=5);
+            walk(rng, grade, start, &[-9..=9], Core)
+        }
+        (Grade::G5, Stretch) => {
+            let (x, y) = (rng.gen_range(-5..=5), rng.gen_range(-5..=5));
+            let (right, down) = (rng.gen_range(1..=6), rng.gen_range(1..=6));
+            // Room to count the move on the grid; only the start is marked.
+            let picture = grid_picture(
+                (x.min(0) - 1, (x + right).max(0) + 1),
+                ((y - down).min(0) - 1, y.max(0) + 1),
+                &[(x, y, "Start")],
+            );
+            if rng.gen_bool(0.5) {
+                q(
+                    format!("Stretch: Start at ({x},{y}). Move {right} right and {down} down. What is x now?"),
+                    (x + right).to_string(),
+                    Stretch,
+                )
+                .visual(picture)
+            } else {
+                q(
+                    format!("Stretch: Start at ({x},{y}). Move {right} right and {down} down. What is y now?"),
+                    (y - down).to_string(),
+                    Stretch,
+                )
+                .visual(picture)
+            }
+        }
+    }
+}
+
+/// A coordinate grid with labeled points.
+fn grid_picture(x: (i32, i32), y: (i32, i32), points: &[(i32, i32, &str)]) -> Visual {
+    Visual::Grid {
+        x_min: x.0,
+        x_max: x.1,
+        y_min: y.0,
+        y_max: y.1,
+        points: points
+            .iter()
+            .map(|(x, y, label)| GridPoint {
+                x: *x,
+                y: *y,
+                label: label.to_string(),
+            })
+            .collect(),
+    }
+}
+
+/// "Start at s. Move +a, then -b. Where are you?" One move per range.
+/// Below Grade 5 the walk is re-rolled so it never drops below 0.
+fn walk(
+    rng: &mut dyn RngCore,
+    grade: Grade,
+    start: i32,
+    moves: &[std::ops::RangeInclusive<i32>],
+    difficulty: Difficulty,
+) -> Question {
+    let allow_negative = grade == Grade::G5;
+    let mut at = start;
+    // Where you'd end up reading every − as + (only a misconception if there was a −).
+    let mut all_up = start;
+    let mut steps = vec![];
+    for range in moves {
+        let mut m = 0;
+        while m == 0 || (!allow_negative && at + m < 0) {
+            m = rng.gen_range(range.clone());
+        }
+        at += m;
+        all_up += m.abs();
+        steps.push(if m > 0 { format!("+{m}") } else { m.to_string() });
+    }
+
+    let prefix = if difficulty == Difficulty::Stretch { "Stretch: " } else { "" };
+    q(
+        format!("{prefix}Start at {start}. Move {}. Where are you?", steps.join(", then ")),
+        at.to_string(),
+        difficulty,
+    )
+    .wrong(all_up, Misconception::MovedWrongWay)
+}
+
 /* ---------------------------
    DAY 7 — What Might Happen? (Chance)
 ---------------------------- */
@@ -245,12 +1214,14 @@ fn day7_what_might_happen(grade: Grade) -> Vec<Question> {
         Grade::G1 => vec![
             q("Which is more likely: the sun rises tomorrow OR it snows candy? (sun/candy)", "sun", Difficulty::Core),
             q("If a bag has 10 red and 1 blue, which color is easier to grab? (red/blue)", "red", Difficulty::Core),
-            q("Stretch: Is it possible to roll a 7 on one standard die? (yes/no)", "no", Difficulty::Stretch),
+            q("Stretch: Is it possible to roll a 7 on one standard die? (yes/no)", "no", Difficulty::Stretch)
+                .visual(die_faces()),
         ],
         Grade::G2 => vec![
             q("A coin has heads and tails. How many sides? (2)", "2", Difficulty::Core),
             q("More likely on a die: roll a 1 OR roll a 7? (1/7)", "1", Difficulty::Core),
-            q("Stretch: Is rolling an even number possible on a die? (yes/no)", "yes", Difficulty::Stretch),
+            q("Stretch: Is rolling an even number possible on a die? (yes/no)", "yes", Difficulty::Stretch)
+                .visual(die_faces()),
         ],
         Grade::G3 => vec![
             q("A fair coin: chance of heads is 1 out of __.", "2", Difficulty::Core),
@@ -258,18 +1229,125 @@ fn day7_what_might_happen(grade: Grade) -> Vec<Question> {
             q("Stretch: On a die, probability of rolling a 6 as a fraction is 1/__", "6", Difficulty::Stretch),
         ],
         Grade::G4 => vec![
-            q("On a die, probability of rolling an even number is __/6 (write fraction)", "3/6", Difficulty::Core),
-            q("On a die, probability of rolling >4 is __/6", "2/6", Difficulty::Core),
-            q("Stretch: Simplify 3/6 to __ (1/2)", "1/2", Difficulty::Stretch),
+            q("On a die, probability of rolling an even number is __/6 (write fraction)", "3/6", Difficulty::Core)
+                .visual(die_faces()),
+            q("On a die, probability of rolling >4 is __/6", "2/6", Difficulty::Core).visual(die_faces()),
+            q("Stretch: Simplify 3/6 to __ (1/2)", "1/2", Difficulty::Stretch).kind(AnswerKind::LowestTerms),
         ],
         Grade::G5 => vec![
             q("A bag has 3 red and 2 blue. Probability of red is __/5", "3/5", Difficulty::Core),
             q("A die: probability of rolling 1 or 2 is __/6", "2/6", Difficulty::Core),
-            q("Stretch: Simplify 2/6 to __ (1/3)", "1/3", Difficulty::Stretch),
+            q("Stretch: Simplify 2/6 to __ (1/3)", "1/3", Difficulty::Stretch).kind(AnswerKind::LowestTerms),
         ],
     }
 }
 
+fn day7_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (many, few) = (rng.gen_range(6..=12), rng.gen_range(1..=3));
+            let (red, blue) = if rng.gen_bool(0.5) { (many, few) } else { (few, many) };
+            q(
+                format!("If a bag has {red} red and {blue} blue, which color is easier to grab? (red/blue)"),
+                if red > blue { "red" } else { "blue" },
+                Core,
+            )
+        }
+        (Grade::G1, Stretch) => {
+            let n = rng.gen_range(1..=9);
+            q(
+                format!("Stretch: Is it possible to roll a {n} on one standard die? (yes/no)"),
+                yes_no(n <= 6),
+                Stretch,
+            )
+            .visual(die_faces())
+        }
+        (Grade::G2, Core) => {
+            let (can, cannot) = (rng.gen_range(1..=6), rng.gen_range(7..=12));
+            let (a, b) = if rng.gen_bool(0.5) { (can, cannot) } else { (cannot, can) };
+            q(
+                format!("More likely on a die: roll a {a} OR roll a {b}? ({a}/{b})"),
+                can.to_string(),
+                Core,
+            )
+        }
+        (Grade::G2, Stretch) => {
+            let k = rng.gen_range(1..=7);
+            q(
+                format!("Stretch: Is rolling a number bigger than {k} possible on a die? (yes/no)"),
+                yes_no(k < 6),
+                Stretch,
+            )
+            .visual(die_faces())
+        }
+        (Grade::G3, Core) => {
+            let n = rng.gen_range(2..=8);
+            q(
+                format!("A spinner has {n} equal parts. Chance of landing on one part is 1 out of __."),
+                n.to_string(),
+                Core,
+            )
+            .visual(spinner(n))
+        }
+        (Grade::G3, Stretch) => {
+            let n = rng.gen_range(3..=10);
+            q(
+                format!("Stretch: A spinner has {n} equal parts. The probability of landing on one part as a fraction is 1/__"),
+                n.to_string(),
+                Stretch,
+            )
+            .visual(spinner(n))
+        }
+        (Grade::G4, Core) => {
+            let k = rng.gen_range(1..=5);
+            q(
+                format!("On a die, probability of rolling more than {k} is __/6"),
+                format!("{}/6", 6 - k),
+                Core,
+            )
+            .visual(die_faces())
+        }
+        (Grade::G4, Stretch) | (Grade::G5, Stretch) => {
+            let den = rng.gen_range(2..=6);
+            let mut num = rng.gen_range(1..den);
+            while gcd(num, den) != 1 {
+                num = rng.gen_range(1..den);
+            }
+            let m = rng.gen_range(2..=4);
+            q(
+                format!("Stretch: Simplify {}/{} to lowest terms", num * m, den * m),
+                format!("{num}/{den}"),
+                Stretch,
+            )
+            .kind(AnswerKind::LowestTerms)
+        }
+        (Grade::G5, Core) => {
+            let (red, blue) = (rng.gen_range(1..=6), rng.gen_range(1..=6));
+            q(
+                format!("A bag has {red} red and {blue} blue. Probability of red is __/{}", red + blue),
+                format!("{red}/{}", red + blue),
+                Core,
+            )
+        }
+    }
+}
+
+/// All six faces of a die.
+fn die_faces() -> Visual {
+    Visual::Dice {
+        faces: (1..=6).collect(),
+    }
+}
+
+fn spinner(parts: i32) -> Visual {
+    Visual::Spinner {
+        parts: parts as u32,
+        labels: Vec::new(),
+    }
+}
+
 /* ---------------------------
    DAY 8 — Number Secrets
 ---------------------------- */
@@ -303,6 +1381,82 @@ fn day8_number_secrets(grade: Grade) -> Vec<Question> {
     }
 }
 
+fn day8_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => even_or_odd(rng.gen_range(1..=20)),
+        (Grade::G1, Stretch) => skip_count(2, rng.gen_range(1..=5)),
+        (Grade::G2, Core) => even_or_odd(rng.gen_range(10..=99)),
+        (Grade::G2, Stretch) => {
+            let by = *[5, 10].choose(rng).unwrap();
+            skip_count(by, rng.gen_range(1..=4))
+        }
+        (Grade::G3, Core) => divisible_by(rng.gen_range(10..=50), rng.gen_range(2..=5)),
+        (Grade::G3, Stretch) => prime_check(2 * rng.gen_range(5..=24) + 1),
+        (Grade::G4, Core) => divisible_by(rng.gen_range(20..=100), rng.gen_range(3..=9)),
+        (Grade::G4, Stretch) => prime_check(2 * rng.gen_range(10..=49) + 1),
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let n = 2 * rng.gen_range(6..=30);
+                let factors: Vec<i32> = (2..=9).filter(|f| n % f == 0).collect();
+                let others: Vec<i32> = (2..=9).filter(|f| n % f != 0).collect();
+                match (factors.choose(rng), others.choose(rng)) {
+                    (Some(&yes), Some(&no)) => {
+                        let (a, b) = if rng.gen_bool(0.5) { (yes, no) } else { (no, yes) };
+                        q(
+                            format!("What is a factor of {n}? (pick: {a}/{b}) Answer with {a} or {b}."),
+                            yes.to_string(),
+                            Core,
+                        )
+                    }
+                    _ => divisible_by(n, 5),
+                }
+            } else {
+                divisible_by(rng.gen_range(20..=120), rng.gen_range(3..=12))
+            }
+        }
+        (Grade::G5, Stretch) => prime_check(2 * rng.gen_range(15..=75) + 1),
+    }
+}
+
+fn even_or_odd(n: i32) -> Question {
+    q(
+        format!("Is {n} even or odd? (even/odd)"),
+        if n % 2 == 0 { "even" } else { "odd" },
+        Difficulty::Core,
+    )
+}
+
+fn skip_count(by: i32, from: i32) -> Question {
+    let a = by * from;
+    q(
+        format!("Stretch: Skip count by {by}s: {}, {}, {}, __", a, a + by, a + 2 * by),
+        (a + 3 * by).to_string(),
+        Difficulty::Stretch,
+    )
+}
+
+fn divisible_by(n: i32, k: i32) -> Question {
+    q(
+        format!("Is {n} divisible by {k}? (yes/no)"),
+        yes_no(n % k == 0),
+        Difficulty::Core,
+    )
+}
+
+fn prime_check(n: i32) -> Question {
+    q(
+        format!("Stretch: Is {n} a prime number? (yes/no)"),
+        yes_no(is_prime(n)),
+        Difficulty::Stretch,
+    )
+}
+
+fn is_prime(n: i32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
 /* ---------------------------
    DAY 9 — Counting Smart
 ---------------------------- */
@@ -316,26 +1470,155 @@ fn day9_counting_smart(grade: Grade) -> Vec<Question> {
         Grade::G2 => vec![
             q("2 shirts and 2 pants. How many outfits?", "4", Difficulty::Core),
             q("3 snacks to choose from. How many choices?", "3", Difficulty::Core),
-            q("Stretch: 3 shirts and 2 hats. How many outfits?", "6", Difficulty::Stretch),
+            q("Stretch: 3 shirts and 2 hats. How many outfits?", "6", Difficulty::Stretch)
+                .wrong("5", Misconception::AddedChoices),
         ],
         Grade::G3 => vec![
-            q("If you have 3 shirts and 2 pants, outfits = ?", "6", Difficulty::Core),
+            q("If you have 3 shirts and 2 pants, outfits = ?", "6", Difficulty::Core)
+                .wrong("5", Misconception::AddedChoices),
             q("How many ways to pick 1 of 4 prizes?", "4", Difficulty::Core),
-            q("Stretch: A set has 2 items. How many subsets? (include empty set)", "4", Difficulty::Stretch),
+            q("Stretch: A set has 2 items. How many subsets? (include empty set)", "4", Difficulty::Stretch)
+                .wrong("3", Misconception::ForgotEmptySet),
         ],
         Grade::G4 => vec![
-            q("A set has 3 items. How many subsets? (include empty set)", "8", Difficulty::Core),
+            q("A set has 3 items. How many subsets? (include empty set)", "8", Difficulty::Core)
+                .wrong("6", Misconception::SubsetsTimesTwo)
+                .wrong("7", Misconception::ForgotEmptySet),
             q("You can go Left or Right at 2 turns. How many paths? (2×2)", "4", Difficulty::Core),
-            q("Stretch: A set has 4 items. How many subsets?", "16", Difficulty::Stretch),
+            q("Stretch: A set has 4 items. How many subsets?", "16", Difficulty::Stretch)
+                .wrong("8", Misconception::SubsetsTimesTwo)
+                .wrong("15", Misconception::ForgotEmptySet),
         ],
         Grade::G5 => vec![
-            q("A set has 4 items. How many subsets?", "16", Difficulty::Core),
+            q("A set has 4 items. How many subsets?", "16", Difficulty::Core)
+                .wrong("8", Misconception::SubsetsTimesTwo)
+                .wrong("15", Misconception::ForgotEmptySet),
             q("If you have 5 choices for topping, how many ways to pick 1 topping?", "5", Difficulty::Core),
-            q("Stretch: A set has 5 items. How many subsets?", "32", Difficulty::Stretch),
+            q("Stretch: A set has 5 items. How many subsets?", "32", Difficulty::Stretch)
+                .wrong("10", Misconception::SubsetsTimesTwo)
+                .wrong("31", Misconception::ForgotEmptySet),
         ],
     }
 }
 
+fn day9_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            if rng.gen_bool(0.5) {
+                let a = rng.gen_range(2..=4);
+                q(format!("You have {a} shirts and 1 hat. How many outfits?"), a.to_string(), Core)
+            } else {
+                let n = rng.gen_range(2..=5);
+                q(
+                    format!("You can choose 1 snack from {n} snacks. How many choices?"),
+                    n.to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G1, Stretch) => outfits(rng, "shirts", "hats", 2..=3, Stretch),
+        (Grade::G2, Core) => outfits(rng, "shirts", "pants", 2..=3, Core),
+        (Grade::G2, Stretch) => outfits(rng, "shirts", "hats", 2..=4, Stretch),
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                outfits(rng, "shirts", "pants", 2..=5, Core)
+            } else {
+                let n = rng.gen_range(3..=9);
+                q(format!("How many ways to pick 1 of {n} prizes?"), n.to_string(), Core)
+            }
+        }
+        (Grade::G3, Stretch) => subsets(rng.gen_range(1..=3), Stretch),
+        (Grade::G4, Core) => {
+            if rng.gen_bool(0.5) {
+                subsets(rng.gen_range(2..=4), Core)
+            } else {
+                let n = rng.gen_range(2..=4);
+                q(
+                    format!("You can go Left or Right at {n} turns. How many paths?"),
+                    (1 << n).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let (a, b, c) = (rng.gen_range(2..=4), rng.gen_range(2..=3), rng.gen_range(2..=3));
+            q(
+                format!("Stretch: {a} shirts, {b} pants and {c} hats. How many outfits?"),
+                (a * b * c).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G5, Core) => subsets(rng.gen_range(3..=5), Core),
+        (Grade::G5, Stretch) => {
+            if rng.gen_bool(0.5) {
+                subsets(rng.gen_range(5..=7), Stretch)
+            } else {
+                let n = rng.gen_range(3..=5);
+                let ways = (1..=n).rev().map(|k| k.to_string()).collect::<Vec<_>>().join(" × ");
+                q(
+                    format!("Stretch: How many ways can {n} friends line up in a row?"),
+                    (1..=n).product::<i32>().to_string(),
+                    Stretch,
+                )
+                .hints(
+                    [
+                        "How many friends could stand first? Then how many are left for second?".to_string(),
+                        format!("The choices go {n}, then {}, then {}…", n - 1, n - 2),
+                    ],
+                    format!("{ways} = {}", (1..=n).product::<i32>()),
+                )
+            }
+        }
+    }
+}
+
+fn outfits(
+    rng: &mut dyn RngCore,
+    tops: &str,
+    bottoms: &str,
+    range: std::ops::RangeInclusive<i32>,
+    difficulty: Difficulty,
+) -> Question {
+    let (a, b) = (rng.gen_range(range.clone()), rng.gen_range(range));
+    let prefix = if difficulty == Difficulty::Stretch { "Stretch: " } else { "" };
+    q(
+        format!("{prefix}{a} {tops} and {b} {bottoms}. How many outfits?"),
+        (a * b).to_string(),
+        difficulty,
+    )
+    .wrong(a + b, Misconception::AddedChoices)
+    .hints(
+        [
+            format!("Pick one of the {tops}. How many {bottoms} can go with it?"),
+            format!("Every one of the {a} {tops} goes with {b} {bottoms}."),
+        ],
+        format!("{a} × {b} = {} outfits", a * b),
+    )
+}
+
+fn subsets(n: u32, difficulty: Difficulty) -> Question {
+    let prefix = if difficulty == Difficulty::Stretch { "Stretch: " } else { "" };
+    q(
+        format!(
+            "{prefix}A set has {}. How many subsets? (include empty set)",
+            plural(n as i32, "item", "items")
+        ),
+        (1u32 << n).to_string(),
+        difficulty,
+    )
+    .wrong(2 * n, Misconception::SubsetsTimesTwo)
+    .wrong((1u32 << n) - 1, Misconception::ForgotEmptySet)
+    .hints(
+        [
+            "Each item is either in the subset or not: 2 choices.".to_string(),
+            format!("Multiply 2 by itself once for each of the {n} items."),
+        ],
+        format!("{} = {}", vec!["2"; n as usize].join(" × "), 1u32 << n),
+    )
+}
+
 /* ---------------------------
    DAY 10 — How Things Change (Rates)
 ---------------------------- */
@@ -369,6 +1652,143 @@ fn day10_how_things_change(grade: Grade) -> Vec<Question> {
     }
 }
 
+fn day10_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let n = rng.gen_range(2..=5);
+            if rng.gen_bool(0.5) {
+                q(
+                    format!("You get 1 sticker each day. After {n} days, stickers = ?"),
+                    n.to_string(),
+                    Core,
+                )
+            } else {
+                let s = rng.gen_range(n + 1..=10);
+                q(
+                    format!("You lose 1 point each turn from {s}. After {n} turns, points = ?"),
+                    (s - n).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G1, Stretch) => {
+            let (d, s, n) = (2, rng.gen_range(0..=3), rng.gen_range(2..=4));
+            q(
+                format!("Stretch: You gain {d} points each turn from {s}. After {n} turns, points = ?"),
+                (s + d * n).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G2, Core) => {
+            if rng.gen_bool(0.5) {
+                let (r, n) = (rng.gen_range(2..=5), rng.gen_range(2..=5));
+                q(
+                    format!("You save ${r} each week. After {n} weeks, money = ?"),
+                    (r * n).to_string(),
+                    Core,
+                )
+                .kind(AnswerKind::Money)
+            } else {
+                let (s, h) = (rng.gen_range(5..=10), rng.gen_range(2..=4));
+                q(
+                    format!("A candle burns down 1 inch per hour from {s} inches. After {h} hours, inches = ?"),
+                    (s - h).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G2, Stretch) => {
+            let (r, n) = (rng.gen_range(2..=4), rng.gen_range(3..=6));
+            q(
+                format!("Stretch: You grow {r} cm per year (pretend). In {n} years, cm = ?"),
+                (r * n).to_string(),
+                Stretch,
+            )
+            .kind(unit("cm"))
+        }
+        (Grade::G3, Core) => {
+            let (r, n) = (rng.gen_range(2..=6), rng.gen_range(3..=8));
+            if rng.gen_bool(0.5) {
+                q(
+                    format!("A game adds {r} points each round. After {n} rounds, points added = ?"),
+                    (r * n).to_string(),
+                    Core,
+                )
+            } else {
+                q(
+                    format!("A robot moves {r} steps each turn. After {n} turns, steps = ?"),
+                    (r * n).to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let (s, d, n) = (rng.gen_range(8..=20), rng.gen_range(2..=4), rng.gen_range(3..=6));
+            q(
+                format!("Stretch: Start at {s} and subtract {d} each time. After {n} times, number = ?"),
+                (s - d * n).to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G4, Core) => {
+            let (r, h) = (rng.gen_range(3..=12), rng.gen_range(2..=6));
+            if rng.gen_bool(0.5) {
+                q(
+                    format!("A bike goes {r} miles per hour. In {h} hours, miles = ?"),
+                    (r * h).to_string(),
+                    Core,
+                )
+            } else {
+                q(
+                    format!("A tank fills {r} liters per minute. In {h} minutes, liters = ?"),
+                    (r * h).to_string(),
+                    Core,
+                )
+                .kind(unit("liters"))
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let (r, h) = (rng.gen_range(5..=20), rng.gen_range(2..=5));
+            q(
+                format!("Stretch: If you travel {} miles in {h} hours, miles per hour = ?", r * h),
+                r.to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G5, Core) => {
+            if rng.gen_bool(0.5) {
+                let (r, h) = (5 * rng.gen_range(4..=14), rng.gen_range(2..=5));
+                q(
+                    format!("A car goes {} miles in {h} hours. Miles per hour = ?", r * h),
+                    r.to_string(),
+                    Core,
+                )
+            } else {
+                let (r, d) = (rng.gen_range(3..=15), rng.gen_range(2..=7));
+                q(
+                    format!("You read {} pages in {d} days. Pages per day = ?", r * d),
+                    r.to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G5, Stretch) => {
+            let (halves, n) = (2 * rng.gen_range(1..=4) + 1, rng.gen_range(2..=6));
+            q(
+                format!(
+                    "Stretch: If a plant grows {} cm per week, in {n} weeks it grows __ cm.",
+                    ratio_str(halves, 2, 1)
+                ),
+                ratio_str(halves * n, 2, 0),
+                Stretch,
+            )
+            .kind(unit("cm"))
+        }
+    }
+}
+
 /* ---------------------------
    DAY 11 — How We Know (Truth & Reason)
 ---------------------------- */
@@ -402,6 +1822,123 @@ fn day11_how_we_know(grade: Grade) -> Vec<Question> {
     }
 }
 
+fn day11_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            let (a, b) = (rng.gen_range(1..=5), rng.gen_range(1..=5));
+            if rng.gen_bool(0.5) {
+                let shown = a + b + *[-1, 0, 0, 1].choose(rng).unwrap();
+                q(
+                    format!("True or false: {a} + {b} = {shown} (true/false)"),
+                    true_false(shown == a + b),
+                    Core,
+                )
+            } else {
+                let b = if a == b { b + 1 } else { b };
+                q(
+                    format!("True or false: {a} is smaller than {b} (true/false)"),
+                    true_false(a < b),
+                    Core,
+                )
+            }
+        }
+        (Grade::G1, Stretch) => {
+            let a = rng.gen_range(3..=6);
+            let eat = rng.gen_range(1..a);
+            let shown = (a - eat + *[-1, 0, 0, 1].choose(rng).unwrap()).max(0);
+            q(
+                format!("Stretch: If you have {a} cookies and eat {eat}, do you have {shown} left? (yes/no)"),
+                yes_no(shown == a - eat),
+                Stretch,
+            )
+        }
+        (Grade::G2, Core) => {
+            let a = rng.gen_range(6..=15);
+            let b = rng.gen_range(1..a);
+            let wobble = *[-2, 0, 0, 2].choose(rng).unwrap();
+            if rng.gen_bool(0.5) {
+                let shown = a - b + wobble;
+                q(
+                    format!("True or false: {a} - {b} = {shown} (true/false)"),
+                    true_false(wobble == 0),
+                    Core,
+                )
+            } else {
+                let shown = a + b + wobble;
+                q(
+                    format!("True or false: {a} + {b} = {shown} (true/false)"),
+                    true_false(wobble == 0),
+                    Core,
+                )
+            }
+        }
+        (Grade::G2, Stretch) => {
+            let (name, n) = *SHAPE_SIDES.choose(rng).unwrap();
+            let asked = if rng.gen_bool(0.5) { n } else { n + 1 };
+            q(
+                format!(
+                    "Stretch: If every {name} has {n} sides, does {} {name} have {asked} sides? (yes/no)",
+                    a_or_an(name)
+                ),
+                yes_no(asked == n),
+                Stretch,
+            )
+        }
+        (Grade::G3, Core) => {
+            let (n, k) = (rng.gen_range(10..=40), rng.gen_range(2..=5));
+            q(
+                format!("True or false: {n} is divisible by {k} (true/false)"),
+                true_false(n % k == 0),
+                Core,
+            )
+        }
+        (Grade::G3, Stretch) => {
+            let n = 10 * rng.gen_range(1..=9) + rng.gen_range(0..=9);
+            q(
+                format!("Stretch: {n} ends in {}. Is it divisible by 5? (yes/no)", n % 10),
+                yes_no(n % 5 == 0),
+                Stretch,
+            )
+        }
+        (Grade::G4, Core) | (Grade::G5, Core) => {
+            let (a, b) = (rng.gen_range(3..=12), rng.gen_range(3..=9));
+            if rng.gen_bool(0.5) {
+                let wobble = *[-a, 0, 0, b].choose(rng).unwrap();
+                q(
+                    format!("True or false: {a} × {b} = {} (true/false)", a * b + wobble),
+                    true_false(wobble == 0),
+                    Core,
+                )
+            } else {
+                let n = rng.gen_range(12..=100);
+                q(
+                    format!("True or false: {n} is divisible by {b} (true/false)"),
+                    true_false(n % b == 0),
+                    Core,
+                )
+            }
+        }
+        (Grade::G4, Stretch) => {
+            let (a, b) = (rng.gen_range(3..=15), rng.gen_range(3..=9));
+            q(
+                format!("Stretch: Is {a} × {b} an even number? (yes/no)"),
+                yes_no((a * b) % 2 == 0),
+                Stretch,
+            )
+        }
+        (Grade::G5, Stretch) => {
+            let (x, k, b) = (rng.gen_range(2..=9), rng.gen_range(2..=5), rng.gen_range(1..=10));
+            q(
+                format!("Stretch: If x = {x}, then {k}x + {b} = ? (write number)"),
+                (k * x + b).to_string(),
+                Stretch,
+            )
+        }
+    }
+}
+
 /* ---------------------------
    DAY 12 — Choosing Wisely (Strategy)
 ---------------------------- */
@@ -435,7 +1972,282 @@ fn day12_choosing_wisely(grade: Grade) -> Vec<Question> {
     }
 }
 
+fn day12_fresh(grade: Grade, difficulty: Difficulty, rng: &mut dyn RngCore) -> Question {
+    use Difficulty::{Core, Stretch};
+
+    match (grade, difficulty) {
+        (Grade::G1, Core) => {
+            if rng.gen_bool(0.5) {
+                let v = distinct(rng, 1..=9, 2);
+                q(
+                    format!(
+                        "If you want more candy, choose the bigger number: {} or {} (answer {}/{})",
+                        v[0], v[1], v[0], v[1]
+                    ),
+                    v[0].max(v[1]).to_string(),
+                    Core,
+                )
+            } else {
+                let s = rng.gen_range(3..=8);
+                reach_target(rng, s, 10, Core)
+            }
+        }
+        (Grade::G1, Stretch) => {
+            let s = rng.gen_range(4..=8);
+            reach_target(rng, s, 10, Stretch)
+        }
+        (Grade::G2, Core) => {
+            if rng.gen_bool(0.5) {
+                let t = rng.gen_range(10..=15);
+                let s = t - rng.gen_range(2..=5);
+                reach_target(rng, s, t, Core)
+            } else {
+                let (big, small) = (*[5, 10].choose(rng).unwrap(), rng.gen_range(1..=3));
+                let (a, b) = if rng.gen_bool(0.5) { (big, small) } else { (small, big) };
+                q(
+                    format!("Which is better for reaching 20 faster: add {a} or add {b}? ({a}/{b})"),
+                    big.to_string(),
+                    Core,
+                )
+            }
+        }
+        (Grade::G2, Stretch) => {
+            let t = rng.gen_range(12..=20);
+            let s = t - rng.gen_range(3..=6);
+            reach_target(rng, s, t, Stretch)
+        }
+        (Grade::G3, Core) => {
+            if rng.gen_bool(0.5) {
+                let v = distinct(rng, 1..=20, 2);
+                let (a, b) = (v[0] * 5, v[1] * 5);
+                q(
+                    format!("If you can pick 1 prize: {a} points or {b} points ({a}/{b})"),
+                    a.max(b).to_string(),
+                    Core,
+                )
+            } else {
+                let t = 5 * rng.gen_range(4..=8);
+                let s = t - rng.gen_range(3..=9);
+                reach_target(rng, s, t, Core)
+            }
+        }
+        (Grade::G3, Stretch) => {
+            let n = rng.gen_range(10..=40);
+            let want_even = rng.gen_bool(0.5);
+            let answer = if (n % 2 == 0) == want_even { n } else { n + 1 };
+            q(
+                format!(
+                    "Stretch: If you want an {} number, choose {n} or {} ({n}/{})",
+                    if want_even { "even" } else { "odd" },
+                    n + 1,
+                    n + 1
+                ),
+                answer.to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G4, Core) => {
+            let k = *[3, 5].choose(rng).unwrap();
+            let hit = k * rng.gen_range(3..=12);
+            let miss = hit + *[-1, 1].choose(rng).unwrap();
+            let (a, b) = if rng.gen_bool(0.5) { (hit, miss) } else { (miss, hit) };
+            let ask = if k == 5 {
+                "To get a multiple of 5"
+            } else {
+                "To get a number divisible by 3"
+            };
+            q(format!("{ask}, choose {a} or {b} ({a}/{b})"), hit.to_string(), Core)
+        }
+        (Grade::G4, Stretch) => {
+            let limit = 10 * rng.gen_range(3..=9);
+            let (under, over) = (limit - rng.gen_range(1..=3), limit + rng.gen_range(1..=3));
+            let (a, b) = if rng.gen_bool(0.5) { (under, over) } else { (over, under) };
+            q(
+                format!("Stretch: Best choice to keep it under {limit}: {a} or {b} ({a}/{b})"),
+                under.to_string(),
+                Stretch,
+            )
+        }
+        (Grade::G5, Core) => {
+            // 0.6 vs 0.56 style: the longer decimal is the smaller one.
+            let t = rng.gen_range(2..=9);
+            let short = format!("0.{t}");
+            let long = format!("0.{}{}", t - 1, rng.gen_range(1..=9));
+            let (a, b) = if rng.gen_bool(0.5) { (&short, &long) } else { (&long, &short) };
+            q(
+                format!("Best choice: Which is larger? {a} or {b} ({a}/{b})"),
+                short.clone(),
+                Core,
+            )
+        }
+        (Grade::G5, Stretch) => {
+            let pick = |rng: &mut dyn RngCore| {
+                let den = rng.gen_range(2..=8);
+                (rng.gen_range(1..den), den)
+            };
+            let (mut a, mut b) = (pick(rng), pick(rng));
+            while a.0 * b.1 == b.0 * a.1 {
+                b = pick(rng);
+            }
+            if rng.gen_bool(0.5) {
+                std::mem::swap(&mut a, &mut b);
+            }
+            let larger = if a.0 * b.1 > b.0 * a.1 { a } else { b };
+            q(
+                format!(
+                    "Stretch: Which is larger? {}/{} or {}/{} ({}/{} or {}/{})",
+                    a.0, a.1, b.0, b.1, a.0, a.1, b.0, b.1
+                ),
+                format!("{}/{}", larger.0, larger.1),
+                Stretch,
+            )
+        }
+    }
+}
+
+/// "To make 10 from 7, add 3 or add 4?" — one option lands exactly.
+fn reach_target(rng: &mut dyn RngCore, from: i32, target: i32, difficulty: Difficulty) -> Question {
+    let right = target - from;
+    let wrong = right + *[-1, 1, 2].choose(rng).unwrap();
+    let wrong = if wrong <= 0 { right + 1 } else { wrong };
+    let (a, b) = if rng.gen_bool(0.5) { (right, wrong) } else { (wrong, right) };
+    let prefix = if difficulty == Difficulty::Stretch { "Stretch: " } else { "" };
+    q(
+        format!("{prefix}To make {target} from {from}, add {a} or add {b}? ({a}/{b})"),
+        right.to_string(),
+        difficulty,
+    )
+}
+
 // Small helper to keep question construction tidy.
-fn q(prompt: &'static str, answer: &'static str, difficulty: Difficulty) -> Question {
-    Question { prompt, answer, difficulty }
-}
\ No newline at end of file
+fn q(prompt: impl Into<String>, answer: impl Into<String>, difficulty: Difficulty) -> Question {
+    Question {
+        prompt: prompt.into(),
+        answer: answer.into(),
+        difficulty,
+        answer_kind: None,
+        skill: Skill::Unknown,
+        hints: Vec::new(),
+        solution: None,
+        known_wrong: Vec::new(),
+        format: None,
+        visual: None,
+    }
+}
+
+/* ---------------------------
+   Generator helpers
+---------------------------- */
+
+fn unit(name: &str) -> AnswerKind {
+    AnswerKind::Unit {
+        unit: name.to_string(),
+    }
+}
+
+fn plural(n: i32, one: &str, many: &str) -> String {
+    if n == 1 {
+        format!("{n} {one}")
+    } else {
+        format!("{n} {many}")
+    }
+}
+
+fn a_or_an(word: &str) -> &'static str {
+    if word.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+fn yes_no(b: bool) -> &'static str {
+    if b {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn true_false(b: bool) -> &'static str {
+    if b {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// `count` different numbers from `range`, in random order.
+fn distinct(rng: &mut dyn RngCore, range: std::ops::RangeInclusive<i32>, count: usize) -> Vec<i32> {
+    let pool: Vec<i32> = range.collect();
+    pool.choose_multiple(rng, count).copied().collect()
+}
+
+fn join(v: &[i32]) -> String {
+    v.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `num / den` as a short decimal (up to 3 places), keeping at least `min_places`
+/// so "4.0" can be shown next to "2.5" in the same prompt.
+fn ratio_str(num: i32, den: i32, min_places: usize) -> String {
+    let mut s = format!("{:.3}", num as f64 / den as f64);
+    while s.ends_with('0') && s.len() - s.find('.').unwrap_or(0) - 1 > min_places {
+        s.pop();
+    }
+    if s.ends_with('.') {
+        s.pop();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::answer::{check_answer, kind_for_prompt};
+    use crate::app::session::question_rng;
+
+    const DRAWS: u32 = 40;
+
+    #[test]
+    fn every_generated_answer_checks_and_no_known_wrong_matches_it() {
+        for day_id in 1..=12 {
+            for g in 1..=5 {
+                let grade = Grade::from_u8(g);
+                let mut items = seed_questions_for(day_id, grade);
+                for difficulty in [Difficulty::Core, Difficulty::Stretch] {
+                    for index in 0..DRAWS {
+                        let mut rng = question_rng(2024, index);
+                        items.extend(fresh_question(day_id, grade, difficulty, &mut rng));
+                    }
+                }
+
+                for item in &items {
+                    let kind = kind_for_prompt(&item.prompt, item.answer_kind.as_ref());
+                    for alt in item.answer.split('|') {
+                        assert!(
+                            check_answer(alt, &item.answer, kind.as_ref()),
+                            "day {day_id} grade {g}: {:?} does not accept its own answer {alt:?}",
+                            item.prompt
+                        );
+                    }
+                    for wrong in &item.known_wrong {
+                        assert!(
+                            !check_answer(&wrong.answer, &item.answer, kind.as_ref()),
+                            "day {day_id} grade {g}: {:?} lists the answer {:?} as a known wrong one",
+                            item.prompt,
+                            wrong.answer
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
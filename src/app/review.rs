@@ -0,0 +1,219 @@
+// src/app/review.rs
+//
+// Leitner-style review queue. A missed question goes into box 1 (due
+// tomorrow); each correct review moves it up a box and pushes the next
+// review further out. Clearing the last box marks it mastered. A miss
+// anywhere sends it back to box 1.
+
+use serde::{Deserialize, Serialize};
+
+use super::answer::AnswerKind;
+use super::attempts::AttemptSource;
+use super::misconceptions::KnownWrong;
+use super::skill::Skill;
+use super::schema::{load_versioned, save_versioned, Versioned};
+use super::storage::scoped_key;
+use super::visuals::Visual;
+
+const KEY: &str = "the_numbers_review_v1";
+/// Days until the next review, by box (box 1 = index 0).
+const BOX_INTERVALS: [i64; 5] = [1, 2, 4, 7, 14];
+const MAX_ITEMS_TO_KEEP: usize = 300; // mastered items are dropped first
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReviewItem {
+    pub prompt: String,
+    pub answer: String,
+    #[serde(default)]
+    pub answer_kind: Option<AnswerKind>,
+    /// Picture the question was shown with (it may refer to "this rectangle").
+    #[serde(default)]
+    pub visual: Option<Visual>,
+    /// Hints and solution that came with the question (built ones are rebuilt).
+    #[serde(default)]
+    pub hints: Vec<String>,
+    #[serde(default)]
+    pub solution: Option<String>,
+    /// Predictable wrong answers, for the same feedback (and choices) as first time.
+    #[serde(default)]
+    pub known_wrong: Vec<KnownWrong>,
+    pub skill: Skill,
+    pub day_id: usize,
+    pub grade: u8,
+    pub source: AttemptSource,
+    /// 1..=5 while in review.
+    pub box_no: u8,
+    /// YYYY-MM-DD the item is next due.
+    pub due_ymd: String,
+    pub added_ymd: String,
+    pub reviews: u32,
+    pub mastered: bool,
+}
+
+impl ReviewItem {
+    /// Queue identity: the same question on the same day + grade.
+    pub fn same_question(&self, prompt: &str, answer: &str, day_id: usize, grade: u8) -> bool {
+        self.prompt == prompt && self.answer == answer && self.day_id == day_id && self.grade == grade
+    }
+
+    pub fn is_due(&self, today: &str) -> bool {
+        !self.mastered && self.due_ymd.as_str() <= today
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ReviewQueue {
+    pub items: Vec<ReviewItem>,
+}
+
+impl ReviewQueue {
+    /// Put a missed question into box 1 (or back into box 1 if already queued).
+    pub fn add_miss(&mut self, mut item: ReviewItem, today: &str) {
+        let due = add_days(today, BOX_INTERVALS[0]);
+        if let Some(existing) = self
+            .items
+            .iter_mut()
+            .find(|i| i.same_question(&item.prompt, &item.answer, item.day_id, item.grade))
+        {
+            existing.box_no = 1;
+            existing.due_ymd = due;
+            existing.mastered = false;
+            return;
+        }
+
+        item.box_no = 1;
+        item.due_ymd = due;
+        item.added_ymd = today.to_string();
+        item.reviews = 0;
+        item.mastered = false;
+        self.items.push(item);
+        self.compact();
+    }
+
+    /// Fold in the result of reviewing a queued question.
+    pub fn record_review(&mut self, prompt: &str, answer: &str, day_id: usize, grade: u8, ok: bool, today: &str) {
+        let Some(item) = self
+            .items
+            .iter_mut()
+            .find(|i| i.same_question(prompt, answer, day_id, grade))
+        else {
+            return;
+        };
+
+        item.reviews += 1;
+        if !ok {
+            item.box_no = 1;
+            item.due_ymd = add_days(today, BOX_INTERVALS[0]);
+            return;
+        }
+        if item.box_no as usize >= BOX_INTERVALS.len() {
+            item.mastered = true;
+            return;
+        }
+        item.due_ymd = add_days(today, BOX_INTERVALS[item.box_no as usize]);
+        item.box_no += 1;
+    }
+
+    pub fn due(&self, today: &str) -> Vec<ReviewItem> {
+        self.items.iter().filter(|i| i.is_due(today)).cloned().collect()
+    }
+
+    /// Due items for one day + grade (mixed into regular practice).
+    pub fn due_for_day(&self, today: &str, day_id: usize, grade: u8) -> Vec<ReviewItem> {
+        self.items
+            .iter()
+            .filter(|i| i.is_due(today) && i.day_id == day_id && i.grade == grade)
+            .cloned()
+            .collect()
+    }
+
+    /// (still in review, mastered)
+    pub fn counts(&self) -> (usize, usize) {
+        let mastered = self.items.iter().filter(|i| i.mastered).count();
+        (self.items.len() - mastered, mastered)
+    }
+
+    fn compact(&mut self) {
+        while self.items.len() > MAX_ITEMS_TO_KEEP {
+            let idx = self.items.iter().position(|i| i.mastered).unwrap_or(0);
+            self.items.remove(idx);
+        }
+    }
+}
+
+impl Versioned for ReviewQueue {
+    const VERSION: u32 = 1;
+}
+
+pub fn load_review() -> ReviewQueue {
+    load_versioned(&scoped_key(KEY)).unwrap_or_default()
+}
+
+pub fn save_review(q: &ReviewQueue) {
+    save_versioned(&scoped_key(KEY), q);
+}
+
+/// YYYY-MM-DD + n days (proleptic Gregorian; no JS Date needed).
+pub fn add_days(ymd: &str, n: i64) -> String {
+    let mut parts = ymd.split('-').map(|p| p.parse::<i64>().unwrap_or(1));
+    let (y, m, d) = (
+        parts.next().unwrap_or(1970),
+        parts.next().unwrap_or(1),
+        parts.next().unwrap_or(1),
+    );
+    let (y, m, d) = civil_from_days(days_from_civil(y, m, d) + n);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+// Howard Hinnant's date algorithms.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_days_crosses_months_and_years() {
+        assert_eq!(add_days("2026-10-18", 0), "2026-10-18");
+        assert_eq!(add_days("2026-10-18", 1), "2026-10-19");
+        assert_eq!(add_days("2026-10-31", 1), "2026-11-01");
+        assert_eq!(add_days("2026-12-31", 1), "2027-01-01");
+        assert_eq!(add_days("2027-01-01", -1), "2026-12-31");
+        assert_eq!(add_days("2026-03-01", -30), "2026-01-30");
+    }
+
+    #[test]
+    fn add_days_knows_leap_years() {
+        assert_eq!(add_days("2028-02-28", 1), "2028-02-29");
+        assert_eq!(add_days("2027-02-28", 1), "2027-03-01");
+        assert_eq!(add_days("2100-02-28", 1), "2100-03-01");
+        assert_eq!(add_days("2000-02-28", 1), "2000-02-29");
+        assert_eq!(add_days("2028-01-01", 366), "2029-01-01");
+    }
+
+    #[test]
+    fn add_days_counts_from_the_epoch() {
+        assert_eq!(add_days("1970-01-01", 20_744), "2026-10-18");
+    }
+}
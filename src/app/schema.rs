@@ -0,0 +1,105 @@
+// src/app/schema.rs
+//
+// Versioned storage envelope. Everything we persist as JSON is written as
+// {"v": <schema version>, "data": <payload>}. On load, older payloads run
+// through the type's migration chain and are saved back upgraded; payloads
+// that still don't parse are copied to a quarantine key instead of being
+// silently replaced by defaults.
+//
+// Payloads written before the envelope existed (bare JSON) count as version 1.
+// The `_v1` suffix on storage keys is historical; the envelope carries the version now.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use web_sys::{window, Storage};
+
+/// Upgrades a payload by exactly one version.
+pub type Migration = fn(Value) -> Result<Value, String>;
+
+const QUARANTINE_PREFIX: &str = "the_numbers_quarantine:";
+
+pub trait Versioned: Serialize + DeserializeOwned {
+    /// Current schema version (>= 1).
+    const VERSION: u32;
+    /// `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`, so the slice has `VERSION - 1` entries.
+    const MIGRATIONS: &'static [Migration] = &[];
+}
+
+fn local_storage() -> Option<Storage> {
+    window()?.local_storage().ok()?
+}
+
+/// Split a stored string into (version, payload). Bare JSON is version 1.
+fn unwrap_envelope(raw: &str) -> Result<(u32, Value), String> {
+    let v: Value = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    if let Value::Object(map) = &v {
+        if map.len() == 2 {
+            if let (Some(ver), Some(data)) = (map.get("v").and_then(Value::as_u64), map.get("data")) {
+                return Ok((ver as u32, data.clone()));
+            }
+        }
+    }
+    Ok((1, v))
+}
+
+/// Decode a stored string, running migrations as needed.
+/// Returns the value and whether it was upgraded (so the caller can re-save it).
+pub fn decode<T: Versioned>(raw: &str) -> Result<(T, bool), String> {
+    let (mut ver, mut data) = unwrap_envelope(raw)?;
+    let upgraded = ver < T::VERSION;
+
+    while ver < T::VERSION {
+        let step = T::MIGRATIONS
+            .get(ver as usize - 1)
+            .ok_or_else(|| format!("no migration from v{}", ver))?;
+        data = step(data).map_err(|e| format!("v{} -> v{}: {}", ver, ver + 1, e))?;
+        ver += 1;
+    }
+
+    // A newer build may have written this; try it as-is rather than discard it.
+    serde_json::from_value::<T>(data)
+        .map(|t| (t, upgraded))
+        .map_err(|e| format!("v{}: {}", ver, e))
+}
+
+pub fn encode<T: Versioned>(value: &T) -> Option<String> {
+    let data = serde_json::to_value(value).ok()?;
+    serde_json::to_string(&json!({ "v": T::VERSION, "data": data })).ok()
+}
+
+/// Where a payload that failed to load is kept (latest failure wins).
+pub fn quarantine_key(key: &str) -> String {
+    format!("{}{}", QUARANTINE_PREFIX, key)
+}
+
+/// Load `key`. None if it is missing, or if it was corrupt (then it's quarantined).
+pub fn load_versioned<T: Versioned>(key: &str) -> Option<T> {
+    let storage = local_storage()?;
+    let raw = storage.get_item(key).ok()??;
+
+    match decode::<T>(&raw) {
+        Ok((value, upgraded)) => {
+            if upgraded {
+                if let Some(s) = encode(&value) {
+                    let _ = storage.set_item(key, &s);
+                }
+            }
+            Some(value)
+        }
+        Err(_) => {
+            // Keep the original bytes so a fix (or a newer build) can still recover them.
+            if storage.set_item(&quarantine_key(key), &raw).is_ok() {
+                let _ = storage.remove_item(key);
+            }
+            None
+        }
+    }
+}
+
+pub fn save_versioned<T: Versioned>(key: &str, value: &T) {
+    let Some(storage) = local_storage() else { return; };
+    if let Some(s) = encode(value) {
+        let _ = storage.set_item(key, &s);
+    }
+}
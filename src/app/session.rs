@@ -0,0 +1,152 @@
+// src/app/session.rs
+//
+// Seeded practice sessions. Every question a child sees comes from
+// (session seed, question index), so a saved seed replays the exact set.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use super::adaptive::Level;
+use super::grade::Grade;
+use super::stats::ServedQuestion;
+
+/// FNV-1a: tiny, stable across builds/platforms (unlike std's DefaultHasher).
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in bytes {
+        h ^= *b as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h
+}
+
+/// Stable seed for anything derived from a prompt alone (e.g. choice order).
+pub fn prompt_seed(prompt: &str) -> u64 {
+    fnv1a(prompt.as_bytes())
+}
+
+/// Questions in one practice set.
+pub const SET_LEN: u32 = 10;
+
+/// Default seed for a session: same profile + date + day + grade => same set.
+pub fn session_seed(profile: &str, ymd: &str, day_id: usize, grade: Grade) -> u64 {
+    fnv1a(format!("{}|{}|{}|{}", profile, ymd, day_id, grade.as_u8()).as_bytes())
+}
+
+/// Seed of the Nth set of a day (1-based). Set 1 keeps the original seed.
+pub fn set_seed(profile: &str, ymd: &str, day_id: usize, grade: Grade, set_no: u32) -> u64 {
+    if set_no <= 1 {
+        return session_seed(profile, ymd, day_id, grade);
+    }
+    fnv1a(format!("{}|{}|{}|{}|{}", profile, ymd, day_id, grade.as_u8(), set_no).as_bytes())
+}
+
+/// RNG for the Nth question of a session. Independent per index so that
+/// live play and replay never drift out of step.
+pub fn question_rng(seed: u64, index: u32) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(index as u64);
+    rng
+}
+
+/// Which question set a practice widget is serving.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    /// Date the set belongs to (also gates which cached AI day is used).
+    pub ymd: String,
+    pub day_id: usize,
+    pub grade: Grade,
+    pub seed: u64,
+    /// Replays pin the adaptive level each question was served at.
+    /// None means a live session that adapts as the child answers.
+    pub pinned_levels: Option<Vec<Level>>,
+    /// Draw from the cached online set for `ymd`. Fixed when the set starts,
+    /// so an online set that arrives partway through doesn't change the rest.
+    pub online: bool,
+    /// Replays: the questions as first served, by index. Empty for live sets
+    /// (and for replays of sets recorded before questions were kept).
+    pub served: Vec<ServedQuestion>,
+}
+
+impl Session {
+    pub fn new(profile: &str, ymd: &str, day_id: usize, grade: Grade) -> Session {
+        Session {
+            ymd: ymd.to_string(),
+            day_id,
+            grade,
+            seed: session_seed(profile, ymd, day_id, grade),
+            pinned_levels: None,
+            online: false,
+            served: Vec::new(),
+        }
+    }
+
+    /// The Nth set of a day (1-based).
+    pub fn nth_set(profile: &str, ymd: &str, day_id: usize, grade: Grade, set_no: u32) -> Session {
+        Session {
+            seed: set_seed(profile, ymd, day_id, grade, set_no),
+            ..Session::new(profile, ymd, day_id, grade)
+        }
+    }
+
+    pub fn rng_for(&self, index: u32) -> ChaCha8Rng {
+        question_rng(self.seed, index)
+    }
+
+    /// Stretch slots sit at the end of the set, sized by the level the slot is served at.
+    pub fn slot_is_stretch(index: u32, level: Level) -> bool {
+        index < SET_LEN && index >= SET_LEN - level.stretch_slots()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::questions::questions_for;
+    use rand::RngCore;
+
+    fn prompts(seed: u64, index: u32, day_id: usize, grade: Grade) -> Vec<String> {
+        let mut rng = question_rng(seed, index);
+        questions_for(day_id, grade, &mut rng).into_iter().map(|q| q.prompt).collect()
+    }
+
+    #[test]
+    fn same_seed_and_index_give_the_same_questions() {
+        for day_id in 1..=12 {
+            for g in 1..=5 {
+                let grade = Grade::from_u8(g);
+                assert_eq!(prompts(42, 3, day_id, grade), prompts(42, 3, day_id, grade));
+            }
+        }
+    }
+
+    #[test]
+    fn each_index_has_its_own_stream() {
+        let mut a = question_rng(7, 0);
+        let mut b = question_rng(7, 1);
+        assert_ne!(a.next_u64(), b.next_u64());
+        // Drawing from one question's RNG doesn't move another's.
+        let mut c = question_rng(7, 1);
+        assert_eq!(question_rng(7, 1).next_u64(), c.next_u64());
+    }
+
+    #[test]
+    fn seeds_are_stable_and_distinct() {
+        let g = Grade::from_u8(3);
+        assert_eq!(session_seed("kid", "2026-10-18", 4, g), session_seed("kid", "2026-10-18", 4, g));
+        assert_eq!(set_seed("kid", "2026-10-18", 4, g, 1), session_seed("kid", "2026-10-18", 4, g));
+        assert_ne!(set_seed("kid", "2026-10-18", 4, g, 2), set_seed("kid", "2026-10-18", 4, g, 1));
+        assert_ne!(session_seed("kid", "2026-10-18", 4, g), session_seed("kid", "2026-10-19", 4, g));
+        // FNV-1a of "a" (a fixed value, so seeds survive rebuilds).
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn stretch_slots_sit_at_the_end() {
+        let level = Level::default();
+        let n = level.stretch_slots();
+        assert!(!Session::slot_is_stretch(SET_LEN - n - 1, level));
+        assert!(n == 0 || Session::slot_is_stretch(SET_LEN - 1, level));
+        assert!(!Session::slot_is_stretch(SET_LEN, level));
+    }
+}
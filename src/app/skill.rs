@@ -0,0 +1,234 @@
+// src/app/skill.rs
+//
+// Skill taxonomy used by stats + the parent summary.
+// Questions carry an explicit tag; `classify_skill` only guesses for
+// untagged worker content.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Skill {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+    WordProblem,
+    // One per day topic (see data::DAYS).
+    Patterns,
+    Equations,
+    Geometry,
+    Angles,
+    Data,
+    Coordinates,
+    Probability,
+    NumberTheory,
+    Combinatorics,
+    Rates,
+    Logic,
+    Strategy,
+    Unknown,
+}
+
+impl Skill {
+    /// The main skill behind each of the 12 days.
+    pub fn for_day(day_id: usize) -> Skill {
+        match day_id {
+            1 => Skill::Patterns,
+            2 => Skill::Equations,
+            3 => Skill::Geometry,
+            4 => Skill::Angles,
+            5 => Skill::Data,
+            6 => Skill::Coordinates,
+            7 => Skill::Probability,
+            8 => Skill::NumberTheory,
+            9 => Skill::Combinatorics,
+            10 => Skill::Rates,
+            11 => Skill::Logic,
+            12 => Skill::Strategy,
+            _ => Skill::Unknown,
+        }
+    }
+
+    /// Stable machine name (same as the serde form), for exports.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Skill::Addition => "addition",
+            Skill::Subtraction => "subtraction",
+            Skill::Multiplication => "multiplication",
+            Skill::Division => "division",
+            Skill::WordProblem => "word_problem",
+            Skill::Patterns => "patterns",
+            Skill::Equations => "equations",
+            Skill::Geometry => "geometry",
+            Skill::Angles => "angles",
+            Skill::Data => "data",
+            Skill::Coordinates => "coordinates",
+            Skill::Probability => "probability",
+            Skill::NumberTheory => "number_theory",
+            Skill::Combinatorics => "combinatorics",
+            Skill::Rates => "rates",
+            Skill::Logic => "logic",
+            Skill::Strategy => "strategy",
+            Skill::Unknown => "unknown",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Skill::Addition => "Addition",
+            Skill::Subtraction => "Subtraction",
+            Skill::Multiplication => "Multiplication",
+            Skill::Division => "Division",
+            Skill::WordProblem => "Word Problems",
+            Skill::Patterns => "Patterns",
+            Skill::Equations => "Missing Numbers",
+            Skill::Geometry => "Shapes",
+            Skill::Angles => "Angles & Turns",
+            Skill::Data => "Data",
+            Skill::Coordinates => "Grids & Movement",
+            Skill::Probability => "Chance",
+            Skill::NumberTheory => "Number Secrets",
+            Skill::Combinatorics => "Smart Counting",
+            Skill::Rates => "Rates",
+            Skill::Logic => "Logic",
+            Skill::Strategy => "Strategy",
+            Skill::Unknown => "Mixed Skills",
+        }
+    }
+
+    /// A concrete 2-minute practice idea for the parent summary.
+    pub fn plan_text(&self) -> &'static str {
+        match self {
+            Skill::Addition => "Do 10 quick addition facts (0–20). Say answers out loud. Repeat misses.",
+            Skill::Subtraction => "Do 10 subtraction facts (0–20). For tough ones, count back slowly.",
+            Skill::Multiplication => "Pick one table (2s/5s/10s). Do 10 facts, then 3 mixed.",
+            Skill::Division => "Use fact families: 12÷3, 3×4, 12÷4… keep numbers small.",
+            Skill::WordProblem => "Read 2 word problems. Ask: “What are we solving for?” then “Which operation?”",
+            Skill::Patterns => "Make a number pattern together (like 3, 6, 9…) and take turns saying what comes next.",
+            Skill::Equations => "Cover one number in 3 + 4 = 7 with a finger and ask what’s hiding. Swap roles.",
+            Skill::Geometry => "Find 3 shapes in the room. Count sides and corners, then guess the perimeter.",
+            Skill::Angles => "Stand up and make quarter, half and full turns. Name each one as you turn.",
+            Skill::Data => "Ask 5 people a quick question, tally answers, then find the most common one.",
+            Skill::Coordinates => "Draw a small grid. Give 3 moves (right 2, up 1…) and see where you land.",
+            Skill::Probability => "Flip a coin 10 times. Guess first, then compare heads vs tails.",
+            Skill::NumberTheory => "Pick 5 numbers: even or odd? Which can be split into equal groups of 3?",
+            Skill::Combinatorics => "Count outfits from 2 shirts and 3 hats without listing them all.",
+            Skill::Rates => "“Each day adds 2” — build a quick table for 5 days and spot the jump.",
+            Skill::Logic => "Say 3 statements (true or false?) and explain why with “because…”.",
+            Skill::Strategy => "Play a quick race-to-10 game (add 1 or 2). Talk about the best move.",
+            Skill::Unknown => "Do 8 mixed questions. Identify the operation first, then solve.",
+        }
+    }
+}
+
+impl std::fmt::Display for Skill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Fallback guess for untagged (worker) prompts.
+/// Topic words beat operator symbols, and the day's own topic beats "Mixed".
+/// Words match whole (plurals too), so "seven" isn't "even" and "inside" isn't "side".
+pub fn classify_skill(prompt: &str, day_id: usize) -> Skill {
+    let p = prompt.to_lowercase();
+    let has = |words: &[&str]| words.iter().any(|w| has_word(&p, w));
+
+    if has(&["probability", "chance", "likely", "unlikely", "spinner", "die", "dice", "coin"]) {
+        return Skill::Probability;
+    }
+    if has(&["mean", "median", "mode", "range of", "average", "shows up the most"]) {
+        return Skill::Data;
+    }
+    // Before angles: "3 points each turn" is a rate, not a turn.
+    if has(&["per", "each day", "each week", "each turn", "each round", "each minute"]) {
+        return Skill::Rates;
+    }
+    if has(&["angle", "degree", "turn"]) {
+        return Skill::Angles;
+    }
+    if has(&["side", "corner", "perimeter", "area", "triangle", "square", "rectangle", "circle", "hexagon", "octagon"]) {
+        return Skill::Geometry;
+    }
+    if has(&["grid", "point (", "coordinate", "start at"]) {
+        return Skill::Coordinates;
+    }
+    if has(&["prime", "even", "odd", "divisible", "factor", "multiple of", "skip count"]) {
+        return Skill::NumberTheory;
+    }
+    if has(&["outfit", "subset", "how many ways", "paths", "choices"]) {
+        return Skill::Combinatorics;
+    }
+    if has(&["what comes next", "count down", "pattern"]) {
+        return Skill::Patterns;
+    }
+    if has(&["true or false"]) {
+        return Skill::Logic;
+    }
+    if p.contains("__") || p.contains("x =") || p.contains("x=") {
+        return Skill::Equations;
+    }
+
+    if has_binary_op(&p, &['+']) || p.contains(" plus ") {
+        return Skill::Addition;
+    }
+    if has_binary_op(&p, &['-', '−']) || p.contains(" minus ") {
+        return Skill::Subtraction;
+    }
+    if p.contains('×') || p.contains(" times ") {
+        return Skill::Multiplication;
+    }
+    if p.contains('÷') {
+        return Skill::Division;
+    }
+
+    if has(&["how many", "left", "total", "in all", "shared", "altogether"]) {
+        return Skill::WordProblem;
+    }
+
+    Skill::for_day(day_id)
+}
+
+/// `phrase` in `p` as whole words; a plural "s"/"es" on the end still counts.
+pub fn has_word(p: &str, phrase: &str) -> bool {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    p.match_indices(phrase).any(|(i, _)| {
+        let before = p[..i].chars().next_back();
+        let rest = &p[i + phrase.len()..];
+        let rest = rest.strip_prefix("es").or_else(|| rest.strip_prefix('s')).unwrap_or(rest);
+        let joined_before = is_word(phrase.chars().next()) && is_word(before);
+        let joined_after = is_word(phrase.chars().next_back()) && is_word(rest.chars().next());
+        !joined_before && !joined_after
+    })
+}
+
+/// True for "7 - 3" but not "-2" or "count down: 20, 18".
+fn has_binary_op(p: &str, ops: &[char]) -> bool {
+    let chars: Vec<char> = p.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.windows(3).any(|w| {
+        (w[0].is_ascii_digit() || w[0] == '_' || w[0] == ')')
+            && ops.contains(&w[1])
+            && (w[2].is_ascii_digit() || w[2] == '_' || w[2] == '(')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_beat_turns() {
+        assert_eq!(classify_skill("You earn 3 points each turn. After 4 turns, points = ?", 1), Skill::Rates);
+        assert_eq!(classify_skill("A quarter turn is how many degrees?", 1), Skill::Angles);
+    }
+
+    #[test]
+    fn words_match_whole() {
+        assert_eq!(classify_skill("Is 8 even?", 1), Skill::NumberTheory);
+        assert_ne!(classify_skill("Seven friends share 21 cookies. How many each?", 2), Skill::NumberTheory);
+        assert_eq!(classify_skill("How many sides does a hexagon have?", 1), Skill::Geometry);
+        assert_ne!(classify_skill("3 + 4 marbles are inside the box. How many?", 1), Skill::Geometry);
+        assert_eq!(classify_skill("Area: a rectangle is 3 by 2. Area = ?", 1), Skill::Geometry);
+    }
+}
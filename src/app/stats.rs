@@ -1,16 +1,36 @@
 // src/app/stats.rs
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::app::Skill;
+use super::adaptive::Level;
+use super::answer::AnswerKind;
+use super::attempts::AttemptSource;
+use super::formats::QuestionFormat;
+use super::mastery::Mastery;
+use super::misconceptions::{KnownWrong, Misconception};
+use super::schema::{load_versioned, save_versioned, Migration, Versioned};
+use super::session::Session;
+use super::skill::Skill;
+use super::storage::scoped_key;
+use super::visuals::Visual;
+use super::today_ymd_local;
 
 const KEY: &str = "the_numbers_stats_week_v1";
 const MAX_DAYS_TO_KEEP: usize = 45; // plenty for "weekly" without growing forever
+const MAX_SESSIONS_TO_KEEP: usize = 200;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SkillCount {
     pub attempts: u32,
     pub correct: u32,
+    /// Questions got right with no hint showing / after a hint or the solution.
+    #[serde(default)]
+    pub solved_alone: u32,
+    #[serde(default)]
+    pub solved_with_hints: u32,
 }
 
 impl SkillCount {
@@ -28,69 +48,264 @@ impl SkillCount {
             Some(self.correct as f32 / self.attempts as f32)
         }
     }
+
+    pub fn record_solve(&mut self, with_hints: bool) {
+        if with_hints {
+            self.solved_with_hints += 1;
+        } else {
+            self.solved_alone += 1;
+        }
+    }
+
+    pub fn add(&mut self, other: &SkillCount) {
+        self.attempts += other.attempts;
+        self.correct += other.correct;
+        self.solved_alone += other.solved_alone;
+        self.solved_with_hints += other.solved_with_hints;
+    }
 }
 
+/// Per-skill counts for one date. Only skills that were practiced are stored.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DayCounts {
-    pub addition: SkillCount,
-    pub subtraction: SkillCount,
-    pub multiplication: SkillCount,
-    pub division: SkillCount,
-    pub word: SkillCount,
-    pub mixed: SkillCount,
+    pub skills: BTreeMap<Skill, SkillCount>,
+    /// Declared wrong answers typed that day, by misconception.
+    #[serde(default)]
+    pub misconceptions: BTreeMap<Misconception, u32>,
 }
 
 impl DayCounts {
     pub fn skill_mut(&mut self, s: Skill) -> &mut SkillCount {
-        match s {
-            Skill::Addition => &mut self.addition,
-            Skill::Subtraction => &mut self.subtraction,
-            Skill::Multiplication => &mut self.multiplication,
-            Skill::Division => &mut self.division,
-            Skill::WordProblem => &mut self.word,
-            Skill::Unknown => &mut self.mixed,
+        self.skills.entry(s).or_default()
+    }
+
+    pub fn add(&mut self, other: &DayCounts) {
+        for (s, c) in other.skills.iter() {
+            self.skill_mut(*s).add(c);
+        }
+        for (m, n) in other.misconceptions.iter() {
+            *self.misconceptions.entry(*m).or_default() += n;
+        }
+    }
+
+    /// Misconceptions hit at least `min` times, most frequent first.
+    pub fn frequent_misconceptions(&self, min: u32) -> Vec<(Misconception, u32)> {
+        let mut out: Vec<(Misconception, u32)> = self
+            .misconceptions
+            .iter()
+            .filter(|(_, n)| **n >= min)
+            .map(|(m, n)| (*m, *n))
+            .collect();
+        out.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+        out
+    }
+
+    /// All skills combined.
+    pub fn total(&self) -> SkillCount {
+        let mut out = SkillCount::default();
+        for c in self.skills.values() {
+            out.add(c);
         }
+        out
+    }
+
+    /// Practiced skills in taxonomy order.
+    pub fn practiced(&self) -> Vec<(Skill, SkillCount)> {
+        self.skills
+            .iter()
+            .filter(|(_, c)| c.attempts > 0)
+            .map(|(s, c)| (*s, c.clone()))
+            .collect()
     }
 }
 
+/// One seeded practice set — enough to replay exactly what the child saw.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub ymd: String,
+    pub day_id: usize,
+    pub grade: u8,
+    pub seed: u64,
+    /// Number of questions served (highest question index checked + 1).
+    pub questions: u32,
+    pub attempts: u32,
+    pub correct: u32,
+    /// Adaptive level each question was picked at (by index), so replay serves the same items.
+    #[serde(default)]
+    pub levels: Vec<Level>,
+    /// Set when the child reaches the end-of-set summary.
+    #[serde(default)]
+    pub result: Option<SetResult>,
+    /// The questions as served (by index). Replay shows these, so a set built
+    /// from an online day stays the same after that day has rolled over.
+    #[serde(default)]
+    pub served: Vec<ServedQuestion>,
+}
+
+/// One question of a set, as the child saw it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServedQuestion {
+    pub prompt: String,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer_kind: Option<AnswerKind>,
+    pub skill: Skill,
+    pub format: QuestionFormat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visual: Option<Visual>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_wrong: Vec<KnownWrong>,
+    /// Hints and solution that came with the question (built ones are rebuilt).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    pub source: AttemptSource,
+    #[serde(default)]
+    pub stretch: bool,
+    /// Came from the review queue rather than the seeded picks.
+    #[serde(default)]
+    pub review: bool,
+}
+
+/// How a finished set went, scored on first tries.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SetResult {
+    pub finished_ms: f64,
+    pub questions: u32,
+    pub first_try_correct: u32,
+    pub stretch: u32,
+    pub stretch_wins: u32,
+    /// Questions solved after a hint or the worked solution was shown.
+    #[serde(default)]
+    pub solved_with_hints: u32,
+    /// Questions missed on the first try, in set order.
+    pub missed: Vec<SetMiss>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SetMiss {
+    pub prompt: String,
+    pub answer: String,
+    /// What was typed on the first try.
+    pub given: String,
+    /// Got it right on a later try.
+    pub fixed: bool,
+}
+
+/// A guided 15-minute session (Learn → Practice → Win) that ran to the end.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TimedSessionRecord {
+    pub ymd: String,
+    pub day_id: usize,
+    pub grade: u8,
+    /// Seed of the practice set served during the Practice phase.
+    pub seed: u64,
+    /// Date.now() at start / finish (wall clock, pauses included).
+    pub started_ms: f64,
+    pub ended_ms: f64,
+    pub questions: u32,
+    pub attempts: u32,
+    pub correct: u32,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct WeeklyStats {
     /// Map-like storage: Vec of (YYYY-MM-DD, counts). We keep it Vec for stable serde + easy prune.
     pub days: Vec<(String, DayCounts)>,
+    /// Seeded sets, oldest first. Older stats payloads don't have this yet.
+    #[serde(default)]
+    pub sessions: Vec<SessionRecord>,
+    /// Running mastery estimate per skill. Not pruned: it already weighs recent answers most.
+    #[serde(default)]
+    pub mastery: BTreeMap<Skill, Mastery>,
+    /// Completed guided sessions, oldest first.
+    #[serde(default)]
+    pub timed: Vec<TimedSessionRecord>,
 }
 
-fn today_ymd_local() -> String {
-    let d = js_sys::Date::new_0();
-    let yyyy = d.get_full_year() as i32;
-    let mm = (d.get_month() + 1) as i32;
-    let dd = d.get_date() as i32;
-    format!("{:04}-{:02}-{:02}", yyyy, mm, dd)
+impl Versioned for WeeklyStats {
+    const VERSION: u32 = 2;
+    const MIGRATIONS: &'static [Migration] = &[migrate_v1_skill_fields];
 }
 
-fn load_raw() -> Option<String> {
-    let win = web_sys::window()?;
-    let storage = win.local_storage().ok()??;
-    storage.get_item(KEY).ok()?
-}
+/// v1 -> v2: each day's six fixed fields (addition … word, mixed) become a
+/// `skills` map keyed by `Skill`. Days already in map form pass through.
+fn migrate_v1_skill_fields(mut v: Value) -> Result<Value, String> {
+    const V1_FIELDS: [(&str, Skill); 6] = [
+        ("addition", Skill::Addition),
+        ("subtraction", Skill::Subtraction),
+        ("multiplication", Skill::Multiplication),
+        ("division", Skill::Division),
+        ("word", Skill::WordProblem),
+        ("mixed", Skill::Unknown),
+    ];
 
-fn save_raw(s: &str) {
-    if let Some(win) = web_sys::window() {
-        if let Ok(Some(storage)) = win.local_storage() {
-            let _ = storage.set_item(KEY, s);
+    let days = v
+        .get_mut("days")
+        .and_then(Value::as_array_mut)
+        .ok_or("missing days")?;
+    for day in days.iter_mut() {
+        let counts = day
+            .get_mut(1)
+            .and_then(Value::as_object_mut)
+            .ok_or("day entry is not [date, counts]")?;
+        if counts.contains_key("skills") {
+            continue;
+        }
+        let mut skills = serde_json::Map::new();
+        for (field, skill) in V1_FIELDS {
+            let Some(c) = counts.remove(field) else { continue };
+            if c.get("attempts").and_then(Value::as_u64).unwrap_or(0) > 0 {
+                let key = serde_json::to_value(skill).map_err(|e| e.to_string())?;
+                skills.insert(key.as_str().unwrap_or_default().to_string(), c);
+            }
         }
+        counts.insert("skills".to_string(), Value::Object(skills));
     }
+    Ok(v)
 }
 
 pub fn load_stats() -> WeeklyStats {
-    let Some(raw) = load_raw() else {
-        return WeeklyStats::default();
-    };
-    serde_json::from_str::<WeeklyStats>(&raw).unwrap_or_default()
+    let mut st: WeeklyStats = load_versioned(&scoped_key(KEY)).unwrap_or_default();
+    backfill_mastery(&mut st);
+    st
 }
 
 pub fn save_stats(st: &WeeklyStats) {
-    if let Ok(raw) = serde_json::to_string(st) {
-        save_raw(&raw);
+    save_versioned(&scoped_key(KEY), st);
+}
+
+/// Stats saved before mastery tracking only have counters. Replay them
+/// (oldest day first, misses before hits) so existing learners start with
+/// a sensible estimate instead of the prior.
+fn backfill_mastery(st: &mut WeeklyStats) {
+    let missing: Vec<Skill> = st
+        .days
+        .iter()
+        .flat_map(|(_, d)| d.skills.keys().copied())
+        .filter(|s| !st.mastery.contains_key(s))
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    let mut days: Vec<&(String, DayCounts)> = st.days.iter().collect();
+    days.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (_, d) in days {
+        for (skill, c) in d.skills.iter() {
+            if !missing.contains(skill) {
+                continue;
+            }
+            let m = st.mastery.entry(*skill).or_default();
+            for _ in c.correct..c.attempts {
+                m.update(false);
+            }
+            for _ in 0..c.correct {
+                m.update(true);
+            }
+        }
     }
 }
 
@@ -101,6 +316,21 @@ fn prune_old_days(mut st: WeeklyStats) -> WeeklyStats {
         let start = st.days.len() - MAX_DAYS_TO_KEEP;
         st.days = st.days[start..].to_vec();
     }
+
+    // Sessions follow the same window as the day buckets.
+    if let Some((oldest, _)) = st.days.first() {
+        let oldest = oldest.clone();
+        st.sessions.retain(|s| s.ymd >= oldest);
+        st.timed.retain(|s| s.ymd >= oldest);
+    }
+    if st.sessions.len() > MAX_SESSIONS_TO_KEEP {
+        let start = st.sessions.len() - MAX_SESSIONS_TO_KEEP;
+        st.sessions = st.sessions[start..].to_vec();
+    }
+    if st.timed.len() > MAX_SESSIONS_TO_KEEP {
+        let start = st.timed.len() - MAX_SESSIONS_TO_KEEP;
+        st.timed = st.timed[start..].to_vec();
+    }
     st
 }
 
@@ -115,13 +345,106 @@ fn get_or_create_day<'a>(st: &'a mut WeeklyStats, ymd: &str) -> &'a mut DayCount
     &mut st.days[last].1
 }
 
-pub fn record_attempt(st: &mut WeeklyStats, skill: Skill, ok: bool) {
+/// Count a check. Mastery only moves on first tries: a retry may come after
+/// the worked solution (or, with two buttons, be the only answer left).
+pub fn record_attempt(st: &mut WeeklyStats, skill: Skill, ok: bool, first_try: bool) {
     let ymd = today_ymd_local();
     let day = get_or_create_day(st, &ymd);
     day.skill_mut(skill).record(ok);
+    if first_try {
+        st.mastery.entry(skill).or_default().update(ok);
+    }
+    *st = prune_old_days(std::mem::take(st));
+}
+
+/// Count a question as solved (once per question, on the check that gets it right).
+pub fn record_solve(st: &mut WeeklyStats, skill: Skill, with_hints: bool) {
+    let ymd = today_ymd_local();
+    get_or_create_day(st, &ymd).skill_mut(skill).record_solve(with_hints);
+}
+
+pub fn record_misconception(st: &mut WeeklyStats, m: Misconception) {
+    let ymd = today_ymd_local();
+    *get_or_create_day(st, &ymd).misconceptions.entry(m).or_default() += 1;
+}
+
+fn session_record_mut<'a>(st: &'a mut WeeklyStats, session: &Session) -> &'a mut SessionRecord {
+    let grade = session.grade.as_u8();
+    let pos = st.sessions.iter().position(|s| {
+        s.seed == session.seed && s.day_id == session.day_id && s.grade == grade && s.ymd == session.ymd
+    });
+    match pos {
+        Some(i) => &mut st.sessions[i],
+        None => {
+            st.sessions.push(SessionRecord {
+                ymd: session.ymd.clone(),
+                day_id: session.day_id,
+                grade,
+                seed: session.seed,
+                ..SessionRecord::default()
+            });
+            st.sessions.last_mut().unwrap()
+        }
+    }
+}
+
+/// Attach an attempt to its seeded set so the set can be replayed later.
+pub fn record_session_attempt(
+    st: &mut WeeklyStats,
+    session: &Session,
+    index: u32,
+    level: Level,
+    ok: bool,
+) {
+    let rec = session_record_mut(st, session);
+    rec.questions = rec.questions.max(index + 1);
+    let i = index as usize;
+    if rec.levels.len() <= i {
+        rec.levels.resize(i + 1, Level::default());
+    }
+    rec.levels[i] = level;
+    rec.attempts += 1;
+    if ok {
+        rec.correct += 1;
+    }
+}
+
+/// Keep the question served at `index` (replacing it if the set was restarted).
+pub fn record_served(st: &mut WeeklyStats, session: &Session, index: u32, q: ServedQuestion) {
+    let rec = session_record_mut(st, session);
+    let i = index as usize;
+    if i < rec.served.len() {
+        rec.served[i] = q;
+    } else if i == rec.served.len() {
+        rec.served.push(q);
+    }
+}
+
+pub fn record_timed_session(st: &mut WeeklyStats, rec: TimedSessionRecord) {
+    st.timed.push(rec);
     *st = prune_old_days(std::mem::take(st));
 }
 
+/// Close out a set: its summary is stored on the set's record.
+pub fn record_set_result(st: &mut WeeklyStats, session: &Session, result: SetResult) {
+    let rec = session_record_mut(st, session);
+    rec.questions = rec.questions.max(result.questions);
+    rec.result = Some(result);
+}
+
+/// The recorded set for a replay link, if it is still kept.
+pub fn find_session(st: &WeeklyStats, ymd: &str, day_id: usize, grade: u8, seed: u64) -> Option<SessionRecord> {
+    st.sessions
+        .iter()
+        .find(|s| s.seed == seed && s.day_id == day_id && s.grade == grade && s.ymd == ymd)
+        .cloned()
+}
+
+/// Most recent seeded sets first.
+pub fn recent_sessions(st: &WeeklyStats, n: usize) -> Vec<SessionRecord> {
+    st.sessions.iter().rev().take(n).cloned().collect()
+}
+
 /// Return the last N days (including today if present), sorted newest->oldest.
 pub fn last_n_days(st: &WeeklyStats, n: usize) -> Vec<(String, DayCounts)> {
     let mut v = st.days.clone();
@@ -129,29 +452,77 @@ pub fn last_n_days(st: &WeeklyStats, n: usize) -> Vec<(String, DayCounts)> {
     v.into_iter().take(n).collect()
 }
 
+/// Days with `from <= date <= to` (YYYY-MM-DD, inclusive), sorted newest->oldest.
+pub fn days_in_range(st: &WeeklyStats, from: &str, to: &str) -> Vec<(String, DayCounts)> {
+    let mut v: Vec<(String, DayCounts)> = st
+        .days
+        .iter()
+        .filter(|(ymd, _)| ymd.as_str() >= from && ymd.as_str() <= to)
+        .cloned()
+        .collect();
+    v.sort_by(|a, b| b.0.cmp(&a.0));
+    v
+}
+
 /// Sum a slice of DayCounts into a single DayCounts (acts like a “week totals” object).
 pub fn sum_days(days: &[(String, DayCounts)]) -> DayCounts {
     let mut out = DayCounts::default();
 
     for (_, d) in days.iter() {
-        out.addition.attempts += d.addition.attempts;
-        out.addition.correct += d.addition.correct;
+        out.add(d);
+    }
 
-        out.subtraction.attempts += d.subtraction.attempts;
-        out.subtraction.correct += d.subtraction.correct;
+    out
+}
 
-        out.multiplication.attempts += d.multiplication.attempts;
-        out.multiplication.correct += d.multiplication.correct;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::schema::{decode, encode};
 
-        out.division.attempts += d.division.attempts;
-        out.division.correct += d.division.correct;
+    #[test]
+    fn v1_fixed_fields_become_a_skills_map() {
+        // Bare JSON, as written before the versioned envelope.
+        let v1 = r#"{"days":[["2026-10-01",{
+            "addition":{"attempts":4,"correct":3},
+            "subtraction":{"attempts":0,"correct":0},
+            "multiplication":{"attempts":0,"correct":0},
+            "division":{"attempts":2,"correct":2},
+            "word":{"attempts":1,"correct":0},
+            "mixed":{"attempts":0,"correct":0}
+        }]]}"#;
 
-        out.word.attempts += d.word.attempts;
-        out.word.correct += d.word.correct;
+        let (st, upgraded) = decode::<WeeklyStats>(v1).expect("v1 stats load");
+        assert!(upgraded);
+        assert_eq!(st.days.len(), 1);
+        let (ymd, day) = &st.days[0];
+        assert_eq!(ymd, "2026-10-01");
 
-        out.mixed.attempts += d.mixed.attempts;
-        out.mixed.correct += d.mixed.correct;
+        // Only practiced skills are kept.
+        let skills: Vec<Skill> = day.skills.keys().copied().collect();
+        assert_eq!(skills.len(), 3);
+        assert_eq!(day.skills[&Skill::Addition].attempts, 4);
+        assert_eq!(day.skills[&Skill::Addition].correct, 3);
+        assert_eq!(day.skills[&Skill::Division].correct, 2);
+        assert_eq!(day.skills[&Skill::WordProblem].attempts, 1);
+        assert!(st.sessions.is_empty());
     }
 
-    out
-}
\ No newline at end of file
+    #[test]
+    fn current_stats_round_trip() {
+        let mut st = WeeklyStats::default();
+        st.days.push(("2026-10-18".to_string(), DayCounts::default()));
+        st.days[0].1.skill_mut(Skill::Geometry).record(true);
+
+        let raw = encode(&st).expect("encodes");
+        let (back, upgraded) = decode::<WeeklyStats>(&raw).expect("decodes");
+        assert!(!upgraded);
+        assert_eq!(back.days[0].1.skills[&Skill::Geometry].correct, 1);
+    }
+
+    #[test]
+    fn broken_v1_payloads_are_rejected() {
+        assert!(decode::<WeeklyStats>(r#"{"weeks":[]}"#).is_err());
+        assert!(decode::<WeeklyStats>("not json").is_err());
+    }
+}
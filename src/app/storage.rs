@@ -1,35 +1,205 @@
 use serde::{Deserialize, Serialize};
-use web_sys::window;
+use web_sys::{window, Storage};
 
 use super::grade::Grade;
+use super::schema::{load_versioned, save_versioned, Versioned};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
 pub struct Progress {
     pub completed: Vec<usize>,
 }
 
+/// Id of the profile that single-user data migrates into.
+const DEFAULT_PROFILE: &str = "default";
+
 const KEY_PROGRESS: &str = "the_numbers_progress_v1";
 const KEY_GRADE: &str = "the_numbers_grade_v1";
+const KEY_PROFILES: &str = "the_numbers_profiles_v1";
+
+/// Every per-learner key written before profiles existed (moved into the default profile).
+const LEGACY_KEYS: [&str; 7] = [
+    KEY_PROGRESS,
+    KEY_GRADE,
+    "the_numbers_stats_week_v1",
+    "the_numbers_sound_v1",
+    "the_numbers_attempts_v1",
+    "the_numbers_adaptive_v1",
+    "the_numbers_review_v1",
+];
+/// Day caches are keyed per grade + day, so they're matched by prefix.
+const LEGACY_DAY_CACHE_PREFIX: &str = "the_numbers_day_v1_";
+const MAX_NAME_LEN: usize = 24;
+
+// ----------------------------
+// Profiles
+// ----------------------------
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Profiles {
+    pub active: String,
+    pub list: Vec<Profile>,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Profiles {
+            active: DEFAULT_PROFILE.to_string(),
+            list: vec![Profile {
+                id: DEFAULT_PROFILE.to_string(),
+                name: "Learner 1".to_string(),
+            }],
+        }
+    }
+}
+
+impl Profiles {
+    pub fn active_profile(&self) -> Profile {
+        self.list
+            .iter()
+            .find(|p| p.id == self.active)
+            .or_else(|| self.list.first())
+            .cloned()
+            .unwrap_or_else(|| Profiles::default().list[0].clone())
+    }
+}
+
+fn local_storage() -> Option<Storage> {
+    window()?.local_storage().ok()?
+}
+
+/// All keys currently in localStorage (snapshot, so callers may remove while iterating).
+fn all_keys(storage: &Storage) -> Vec<String> {
+    let n = storage.length().unwrap_or(0);
+    (0..n).filter_map(|i| storage.key(i).ok().flatten()).collect()
+}
+
+fn profile_scoped(base: &str, profile_id: &str) -> String {
+    format!("{}@{}", base, profile_id)
+}
+
+/// First run with profiles: move single-user data into the default profile.
+fn migrate_legacy(storage: &Storage) {
+    for key in all_keys(storage) {
+        let is_legacy = LEGACY_KEYS.contains(&key.as_str()) || key.starts_with(LEGACY_DAY_CACHE_PREFIX);
+        if !is_legacy || key.contains('@') {
+            continue;
+        }
+        if let Ok(Some(v)) = storage.get_item(&key) {
+            if storage.set_item(&profile_scoped(&key, DEFAULT_PROFILE), &v).is_ok() {
+                let _ = storage.remove_item(&key);
+            }
+        }
+    }
+}
+
+impl Versioned for Profiles {
+    const VERSION: u32 = 1;
+}
+
+pub fn load_profiles() -> Profiles {
+    let Some(storage) = local_storage() else { return Profiles::default(); };
+    match load_versioned::<Profiles>(KEY_PROFILES) {
+        Some(p) if !p.list.is_empty() => p,
+        Some(_) => Profiles::default(),
+        None => {
+            migrate_legacy(&storage);
+            let p = Profiles::default();
+            save_profiles(&p);
+            p
+        }
+    }
+}
+
+pub fn save_profiles(p: &Profiles) {
+    save_versioned(KEY_PROFILES, p);
+}
+
+pub fn active_profile_id() -> String {
+    load_profiles().active_profile().id
+}
+
+/// localStorage key for `base` under the active profile.
+pub fn scoped_key(base: &str) -> String {
+    profile_scoped(base, &active_profile_id())
+}
+
+pub fn set_active_profile(id: &str) {
+    let mut p = load_profiles();
+    if p.list.iter().any(|x| x.id == id) {
+        p.active = id.to_string();
+        save_profiles(&p);
+    }
+}
+
+/// Create a profile and make it active. Blank names get "Learner N".
+pub fn add_profile(name: &str) -> Profile {
+    let mut p = load_profiles();
+    let n = (1..)
+        .find(|n| !p.list.iter().any(|x| x.id == format!("p{}", n)))
+        .unwrap_or(1);
+    let name: String = name.trim().chars().take(MAX_NAME_LEN).collect();
+    let profile = Profile {
+        id: format!("p{}", n),
+        name: if name.is_empty() {
+            format!("Learner {}", p.list.len() + 1)
+        } else {
+            name
+        },
+    };
+    p.list.push(profile.clone());
+    p.active = profile.id.clone();
+    save_profiles(&p);
+    profile
+}
+
+/// Delete a profile and all of its data. The last profile can't be removed.
+pub fn remove_profile(id: &str) {
+    let mut p = load_profiles();
+    if p.list.len() <= 1 || !p.list.iter().any(|x| x.id == id) {
+        return;
+    }
+    p.list.retain(|x| x.id != id);
+    if p.active == id {
+        p.active = p.list[0].id.clone();
+    }
+    save_profiles(&p);
+
+    if let Some(storage) = local_storage() {
+        let suffix = format!("@{}", id);
+        for key in all_keys(&storage) {
+            if key.ends_with(&suffix) {
+                let _ = storage.remove_item(&key);
+            }
+        }
+    }
+}
+
+// ----------------------------
+// Per-profile progress + grade
+// ----------------------------
+
+impl Versioned for Progress {
+    const VERSION: u32 = 1;
+}
 
 pub fn load_progress() -> Progress {
-    let Some(win) = window() else { return Progress::default(); };
-    let Ok(Some(storage)) = win.local_storage() else { return Progress::default(); };
-    let Ok(Some(raw)) = storage.get_item(KEY_PROGRESS) else { return Progress::default(); };
-    serde_json::from_str(&raw).unwrap_or_default()
+    load_versioned(&scoped_key(KEY_PROGRESS)).unwrap_or_default()
 }
 
 pub fn save_progress(p: &Progress) {
-    let Some(win) = window() else { return; };
-    let Ok(Some(storage)) = win.local_storage() else { return; };
-    if let Ok(raw) = serde_json::to_string(p) {
-        let _ = storage.set_item(KEY_PROGRESS, &raw);
-    }
+    save_versioned(&scoped_key(KEY_PROGRESS), p);
 }
 
 pub fn load_grade() -> Grade {
     let Some(win) = window() else { return Grade::G3; };
     let Ok(Some(storage)) = win.local_storage() else { return Grade::G3; };
-    let Ok(Some(raw)) = storage.get_item(KEY_GRADE) else { return Grade::G3; };
+    let Ok(Some(raw)) = storage.get_item(&scoped_key(KEY_GRADE)) else { return Grade::G3; };
 
     let v: u8 = raw.parse().unwrap_or(3);
     Grade::from_u8(v)
@@ -38,5 +208,5 @@ pub fn load_grade() -> Grade {
 pub fn save_grade(g: Grade) {
     let Some(win) = window() else { return; };
     let Ok(Some(storage)) = win.local_storage() else { return; };
-    let _ = storage.set_item(KEY_GRADE, &g.as_u8().to_string());
-}
\ No newline at end of file
+    let _ = storage.set_item(&scoped_key(KEY_GRADE), &g.as_u8().to_string());
+}
@@ -0,0 +1,285 @@
+// src/app/timed.rs
+//
+// Guided 15-minute session: Learn (3 min) → Practice (10 min) → Win (2 min).
+// The run is saved on every tick, so a reload picks it up where it was. If
+// the page was gone for longer than AWAY_MS the clock stops at the moment it
+// was last seen and the run comes back paused, instead of finishing itself.
+
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+
+use super::schema::{load_versioned, save_versioned, Versioned};
+use super::session::Session;
+use super::stats::TimedSessionRecord;
+use super::storage::scoped_key;
+
+const KEY: &str = "the_numbers_timed_v1";
+const AWAY_MS: f64 = 60_000.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Learn,
+    Practice,
+    Win,
+    /// Finished: the summary screen.
+    Done,
+}
+
+impl Phase {
+    pub fn duration_ms(self) -> f64 {
+        let minutes = match self {
+            Phase::Learn => 3.0,
+            Phase::Practice => 10.0,
+            Phase::Win => 2.0,
+            Phase::Done => 0.0,
+        };
+        minutes * 60_000.0
+    }
+
+    pub fn next(self) -> Phase {
+        match self {
+            Phase::Learn => Phase::Practice,
+            Phase::Practice => Phase::Win,
+            Phase::Win | Phase::Done => Phase::Done,
+        }
+    }
+
+    /// Step number (1–3) shown in the header.
+    pub fn step(self) -> usize {
+        match self {
+            Phase::Learn => 1,
+            Phase::Practice => 2,
+            Phase::Win | Phase::Done => 3,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::Learn => "Learn",
+            Phase::Practice => "Practice",
+            Phase::Win => "Win",
+            Phase::Done => "Summary",
+        }
+    }
+}
+
+/// The one guided session in progress for the active learner.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimedRun {
+    pub ymd: String,
+    pub day_id: usize,
+    pub grade: u8,
+    pub seed: u64,
+    pub phase: Phase,
+    /// Time spent in the current phase before `running_since`.
+    pub banked_ms: f64,
+    /// When the clock last started; None while paused.
+    pub running_since: Option<f64>,
+    /// Last tick that saw the clock running (detects reloads / closed tabs).
+    pub last_seen_ms: f64,
+    pub started_ms: f64,
+    pub ended_ms: Option<f64>,
+    pub questions: u32,
+    pub attempts: u32,
+    pub correct: u32,
+}
+
+impl TimedRun {
+    pub fn start(session: &Session, now: f64) -> TimedRun {
+        TimedRun {
+            ymd: session.ymd.clone(),
+            day_id: session.day_id,
+            grade: session.grade.as_u8(),
+            seed: session.seed,
+            phase: Phase::Learn,
+            banked_ms: 0.0,
+            running_since: Some(now),
+            last_seen_ms: now,
+            started_ms: now,
+            ended_ms: None,
+            questions: 0,
+            attempts: 0,
+            correct: 0,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.running_since.is_none() && self.phase != Phase::Done
+    }
+
+    fn elapsed_ms(&self, now: f64) -> f64 {
+        self.banked_ms + self.running_since.map(|t| (now - t).max(0.0)).unwrap_or(0.0)
+    }
+
+    pub fn remaining_ms(&self, now: f64) -> f64 {
+        (self.phase.duration_ms() - self.elapsed_ms(now)).max(0.0)
+    }
+
+    /// Move to the next phase now (time left in this one is dropped).
+    pub fn advance(&mut self, now: f64) {
+        self.phase = self.phase.next();
+        self.banked_ms = 0.0;
+        if self.phase == Phase::Done {
+            self.running_since = None;
+            self.ended_ms = Some(now);
+        } else if self.running_since.is_some() {
+            self.running_since = Some(now);
+        }
+    }
+
+    pub fn pause(&mut self, now: f64) {
+        if self.running_since.is_some() {
+            self.banked_ms = self.elapsed_ms(now);
+            self.running_since = None;
+        }
+    }
+
+    pub fn resume(&mut self, now: f64) {
+        if self.is_paused() {
+            self.running_since = Some(now);
+            self.last_seen_ms = now;
+        }
+    }
+
+    /// Advance the clock. Returns true when the phase changed or the run auto-paused.
+    pub fn tick(&mut self, now: f64) -> bool {
+        if self.running_since.is_none() {
+            return false;
+        }
+        if now - self.last_seen_ms > AWAY_MS {
+            self.pause(self.last_seen_ms);
+            return true;
+        }
+        self.last_seen_ms = now;
+
+        let mut changed = false;
+        while self.phase != Phase::Done && self.remaining_ms(now) <= 0.0 {
+            // Carry the overshoot into the next phase so the total stays 15 minutes.
+            let over = self.elapsed_ms(now) - self.phase.duration_ms();
+            self.advance(now - over);
+            changed = true;
+        }
+        changed
+    }
+
+    /// Count one checked answer from the Practice phase.
+    pub fn record_check(&mut self, index: u32, ok: bool) {
+        self.questions = self.questions.max(index + 1);
+        self.attempts += 1;
+        if ok {
+            self.correct += 1;
+        }
+    }
+
+    pub fn record(&self) -> TimedSessionRecord {
+        TimedSessionRecord {
+            ymd: self.ymd.clone(),
+            day_id: self.day_id,
+            grade: self.grade,
+            seed: self.seed,
+            started_ms: self.started_ms,
+            ended_ms: self.ended_ms.unwrap_or(self.last_seen_ms),
+            questions: self.questions,
+            attempts: self.attempts,
+            correct: self.correct,
+        }
+    }
+}
+
+impl Versioned for TimedRun {
+    const VERSION: u32 = 1;
+}
+
+pub fn load_timed() -> Option<TimedRun> {
+    load_versioned(&scoped_key(KEY))
+}
+
+pub fn save_timed(run: &TimedRun) {
+    save_versioned(&scoped_key(KEY), run);
+}
+
+pub fn clear_timed() {
+    let Some(win) = window() else { return; };
+    let Ok(Some(storage)) = win.local_storage() else { return; };
+    let _ = storage.remove_item(&scoped_key(KEY));
+}
+
+/// "m:ss" for the countdown.
+pub fn clock_label(ms: f64) -> String {
+    let secs = (ms / 1000.0).ceil() as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_at(now: f64) -> TimedRun {
+        TimedRun {
+            ymd: "2026-10-18".to_string(),
+            day_id: 1,
+            grade: 3,
+            seed: 7,
+            phase: Phase::Learn,
+            banked_ms: 0.0,
+            running_since: Some(now),
+            last_seen_ms: now,
+            started_ms: now,
+            ended_ms: None,
+            questions: 0,
+            attempts: 0,
+            correct: 0,
+        }
+    }
+
+    #[test]
+    fn a_phase_rolls_over_and_carries_the_overshoot() {
+        let mut run = run_at(0.0);
+        for now in [50_000.0, 100_000.0, 150_000.0] {
+            assert!(!run.tick(now));
+        }
+        assert_eq!(run.phase, Phase::Learn);
+
+        // Learn ends at 3:00; this tick lands 10 s past it.
+        assert!(run.tick(190_000.0));
+        assert_eq!(run.phase, Phase::Practice);
+        assert_eq!(run.running_since, Some(180_000.0));
+        assert_eq!(run.remaining_ms(190_000.0), Phase::Practice.duration_ms() - 10_000.0);
+    }
+
+    #[test]
+    fn a_long_gap_pauses_at_the_last_tick_seen() {
+        let mut run = run_at(0.0);
+        run.tick(30_000.0);
+
+        assert!(run.tick(30_000.0 + AWAY_MS + 1.0));
+        assert!(run.is_paused());
+        assert_eq!(run.phase, Phase::Learn);
+        assert_eq!(run.banked_ms, 30_000.0);
+        assert_eq!(run.remaining_ms(1_000_000.0), Phase::Learn.duration_ms() - 30_000.0);
+
+        // Ticks while paused do nothing; resuming picks up the same time left.
+        assert!(!run.tick(2_000_000.0));
+        run.resume(2_000_000.0);
+        assert_eq!(run.remaining_ms(2_000_000.0), Phase::Learn.duration_ms() - 30_000.0);
+    }
+
+    #[test]
+    fn record_check_counts_questions_attempts_and_correct() {
+        let mut run = run_at(0.0);
+        run.record_check(0, false);
+        run.record_check(0, true);
+        run.record_check(1, true);
+        // A retry of an earlier question does not lower the question count.
+        run.record_check(0, true);
+
+        assert_eq!(run.questions, 2);
+        assert_eq!(run.attempts, 4);
+        assert_eq!(run.correct, 3);
+
+        let record = run.record();
+        assert_eq!((record.questions, record.attempts, record.correct), (2, 4, 3));
+        assert_eq!(record.ended_ms, run.last_seen_ms);
+    }
+}
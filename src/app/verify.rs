@@ -0,0 +1,441 @@
+// src/app/verify.rs
+//
+// Checks the worker's answer keys before a set is cached. Prompts we can
+// compute ourselves are worked out here and compared with the key:
+//   • plain expressions ("7 + 5 = __", "What is 180 ÷ 2?", "(2 + 4 + 6) ÷ 3 = ?"),
+//   • "True or false: 7 × 3 = 21",
+//   • sequences with a constant difference ("3, 6, 9, __"),
+//   • outfits ("3 shirts and 2 hats. How many outfits?") and subsets,
+//   • the missing-number, move, change and perimeter/area shapes hints.rs reads.
+//
+// Estimates, remainder answers ("3 R1") and outfit questions that only use
+// some of the items listed are left alone: exact arithmetic would call a
+// right key wrong.
+//
+// A key that disagrees with the computed value is dropped; anything we can't
+// compute is kept but counted as unchecked. The counts travel with the cached
+// set so the parent summary can report them.
+
+use serde::{Deserialize, Serialize};
+
+use super::ai_day::AiQuestion;
+use super::answer::{number_ignoring_unit, parse_yes_no};
+use super::hints::computed_value;
+
+/// How a fetched set's answer keys held up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnswerCheck {
+    /// Worked out here and matched the key.
+    pub confirmed: u32,
+    /// Worked out here and didn't match: removed from the set.
+    pub dropped: u32,
+    /// Not a shape we can compute; kept as sent.
+    pub unchecked: u32,
+}
+
+impl AnswerCheck {
+    pub fn add(&mut self, other: &AnswerCheck) {
+        self.confirmed += other.confirmed;
+        self.dropped += other.dropped;
+        self.unchecked += other.unchecked;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.confirmed + self.dropped + self.unchecked == 0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Confirmed,
+    Contradicted,
+    Unchecked,
+}
+
+/// What a prompt works out to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Expected {
+    Number(f64),
+    Truth(bool),
+}
+
+/// Compare one answer key with what the prompt works out to.
+pub fn verify(prompt: &str, answer: &str) -> Verdict {
+    if has_remainder(answer) {
+        return Verdict::Unchecked;
+    }
+    let matches = match expected(prompt) {
+        Some(Expected::Number(v)) => number_ignoring_unit(answer).map(|a| (a - v).abs() < 0.01),
+        Some(Expected::Truth(t)) => parse_yes_no(answer).map(|a| a == t),
+        None => None,
+    };
+    match matches {
+        Some(true) => Verdict::Confirmed,
+        Some(false) => Verdict::Contradicted,
+        None => Verdict::Unchecked,
+    }
+}
+
+/// Drop items whose answer key is contradicted; count how the rest fared.
+pub fn check_items(items: &mut Vec<AiQuestion>) -> AnswerCheck {
+    let mut check = AnswerCheck::default();
+    items.retain(|q| match verify(&q.prompt, &q.answer) {
+        Verdict::Confirmed => {
+            check.confirmed += 1;
+            true
+        }
+        Verdict::Contradicted => {
+            check.dropped += 1;
+            false
+        }
+        Verdict::Unchecked => {
+            check.unchecked += 1;
+            true
+        }
+    });
+    check
+}
+
+fn expected(prompt: &str) -> Option<Expected> {
+    let text = prompt.trim();
+    let text = text.strip_prefix("Stretch:").unwrap_or(text).trim();
+
+    if is_estimate(text) {
+        return None;
+    }
+    if text.to_lowercase().starts_with("true or false") {
+        return true_or_false(text).map(Expected::Truth);
+    }
+    expression(text)
+        .or_else(|| sequence(text))
+        .or_else(|| outfits(text))
+        .or_else(|| subsets(text))
+        .or_else(|| computed_value(text))
+        .map(Expected::Number)
+}
+
+// ----------------------------
+// Prompt shapes
+// ----------------------------
+
+/// "7 + 5 = __", "Mean: (2 + 4 + 6) ÷ 3 = ?", "What is 180 ÷ 2?"
+fn expression(text: &str) -> Option<f64> {
+    let lhs = ["= __", "= ?", "=?"].iter().find_map(|end| text.strip_suffix(end));
+    if let Some(lhs) = lhs {
+        return evaluate(after_label(lhs));
+    }
+    let rest = text.strip_prefix("What is ")?;
+    evaluate(rest.strip_suffix('?')?)
+}
+
+/// "True or false: 7 × 3 = 21"
+fn true_or_false(text: &str) -> Option<bool> {
+    let (lhs, rhs) = after_label(text).split_once('=')?;
+    Some((evaluate(lhs)? - plain(rhs)?).abs() < 1e-9)
+}
+
+/// "What comes next? 3, 6, 9, __". Only constant jumps: a short list of
+/// growing jumps could be more than one pattern.
+fn sequence(text: &str) -> Option<f64> {
+    let body = text.strip_suffix("__")?;
+    let list = body.rsplit(['?', ':']).next()?;
+    let terms: Vec<f64> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(plain)
+        .collect::<Option<_>>()?;
+    if terms.len() < 3 {
+        return None;
+    }
+    let d = terms[1] - terms[0];
+    terms
+        .windows(2)
+        .all(|w| (w[1] - w[0] - d).abs() < 1e-9)
+        .then(|| terms[terms.len() - 1] + d)
+}
+
+/// "3 shirts and 2 hats. How many outfits?" → 3 × 2. Only counts written
+/// before a word are read, so "(2)" style nudges are ignored. The question
+/// has to follow the items directly, with no rule in between ("the red shirt
+/// can't go with the blue pants"). With three kinds of item, it has to name
+/// all of them: "… outfits of a shirt and pants?" leaves the hats out.
+fn outfits(text: &str) -> Option<f64> {
+    const EXCLUSIONS: [&str; 10] =
+        ["can't", "can’t", "cannot", "not", "without", "except", "never", "only", "don't", "won't"];
+
+    let lower = text.to_lowercase();
+    if !lower.contains("outfit") {
+        return None;
+    }
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    // (word index, count, item)
+    let counts: Vec<(usize, f64, &str)> = words
+        .windows(2)
+        .enumerate()
+        .filter(|(_, w)| w[1].starts_with(|c: char| c.is_alphabetic()))
+        .filter_map(|(i, w)| Some((i, f64::from(w[0].parse::<u32>().ok()?), w[1])))
+        .collect();
+    if !(2..=3).contains(&counts.len()) {
+        return None;
+    }
+    // What comes after the last item listed.
+    let question = words[counts[counts.len() - 1].0 + 2..].join(" ");
+    let asks = question.trim_start_matches(|c: char| !c.is_alphanumeric());
+    if !(asks.starts_with("how many outfits") || asks.starts_with("outfits")) {
+        return None;
+    }
+    if lower
+        .split(|c: char| !(c.is_alphabetic() || c == '\'' || c == '’'))
+        .any(|w| EXCLUSIONS.contains(&w))
+    {
+        return None;
+    }
+    if counts.len() > 2 {
+        let named = |noun: &str| {
+            let noun = noun.trim_end_matches(|c: char| !c.is_alphabetic());
+            question.contains(noun.strip_suffix('s').unwrap_or(noun))
+        };
+        if !counts.iter().all(|(_, _, noun)| named(noun)) {
+            return None;
+        }
+    }
+    Some(counts.iter().map(|(_, n, _)| n).product())
+}
+
+/// "A set has 3 items. How many subsets? (include empty set)" → 2³. Only
+/// that wording: "subsets with exactly 2 items" or "non-empty subsets" count
+/// something else.
+fn subsets(text: &str) -> Option<f64> {
+    let rest = text.strip_prefix("A set has ")?;
+    let (n, rest) = rest.split_once(' ')?;
+    let rest = rest.strip_prefix("items.").or_else(|| rest.strip_prefix("item."))?;
+    let tail = rest.trim().strip_prefix("How many subsets?")?.trim();
+    if !(tail.is_empty() || tail == "(include empty set)") {
+        return None;
+    }
+    let n: i32 = n.parse().ok()?;
+    (0..=20).contains(&n).then(|| 2f64.powi(n))
+}
+
+/// "Estimate: 48 + 51 = ?", "Round 47 to the nearest ten", "about how many …"
+fn is_estimate(text: &str) -> bool {
+    const WORDS: [&str; 9] = [
+        "estimate", "estimated", "round", "rounded", "rounding", "about", "approximately", "roughly", "nearest",
+    ];
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphabetic())
+        .any(|w| WORDS.contains(&w))
+}
+
+/// "3 R1", "3 r 1", "3 remainder 1"
+fn has_remainder(answer: &str) -> bool {
+    let t = answer.to_lowercase().replace(' ', "");
+    if t.contains("remainder") {
+        return true;
+    }
+    let chars: Vec<char> = t.chars().collect();
+    chars
+        .windows(3)
+        .any(|w| w[0].is_ascii_digit() && w[1] == 'r' && w[2].is_ascii_digit())
+}
+
+/// "Mean: …" → "…"
+fn after_label(s: &str) -> &str {
+    s.rsplit(':').next().unwrap_or(s).trim()
+}
+
+fn plain(s: &str) -> Option<f64> {
+    s.trim().trim_end_matches(['.', '?']).replace('−', "-").parse().ok()
+}
+
+// ----------------------------
+// Expression evaluator
+// ----------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tok {
+    Num(f64),
+    Op(char),
+    Open,
+    Close,
+}
+
+/// Numbers, + − × ÷ (also x * /) and parentheses, with the usual precedence.
+/// Anything else, or no operator at all, gives None.
+fn evaluate(s: &str) -> Option<f64> {
+    let toks = tokenize(s)?;
+    if !toks.iter().any(|t| matches!(t, Tok::Op(_))) {
+        return None;
+    }
+    let mut p = Parser { toks: &toks, at: 0 };
+    let v = p.sum()?;
+    (p.at == toks.len() && v.is_finite()).then_some(v)
+}
+
+fn tokenize(s: &str) -> Option<Vec<Tok>> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let n: String = chars[start..i].iter().collect();
+            out.push(Tok::Num(n.parse().ok()?));
+            continue;
+        }
+        match c {
+            ' ' => {}
+            '+' => out.push(Tok::Op('+')),
+            '-' | '−' | '–' => out.push(Tok::Op('-')),
+            '×' | 'x' | 'X' | '*' | '·' => out.push(Tok::Op('×')),
+            '÷' | '/' => out.push(Tok::Op('÷')),
+            '(' => out.push(Tok::Open),
+            ')' => out.push(Tok::Close),
+            _ => return None,
+        }
+        i += 1;
+    }
+    Some(out)
+}
+
+struct Parser<'a> {
+    toks: &'a [Tok],
+    at: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<Tok> {
+        self.toks.get(self.at).copied()
+    }
+
+    fn sum(&mut self) -> Option<f64> {
+        let mut v = self.product()?;
+        while let Some(Tok::Op(o @ ('+' | '-'))) = self.peek() {
+            self.at += 1;
+            let r = self.product()?;
+            v = if o == '+' { v + r } else { v - r };
+        }
+        Some(v)
+    }
+
+    fn product(&mut self) -> Option<f64> {
+        let mut v = self.factor()?;
+        while let Some(Tok::Op(o @ ('×' | '÷'))) = self.peek() {
+            self.at += 1;
+            let r = self.factor()?;
+            v = if o == '×' {
+                v * r
+            } else if r != 0.0 {
+                v / r
+            } else {
+                return None;
+            };
+        }
+        Some(v)
+    }
+
+    fn factor(&mut self) -> Option<f64> {
+        let t = self.peek()?;
+        self.at += 1;
+        match t {
+            Tok::Num(n) => Some(n),
+            Tok::Op('-') => self.factor().map(|v| -v),
+            Tok::Open => {
+                let v = self.sum()?;
+                if self.peek() != Some(Tok::Close) {
+                    return None;
+                }
+                self.at += 1;
+                Some(v)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ai_day::Difficulty;
+
+    #[test]
+    fn computable_keys_are_confirmed() {
+        assert_eq!(verify("7 + 5 = __", "12"), Verdict::Confirmed);
+        assert_eq!(verify("Mean: (2 + 4 + 6) ÷ 3 = ?", "4"), Verdict::Confirmed);
+        assert_eq!(verify("What is 180 ÷ 2?", "90"), Verdict::Confirmed);
+        assert_eq!(verify("What is 2 + 3 × 4?", "14"), Verdict::Confirmed);
+        assert_eq!(verify("True or false: 7 × 3 = 21", "true"), Verdict::Confirmed);
+        assert_eq!(verify("What comes next? 3, 6, 9, __", "12"), Verdict::Confirmed);
+        assert_eq!(verify("3 shirts and 2 hats. How many outfits?", "6"), Verdict::Confirmed);
+        assert_eq!(verify("If you have 3 shirts and 2 pants, outfits = ?", "6"), Verdict::Confirmed);
+        assert_eq!(verify("You have 2 shirts and 1 hat. How many outfits? (2)", "2"), Verdict::Confirmed);
+        assert_eq!(verify("A set has 3 items. How many subsets?", "8"), Verdict::Confirmed);
+        assert_eq!(verify("How much did it change? 12 → 19", "7"), Verdict::Confirmed);
+        assert_eq!(verify("Stretch: A set has 1 item. How many subsets? (include empty set)", "2"), Verdict::Confirmed);
+    }
+
+    #[test]
+    fn wrong_keys_are_contradicted() {
+        assert_eq!(verify("7 + 5 = __", "13"), Verdict::Contradicted);
+        assert_eq!(verify("True or false: 7 × 3 = 24", "true"), Verdict::Contradicted);
+        assert_eq!(verify("What comes next? 3, 6, 9, __", "11"), Verdict::Contradicted);
+    }
+
+    #[test]
+    fn shapes_we_cant_be_sure_of_are_unchecked() {
+        assert_eq!(verify("Estimate: 48 + 51 = ?", "100"), Verdict::Unchecked);
+        assert_eq!(verify("Round 47 + 12 to the nearest ten = ?", "60"), Verdict::Unchecked);
+        assert_eq!(verify("What is 7 ÷ 2?", "3 R1"), Verdict::Unchecked);
+        assert_eq!(verify("What is 7 ÷ 2?", "3 remainder 1"), Verdict::Unchecked);
+        assert_eq!(
+            verify("3 shirts, 2 pants and 4 hats. How many outfits of a shirt and pants?", "6"),
+            Verdict::Unchecked
+        );
+        assert_eq!(verify("What comes next? 1, 2, 4, __", "8"), Verdict::Unchecked);
+        assert_eq!(
+            verify("3 shirts and 4 pants. The red shirt can't go with the blue pants. How many outfits?", "11"),
+            Verdict::Unchecked
+        );
+        assert_eq!(verify("3 shirts and 4 pants. How many outfits without the blue pants?", "9"), Verdict::Unchecked);
+        assert_eq!(verify("A set has 3 items. How many subsets have exactly 2 items?", "3"), Verdict::Unchecked);
+        assert_eq!(verify("A set has 3 items. How many non-empty subsets?", "7"), Verdict::Unchecked);
+        assert_eq!(verify("How much did it change? 19 → 12", "7"), Verdict::Unchecked);
+        assert_eq!(verify("How much did it change? 19 → 12", "-7"), Verdict::Unchecked);
+        assert_eq!(verify("Name a shape with 4 equal sides.", "square"), Verdict::Unchecked);
+    }
+
+    #[test]
+    fn outfits_with_every_item_named_are_checked() {
+        assert_eq!(
+            verify("3 shirts, 2 pants and 4 hats. How many outfits with a shirt, pants and a hat?", "24"),
+            Verdict::Confirmed
+        );
+    }
+
+    #[test]
+    fn check_items_drops_contradicted_keys() {
+        let item = |prompt: &str, answer: &str| AiQuestion {
+            prompt: prompt.to_string(),
+            answer: answer.to_string(),
+            difficulty: Difficulty::Core,
+            answer_kind: None,
+            skill: None,
+            hints: Vec::new(),
+            solution: None,
+            known_wrong: Vec::new(),
+            format: None,
+            visual: None,
+        };
+        let mut items = vec![item("7 + 5 = __", "12"), item("7 + 5 = __", "13"), item("Name a shape.", "square")];
+        let check = check_items(&mut items);
+        assert_eq!(check, AnswerCheck { confirmed: 1, dropped: 1, unchecked: 1 });
+        assert_eq!(items.len(), 2);
+        assert!(items.iter().all(|q| q.answer != "13"));
+    }
+}
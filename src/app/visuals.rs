@@ -0,0 +1,705 @@
+// src/app/visuals.rs
+//
+// Pictures for questions. A question (local or from the worker) can carry a
+// small visual spec: a polygon with labeled sides, an angle, a coordinate
+// grid with points, a bar chart or dot plot, a clock, dice or a spinner.
+//
+// `Visual::figure` turns a spec into plain SVG marks (lines, shapes, text)
+// in pixel units; app.rs only has to draw the marks. Specs that can't be
+// drawn sensibly (too few corners, huge grids, non-finite numbers…) give
+// None, and the question is shown without a picture.
+
+use serde::{Deserialize, Serialize};
+
+/// Room around a figure for side and axis labels.
+const PAD: f64 = 26.0;
+/// Biggest drawing area for scaled figures (polygons, grids).
+const MAX_W: f64 = 220.0;
+const MAX_H: f64 = 150.0;
+/// Widest grid / dot plot we draw (in units).
+const MAX_SPAN: i32 = 20;
+
+const INK: &str = "#222";
+const FAINT: &str = "#ddd";
+const FILL: &str = "#e8f0fe";
+const ACCENT: &str = "#d33";
+const WEDGES: [&str; 6] = ["#fde68a", "#bfdbfe", "#fecaca", "#bbf7d0", "#ddd6fe", "#fed7aa"];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Visual {
+    /// Corners in any units (y up, scaled to fit). `labels[i]` names the side
+    /// from corner i to corner i+1; "" leaves a side unlabeled.
+    Polygon {
+        points: Vec<[f64; 2]>,
+        #[serde(default)]
+        labels: Vec<String>,
+    },
+    /// An angle opening counter-clockwise from a flat ray (0 < degrees < 360).
+    Angle {
+        degrees: f64,
+        #[serde(default)]
+        label: Option<String>,
+    },
+    /// A coordinate grid with plotted points.
+    Grid {
+        x_min: i32,
+        x_max: i32,
+        y_min: i32,
+        y_max: i32,
+        #[serde(default)]
+        points: Vec<GridPoint>,
+    },
+    BarChart { bars: Vec<Bar> },
+    /// One dot per value, stacked over a number line.
+    DotPlot { values: Vec<i32> },
+    Clock {
+        hour: u8,
+        #[serde(default)]
+        minute: u8,
+    },
+    /// Die faces (1–6), side by side.
+    Dice { faces: Vec<u8> },
+    /// `parts` equal parts, optionally labeled.
+    Spinner {
+        parts: u32,
+        #[serde(default)]
+        labels: Vec<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GridPoint {
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub label: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    #[serde(default)]
+    pub label: String,
+    pub value: f64,
+}
+
+/// One SVG element, in pixels (y down).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mark {
+    Line {
+        from: (f64, f64),
+        to: (f64, f64),
+        stroke: &'static str,
+        width: f64,
+    },
+    Rect {
+        at: (f64, f64),
+        size: (f64, f64),
+        round: f64,
+        fill: &'static str,
+        stroke: &'static str,
+    },
+    Circle {
+        center: (f64, f64),
+        r: f64,
+        fill: &'static str,
+        stroke: &'static str,
+    },
+    /// SVG path data ("M … L … A … Z").
+    Path {
+        d: String,
+        fill: &'static str,
+        stroke: &'static str,
+    },
+    Text {
+        at: (f64, f64),
+        text: String,
+        size: f64,
+        bold: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Figure {
+    pub width: f64,
+    pub height: f64,
+    pub marks: Vec<Mark>,
+    /// Short description for screen readers.
+    pub title: String,
+}
+
+impl Visual {
+    /// A rectangle `w` by `h` with the bottom and right sides labeled.
+    pub fn rectangle(w: i32, h: i32) -> Visual {
+        let (w, h) = (w as f64, h as f64);
+        Visual::Polygon {
+            points: vec![[0.0, 0.0], [w, 0.0], [w, h], [0.0, h]],
+            labels: vec![fmt_num(w), fmt_num(h), String::new(), String::new()],
+        }
+    }
+
+    /// A right triangle with legs `base` and `height`; the long side shows `long`.
+    pub fn right_triangle(base: i32, height: i32, long: &str) -> Visual {
+        let (b, h) = (base as f64, height as f64);
+        Visual::Polygon {
+            points: vec![[0.0, 0.0], [b, 0.0], [0.0, h]],
+            labels: vec![fmt_num(b), long.to_string(), fmt_num(h)],
+        }
+    }
+
+    /// An unlabeled regular polygon (flat bottom).
+    pub fn regular(sides: u32) -> Visual {
+        let n = sides.max(3) as f64;
+        let start = -std::f64::consts::FRAC_PI_2 - std::f64::consts::PI / n;
+        let points = (0..sides.max(3))
+            .map(|i| {
+                let a = start + i as f64 * std::f64::consts::TAU / n;
+                [a.cos(), a.sin()]
+            })
+            .collect();
+        Visual::Polygon { points, labels: Vec::new() }
+    }
+
+    /// The marks to draw, or None if the spec can't be drawn sensibly.
+    pub fn figure(&self) -> Option<Figure> {
+        match self {
+            Visual::Polygon { points, labels } => polygon(points, labels),
+            Visual::Angle { degrees, label } => angle(*degrees, label.as_deref()),
+            Visual::Grid {
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                points,
+            } => grid((*x_min, *x_max), (*y_min, *y_max), points),
+            Visual::BarChart { bars } => bar_chart(bars),
+            Visual::DotPlot { values } => dot_plot(values),
+            Visual::Clock { hour, minute } => clock(*hour, *minute),
+            Visual::Dice { faces } => dice(faces),
+            Visual::Spinner { parts, labels } => spinner(*parts, labels),
+        }
+    }
+}
+
+// ----------------------------
+// Figures
+// ----------------------------
+
+fn polygon(points: &[[f64; 2]], labels: &[String]) -> Option<Figure> {
+    if points.len() < 3 || points.len() > 12 || points.iter().flatten().any(|v| !v.is_finite()) {
+        return None;
+    }
+    let (x0, x1) = min_max(points.iter().map(|p| p[0]));
+    let (y0, y1) = min_max(points.iter().map(|p| p[1]));
+    let (w, h) = (x1 - x0, y1 - y0);
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+    // Very thin shapes are stretched a little so labels still fit.
+    let scale = (MAX_W / w).min(MAX_H / h);
+    let (sw, sh) = ((w * scale).max(40.0), (h * scale).max(40.0));
+    let px: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| (PAD + (p[0] - x0) / w * sw, PAD + (y1 - p[1]) / h * sh))
+        .collect();
+
+    let mut marks = vec![Mark::Path {
+        d: closed_path(&px),
+        fill: FILL,
+        stroke: INK,
+    }];
+
+    let n = px.len();
+    let center = (
+        px.iter().map(|p| p.0).sum::<f64>() / n as f64,
+        px.iter().map(|p| p.1).sum::<f64>() / n as f64,
+    );
+    for i in 0..n {
+        let (prev, at, next) = (px[(i + n - 1) % n], px[i], px[(i + 1) % n]);
+        // Little square on right-angle corners.
+        let (u, v) = (unit(sub(prev, at)), unit(sub(next, at)));
+        if (u.0 * v.0 + u.1 * v.1).abs() < 0.02 {
+            let s = 10.0;
+            let a = add(at, scale_v(u, s));
+            let b = add(add(at, scale_v(u, s)), scale_v(v, s));
+            let c = add(at, scale_v(v, s));
+            marks.push(Mark::Path {
+                d: format!("M{} L{} L{}", pt(a), pt(b), pt(c)),
+                fill: "none",
+                stroke: INK,
+            });
+        }
+        // Side label just outside the middle of side i → i+1.
+        if let Some(label) = labels.get(i).filter(|l| !l.is_empty()) {
+            let mid = ((at.0 + next.0) / 2.0, (at.1 + next.1) / 2.0);
+            let edge = unit(sub(next, at));
+            let mut normal = (edge.1, -edge.0);
+            if normal.0 * (mid.0 - center.0) + normal.1 * (mid.1 - center.1) < 0.0 {
+                normal = (-normal.0, -normal.1);
+            }
+            marks.push(text(add(mid, scale_v(normal, 13.0)), label, 14.0, true));
+        }
+    }
+
+    let shown: Vec<&str> = labels.iter().map(String::as_str).filter(|l| !l.is_empty()).collect();
+    let title = if shown.is_empty() {
+        format!("A shape with {n} sides")
+    } else {
+        format!("A shape with {n} sides, labeled {}", shown.join(", "))
+    };
+    Some(Figure {
+        width: sw + 2.0 * PAD,
+        height: sh + 2.0 * PAD,
+        marks,
+        title,
+    })
+}
+
+fn angle(degrees: f64, label: Option<&str>) -> Option<Figure> {
+    if !degrees.is_finite() || degrees <= 0.0 || degrees >= 360.0 {
+        return None;
+    }
+    // Reflex angles need room below the flat ray too.
+    let (w, h) = (220.0, if degrees > 180.0 { 220.0 } else { 130.0 });
+    let c = (110.0, 110.0);
+    let ray = 90.0;
+    let dir = |deg: f64| {
+        let r = deg.to_radians();
+        (r.cos(), -r.sin())
+    };
+
+    let mut marks = vec![
+        Mark::Line {
+            from: c,
+            to: add(c, scale_v(dir(0.0), ray)),
+            stroke: INK,
+            width: 3.0,
+        },
+        Mark::Line {
+            from: c,
+            to: add(c, scale_v(dir(degrees), ray)),
+            stroke: INK,
+            width: 3.0,
+        },
+    ];
+    if (degrees - 90.0).abs() < 0.5 {
+        let (a, b) = (scale_v(dir(0.0), 16.0), scale_v(dir(90.0), 16.0));
+        marks.push(Mark::Path {
+            d: format!("M{} L{} L{}", pt(add(c, a)), pt(add(add(c, a), b)), pt(add(c, b))),
+            fill: "none",
+            stroke: ACCENT,
+        });
+    } else {
+        let r = 28.0;
+        let large = if degrees > 180.0 { 1 } else { 0 };
+        marks.push(Mark::Path {
+            d: format!(
+                "M{} A{r} {r} 0 {large} 0 {}",
+                pt(add(c, scale_v(dir(0.0), r))),
+                pt(add(c, scale_v(dir(degrees), r)))
+            ),
+            fill: "none",
+            stroke: ACCENT,
+        });
+    }
+    if let Some(label) = label.filter(|l| !l.is_empty()) {
+        marks.push(text(add(c, scale_v(dir(degrees / 2.0), 48.0)), label, 14.0, true));
+    }
+    marks.push(Mark::Circle {
+        center: c,
+        r: 3.5,
+        fill: INK,
+        stroke: INK,
+    });
+
+    Some(Figure {
+        width: w,
+        height: h,
+        marks,
+        title: format!("An angle{}", label.map(|l| format!(" labeled {l}")).unwrap_or_default()),
+    })
+}
+
+fn grid(x: (i32, i32), y: (i32, i32), points: &[GridPoint]) -> Option<Figure> {
+    let (sx, sy) = (x.1 - x.0, y.1 - y.0);
+    let inside = |p: &GridPoint| (x.0..=x.1).contains(&p.x) && (y.0..=y.1).contains(&p.y);
+    if !(1..=MAX_SPAN).contains(&sx) || !(1..=MAX_SPAN).contains(&sy) || !points.iter().all(inside) {
+        return None;
+    }
+    let cell = (MAX_W / sx as f64).min(MAX_W / sy as f64).min(24.0);
+    let at = |gx: i32, gy: i32| (PAD + (gx - x.0) as f64 * cell, PAD + (y.1 - gy) as f64 * cell);
+    let every = if sx.max(sy) > 12 { 2 } else { 1 };
+
+    let mut marks = Vec::new();
+    for gx in x.0..=x.1 {
+        let axis = gx == 0;
+        marks.push(Mark::Line {
+            from: at(gx, y.0),
+            to: at(gx, y.1),
+            stroke: if axis { INK } else { FAINT },
+            width: if axis { 2.0 } else { 1.0 },
+        });
+        if gx % every == 0 {
+            marks.push(text(add(at(gx, y.0), (0.0, 14.0)), &gx.to_string(), 11.0, false));
+        }
+    }
+    for gy in y.0..=y.1 {
+        let axis = gy == 0;
+        marks.push(Mark::Line {
+            from: at(x.0, gy),
+            to: at(x.1, gy),
+            stroke: if axis { INK } else { FAINT },
+            width: if axis { 2.0 } else { 1.0 },
+        });
+        if gy % every == 0 {
+            marks.push(text(add(at(x.0, gy), (-12.0, 0.0)), &gy.to_string(), 11.0, false));
+        }
+    }
+    for p in points {
+        let c = at(p.x, p.y);
+        marks.push(Mark::Circle {
+            center: c,
+            r: 5.0,
+            fill: ACCENT,
+            stroke: ACCENT,
+        });
+        if !p.label.is_empty() {
+            marks.push(text(add(c, (0.0, -12.0)), &p.label, 13.0, true));
+        }
+    }
+
+    Some(Figure {
+        width: sx as f64 * cell + 2.0 * PAD,
+        height: sy as f64 * cell + 2.0 * PAD,
+        marks,
+        title: format!(
+            "A grid from ({},{}) to ({},{}) with {} marked",
+            x.0,
+            y.0,
+            x.1,
+            y.1,
+            plural(points.len(), "point")
+        ),
+    })
+}
+
+fn bar_chart(bars: &[Bar]) -> Option<Figure> {
+    if bars.is_empty() || bars.len() > 8 || bars.iter().any(|b| !b.value.is_finite() || b.value < 0.0) {
+        return None;
+    }
+    let max = bars.iter().map(|b| b.value).fold(0.0, f64::max);
+    if max <= 0.0 {
+        return None;
+    }
+    let (bar_w, gap, chart_h) = (30.0, 14.0, 120.0);
+    let base = PAD + chart_h;
+    let width = 2.0 * PAD + bars.len() as f64 * (bar_w + gap) - gap;
+
+    let mut marks = Vec::new();
+    for (i, b) in bars.iter().enumerate() {
+        let x = PAD + i as f64 * (bar_w + gap);
+        let h = b.value / max * chart_h;
+        marks.push(Mark::Rect {
+            at: (x, base - h),
+            size: (bar_w, h),
+            round: 3.0,
+            fill: WEDGES[i % WEDGES.len()],
+            stroke: INK,
+        });
+        marks.push(text((x + bar_w / 2.0, base - h - 9.0), &fmt_num(b.value), 12.0, true));
+        if !b.label.is_empty() {
+            marks.push(text((x + bar_w / 2.0, base + 14.0), &b.label, 12.0, false));
+        }
+    }
+    marks.push(Mark::Line {
+        from: (PAD - 6.0, base),
+        to: (width - PAD + 6.0, base),
+        stroke: INK,
+        width: 2.0,
+    });
+
+    let values: Vec<String> = bars.iter().map(|b| fmt_num(b.value)).collect();
+    Some(Figure {
+        width,
+        height: base + PAD,
+        marks,
+        title: format!("A bar chart: {}", values.join(", ")),
+    })
+}
+
+fn dot_plot(values: &[i32]) -> Option<Figure> {
+    if values.is_empty() || values.len() > 40 {
+        return None;
+    }
+    let (lo, hi) = (*values.iter().min()?, *values.iter().max()?);
+    // Always show a few numbers either side, so one value still makes a line.
+    let (lo, hi) = (lo - 1, hi.max(lo + 4) + 1);
+    if hi - lo > MAX_SPAN {
+        return None;
+    }
+    let tallest = (lo..=hi).map(|v| values.iter().filter(|x| **x == v).count()).max()?;
+    let step = 22.0;
+    let base = PAD + tallest as f64 * 16.0 + 6.0;
+    let at = |v: i32| PAD + (v - lo) as f64 * step;
+
+    let mut marks = vec![Mark::Line {
+        from: (at(lo) - 8.0, base),
+        to: (at(hi) + 8.0, base),
+        stroke: INK,
+        width: 2.0,
+    }];
+    for v in lo..=hi {
+        marks.push(Mark::Line {
+            from: (at(v), base - 4.0),
+            to: (at(v), base + 4.0),
+            stroke: INK,
+            width: 1.5,
+        });
+        marks.push(text((at(v), base + 16.0), &v.to_string(), 12.0, false));
+        let count = values.iter().filter(|x| **x == v).count();
+        for k in 0..count {
+            marks.push(Mark::Circle {
+                center: (at(v), base - 12.0 - k as f64 * 16.0),
+                r: 6.0,
+                fill: ACCENT,
+                stroke: ACCENT,
+            });
+        }
+    }
+
+    Some(Figure {
+        width: at(hi) + PAD,
+        height: base + PAD,
+        marks,
+        title: format!("A dot plot of {}", plural(values.len(), "value")),
+    })
+}
+
+fn clock(hour: u8, minute: u8) -> Option<Figure> {
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+    let c = (100.0, 100.0);
+    let r = 80.0;
+    // Clockwise from 12 o'clock.
+    let dir = |turn: f64| {
+        let a = turn * std::f64::consts::TAU;
+        (a.sin(), -a.cos())
+    };
+
+    let mut marks = vec![Mark::Circle {
+        center: c,
+        r,
+        fill: "#fff",
+        stroke: INK,
+    }];
+    for i in 1..=12 {
+        let d = dir(i as f64 / 12.0);
+        marks.push(Mark::Line {
+            from: add(c, scale_v(d, r - 8.0)),
+            to: add(c, scale_v(d, r)),
+            stroke: INK,
+            width: 2.0,
+        });
+        marks.push(text(add(c, scale_v(d, r - 20.0)), &i.to_string(), 13.0, true));
+    }
+    let hours = (hour % 12) as f64 + minute as f64 / 60.0;
+    marks.push(Mark::Line {
+        from: c,
+        to: add(c, scale_v(dir(hours / 12.0), 42.0)),
+        stroke: INK,
+        width: 5.0,
+    });
+    marks.push(Mark::Line {
+        from: c,
+        to: add(c, scale_v(dir(minute as f64 / 60.0), 64.0)),
+        stroke: INK,
+        width: 3.0,
+    });
+    marks.push(Mark::Circle {
+        center: c,
+        r: 4.0,
+        fill: ACCENT,
+        stroke: ACCENT,
+    });
+
+    Some(Figure {
+        width: 200.0,
+        height: 200.0,
+        marks,
+        title: "A clock".to_string(),
+    })
+}
+
+fn dice(faces: &[u8]) -> Option<Figure> {
+    if faces.is_empty() || faces.len() > 6 || faces.iter().any(|f| !(1..=6).contains(f)) {
+        return None;
+    }
+    let (size, gap, per_row) = (56.0, 14.0, 3);
+    let rows = faces.len().div_ceil(per_row);
+    let cols = faces.len().min(per_row);
+
+    let mut marks = Vec::new();
+    for (i, face) in faces.iter().enumerate() {
+        let x = 10.0 + (i % per_row) as f64 * (size + gap);
+        let y = 10.0 + (i / per_row) as f64 * (size + gap);
+        marks.push(Mark::Rect {
+            at: (x, y),
+            size: (size, size),
+            round: 10.0,
+            fill: "#fff",
+            stroke: INK,
+        });
+        for (px, py) in pips(*face) {
+            marks.push(Mark::Circle {
+                center: (x + px * size, y + py * size),
+                r: 5.0,
+                fill: INK,
+                stroke: INK,
+            });
+        }
+    }
+
+    let shown: Vec<String> = faces.iter().map(|f| f.to_string()).collect();
+    Some(Figure {
+        width: 20.0 + cols as f64 * (size + gap) - gap,
+        height: 20.0 + rows as f64 * (size + gap) - gap,
+        marks,
+        title: format!("Dice showing {}", shown.join(", ")),
+    })
+}
+
+/// Pip positions on a unit face.
+fn pips(face: u8) -> Vec<(f64, f64)> {
+    let (l, m, r) = (0.27, 0.5, 0.73);
+    match face {
+        1 => vec![(m, m)],
+        2 => vec![(l, l), (r, r)],
+        3 => vec![(l, l), (m, m), (r, r)],
+        4 => vec![(l, l), (r, l), (l, r), (r, r)],
+        5 => vec![(l, l), (r, l), (m, m), (l, r), (r, r)],
+        _ => vec![(l, l), (r, l), (l, m), (r, m), (l, r), (r, r)],
+    }
+}
+
+fn spinner(parts: u32, labels: &[String]) -> Option<Figure> {
+    if !(2..=12).contains(&parts) {
+        return None;
+    }
+    let c = (100.0, 100.0);
+    let r = 80.0;
+    let dir = |turn: f64| {
+        let a = turn * std::f64::consts::TAU;
+        (a.sin(), -a.cos())
+    };
+
+    let mut marks = Vec::new();
+    for i in 0..parts {
+        let (a, b) = (i as f64 / parts as f64, (i + 1) as f64 / parts as f64);
+        let large = if b - a > 0.5 { 1 } else { 0 };
+        // Odd counts would put two same-colored wedges side by side at the seam.
+        let colors = if parts % 2 == 1 && parts <= 5 { WEDGES.len() } else { 2 };
+        marks.push(Mark::Path {
+            d: format!(
+                "M{} L{} A{r} {r} 0 {large} 1 {} Z",
+                pt(c),
+                pt(add(c, scale_v(dir(a), r))),
+                pt(add(c, scale_v(dir(b), r)))
+            ),
+            fill: WEDGES[i as usize % colors],
+            stroke: INK,
+        });
+        if let Some(label) = labels.get(i as usize).filter(|l| !l.is_empty()) {
+            marks.push(text(add(c, scale_v(dir((a + b) / 2.0), r * 0.62)), label, 13.0, true));
+        }
+    }
+    // The pointer.
+    marks.push(Mark::Line {
+        from: c,
+        to: add(c, scale_v(dir(0.08), r * 0.7)),
+        stroke: ACCENT,
+        width: 4.0,
+    });
+    marks.push(Mark::Circle {
+        center: c,
+        r: 6.0,
+        fill: ACCENT,
+        stroke: ACCENT,
+    });
+
+    Some(Figure {
+        width: 200.0,
+        height: 200.0,
+        marks,
+        title: format!("A spinner with {parts} equal parts"),
+    })
+}
+
+// ----------------------------
+// Helpers
+// ----------------------------
+
+fn text(at: (f64, f64), s: &str, size: f64, bold: bool) -> Mark {
+    Mark::Text {
+        at,
+        text: s.to_string(),
+        size,
+        bold,
+    }
+}
+
+fn min_max(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)))
+}
+
+fn add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn sub(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn scale_v(a: (f64, f64), k: f64) -> (f64, f64) {
+    (a.0 * k, a.1 * k)
+}
+
+fn unit(a: (f64, f64)) -> (f64, f64) {
+    let len = (a.0 * a.0 + a.1 * a.1).sqrt();
+    if len == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (a.0 / len, a.1 / len)
+    }
+}
+
+fn pt(p: (f64, f64)) -> String {
+    format!("{:.1} {:.1}", p.0, p.1)
+}
+
+fn closed_path(points: &[(f64, f64)]) -> String {
+    let mut d = String::new();
+    for (i, p) in points.iter().enumerate() {
+        d.push_str(if i == 0 { "M" } else { " L" });
+        d.push_str(&pt(*p));
+    }
+    d.push_str(" Z");
+    d
+}
+
+/// 3.0 → "3", 2.5 → "2.5".
+fn fmt_num(v: f64) -> String {
+    if v.fract() == 0.0 {
+        format!("{}", v as i64)
+    } else {
+        format!("{v}")
+    }
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("1 {word}")
+    } else {
+        format!("{n} {word}s")
+    }
+}
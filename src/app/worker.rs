@@ -0,0 +1,460 @@
+// src/app/worker.rs
+//
+// Client for the question worker. Every request:
+//   • goes through the runtime config (offline / AI off = no request at all),
+//   • is aborted after the configured timeout,
+//   • is retried a couple of times on timeouts, network errors and 5xx/429,
+//     with exponential backoff plus jitter,
+//   • is validated before anything is cached: schema version, grade and day
+//     match the request, sane prompt lengths, answer keys that check out
+//     (see verify.rs), and enough core + stretch items left after that.
+//
+// After CIRCUIT_THRESHOLD failed fetches in a row the circuit opens and
+// requests fail fast for CIRCUIT_COOLDOWN_MS (then one trial request is let
+// through). Failures come back as a typed WorkerError so the UI can say
+// what went wrong; the most recent one is kept for the Settings page.
+//
+// Newer workers list what they support at /api/capabilities (batches, dated
+// requests); it's asked once per page load and worker URL.
+
+use std::cell::{Cell, RefCell};
+
+use serde::de::DeserializeOwned;
+
+use super::ai_day::{BatchResponse, Capabilities, DayResponse, Difficulty};
+use super::config::{abort_after, sleep_ms, AppConfig};
+use super::grade::Grade;
+use super::verify::check_items;
+
+/// Payload versions this build understands.
+const SUPPORTED_VERSIONS: std::ops::RangeInclusive<u32> = 1..=1;
+/// Fewer items than this and the local bank is the better set.
+const MIN_CORE: usize = 3;
+const MIN_STRETCH: usize = 1;
+const MAX_PROMPT_CHARS: usize = 300;
+const MAX_ANSWER_CHARS: usize = 60;
+
+/// Tries per fetch (1 = no retry).
+const MAX_ATTEMPTS: u32 = 3;
+const BACKOFF_BASE_MS: f64 = 400.0;
+const CIRCUIT_THRESHOLD: u32 = 3;
+const CIRCUIT_COOLDOWN_MS: f64 = 5.0 * 60.0 * 1000.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorkerError {
+    /// Offline mode or AI off: nothing was sent.
+    Disabled,
+    /// Too many recent failures; not trying again until `retry_at_ms`.
+    CircuitOpen { retry_at_ms: f64 },
+    Timeout,
+    Network(String),
+    Http(u16),
+    /// The body wasn't a DayResponse.
+    Json(String),
+    /// Parsed, but not something we should use.
+    Invalid(Invalid),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Invalid {
+    UnknownVersion(u32),
+    WrongGrade { want: u8, got: u8 },
+    WrongDay { want: usize, got: usize },
+    WrongDate { want: String, got: String },
+    BadDate(String),
+    TooFewCore { need: usize, got: usize },
+    TooFewStretch { need: usize, got: usize },
+}
+
+impl WorkerError {
+    /// Worth another try right away (the server may recover).
+    fn is_transient(&self) -> bool {
+        match self {
+            WorkerError::Timeout | WorkerError::Network(_) => true,
+            WorkerError::Http(code) => *code == 429 || *code >= 500,
+            _ => false,
+        }
+    }
+
+    /// Short, parent-readable description.
+    pub fn message(&self) -> String {
+        match self {
+            WorkerError::Disabled => "online questions are switched off".to_string(),
+            WorkerError::CircuitOpen { retry_at_ms } => {
+                let mins = ((retry_at_ms - js_sys::Date::now()) / 60_000.0).ceil().max(1.0);
+                format!("paused after repeated failures (retrying in about {mins} min)")
+            }
+            WorkerError::Timeout => "the question service took too long".to_string(),
+            WorkerError::Network(_) => "couldn't reach the question service".to_string(),
+            WorkerError::Http(code) => format!("the question service answered with error {code}"),
+            WorkerError::Json(_) => "the question service sent something unreadable".to_string(),
+            WorkerError::Invalid(why) => match why {
+                Invalid::UnknownVersion(v) => format!("the questions use a newer format (v{v})"),
+                Invalid::WrongGrade { want, got } => format!("got Grade {got} questions instead of Grade {want}"),
+                Invalid::WrongDay { want, got } => format!("got Day {got} questions instead of Day {want}"),
+                Invalid::WrongDate { want, got } => format!("got the set for {got} instead of {want}"),
+                Invalid::BadDate(d) => format!("the set has an unreadable date ({d})"),
+                Invalid::TooFewCore { need, got } => format!("only {got} usable core questions (need {need})"),
+                Invalid::TooFewStretch { need, got } => format!("only {got} usable stretch questions (need {need})"),
+            },
+        }
+    }
+}
+
+// ----------------------------
+// Validation
+// ----------------------------
+
+/// Check a response against the request (`date` only when one was asked
+/// for). Items with empty or over-long prompts/answers, or an answer key that
+/// doesn't work out, are dropped; what's left must still make a full set.
+pub fn validate(mut day: DayResponse, grade: Grade, day_id: usize, date: Option<&str>) -> Result<DayResponse, Invalid> {
+    if !SUPPORTED_VERSIONS.contains(&day.version) {
+        return Err(Invalid::UnknownVersion(day.version));
+    }
+    if day.grade != grade.as_u8() {
+        return Err(Invalid::WrongGrade {
+            want: grade.as_u8(),
+            got: day.grade,
+        });
+    }
+    if day.day_id != day_id {
+        return Err(Invalid::WrongDay { want: day_id, got: day.day_id });
+    }
+    if !is_ymd(&day.date_ymd) {
+        return Err(Invalid::BadDate(day.date_ymd.chars().take(20).collect()));
+    }
+    if let Some(want) = date.filter(|d| *d != day.date_ymd) {
+        return Err(Invalid::WrongDate {
+            want: want.to_string(),
+            got: day.date_ymd,
+        });
+    }
+
+    day.items.retain(|q| {
+        let prompt = q.prompt.trim().chars().count();
+        let answer = q.answer.trim().chars().count();
+        (1..=MAX_PROMPT_CHARS).contains(&prompt) && (1..=MAX_ANSWER_CHARS).contains(&answer)
+    });
+    day.check = check_items(&mut day.items);
+
+    let stretch = day.items.iter().filter(|q| q.difficulty == Difficulty::Stretch).count();
+    let core = day.items.len() - stretch;
+    if core < MIN_CORE {
+        return Err(Invalid::TooFewCore { need: MIN_CORE, got: core });
+    }
+    if stretch < MIN_STRETCH {
+        return Err(Invalid::TooFewStretch {
+            need: MIN_STRETCH,
+            got: stretch,
+        });
+    }
+    Ok(day)
+}
+
+/// YYYY-MM-DD (the cache compares these as strings).
+fn is_ymd(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && b.iter().enumerate().all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit())
+}
+
+// ----------------------------
+// Circuit breaker + last error
+// ----------------------------
+
+thread_local! {
+    static FAILURES: Cell<u32> = const { Cell::new(0) };
+    static OPEN_UNTIL: Cell<f64> = const { Cell::new(0.0) };
+    static LAST_ERROR: RefCell<Option<(f64, WorkerError)>> = const { RefCell::new(None) };
+}
+
+fn circuit_check(now: f64) -> Result<(), WorkerError> {
+    let until = OPEN_UNTIL.with(Cell::get);
+    if now < until {
+        Err(WorkerError::CircuitOpen { retry_at_ms: until })
+    } else {
+        Ok(())
+    }
+}
+
+fn record_success() {
+    FAILURES.with(|f| f.set(0));
+    OPEN_UNTIL.with(|o| o.set(0.0));
+}
+
+fn record_failure(now: f64, err: &WorkerError) {
+    let n = FAILURES.with(|f| {
+        f.set(f.get() + 1);
+        f.get()
+    });
+    // Past the threshold every failure (including a half-open trial) re-opens it.
+    if n >= CIRCUIT_THRESHOLD {
+        OPEN_UNTIL.with(|o| o.set(now + CIRCUIT_COOLDOWN_MS));
+    }
+    LAST_ERROR.with(|l| *l.borrow_mut() = Some((now, err.clone())));
+}
+
+/// The most recent failure this page load: (time in ms, error).
+pub fn last_error() -> Option<(f64, WorkerError)> {
+    LAST_ERROR.with(|l| l.borrow().clone())
+}
+
+// ----------------------------
+// Capabilities
+// ----------------------------
+
+thread_local! {
+    /// (worker URL, what it said) for this page load.
+    static CAPABILITIES: RefCell<Option<(String, Capabilities)>> = const { RefCell::new(None) };
+}
+
+/// What the worker supports. A worker without /api/capabilities (404) gets
+/// the defaults; other failures also do, but are asked again next time.
+pub async fn capabilities(config: &AppConfig) -> Capabilities {
+    let base = base_url(config);
+    let known = CAPABILITIES.with(|c| c.borrow().clone());
+    if let Some((_, caps)) = known.filter(|(url, _)| *url == base) {
+        return caps;
+    }
+    if !config.may_fetch() || circuit_check(js_sys::Date::now()).is_err() {
+        return Capabilities::default();
+    }
+    let caps = match get_json::<Capabilities>(&format!("{base}/api/capabilities"), config.timeout_ms()).await {
+        Ok(caps) => caps,
+        Err(WorkerError::Http(404)) => Capabilities::default(),
+        // Unreachable right now; the day requests will say why.
+        Err(_) => return Capabilities::default(),
+    };
+    CAPABILITIES.with(|c| *c.borrow_mut() = Some((base, caps.clone())));
+    caps
+}
+
+// ----------------------------
+// Requests
+// ----------------------------
+
+/// One day's set for a grade, validated. Never sends anything when the
+/// config says offline / AI off. `date` asks for a particular day's set
+/// (only for workers that say they're `dated`); None means the worker's today.
+pub async fn fetch_day(config: &AppConfig, grade: Grade, day_id: usize, date: Option<&str>) -> Result<DayResponse, WorkerError> {
+    if !config.may_fetch() {
+        return Err(WorkerError::Disabled);
+    }
+    circuit_check(js_sys::Date::now())?;
+
+    let mut url = format!("{}/api/day-questions?grade={}&day={}", base_url(config), grade.as_u8(), day_id);
+    if let Some(date) = date {
+        url.push_str(&format!("&date={date}"));
+    }
+
+    let result = get_with_retries::<DayResponse>(&url, config.timeout_ms())
+        .await
+        .and_then(|day| validate(day, grade, day_id, date).map_err(WorkerError::Invalid));
+    record(&result);
+    result
+}
+
+/// Several days' sets for a grade in one request, for every date from `from`
+/// to `to`. Days that fail validation (or weren't asked for) are left out,
+/// so the caller fetches whatever is missing one by one.
+pub async fn fetch_batch(
+    config: &AppConfig,
+    grade: Grade,
+    days: &[usize],
+    from: &str,
+    to: &str,
+) -> Result<Vec<DayResponse>, WorkerError> {
+    if !config.may_fetch() {
+        return Err(WorkerError::Disabled);
+    }
+    circuit_check(js_sys::Date::now())?;
+
+    let list = days.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+    let url = format!(
+        "{}/api/day-questions/batch?grade={}&days={}&from={}&to={}",
+        base_url(config),
+        grade.as_u8(),
+        list,
+        from,
+        to
+    );
+
+    let result = get_with_retries::<BatchResponse>(&url, config.timeout_ms())
+        .await
+        .and_then(|batch| {
+            if !SUPPORTED_VERSIONS.contains(&batch.version) {
+                return Err(WorkerError::Invalid(Invalid::UnknownVersion(batch.version)));
+            }
+            if batch.grade != grade.as_u8() {
+                return Err(WorkerError::Invalid(Invalid::WrongGrade {
+                    want: grade.as_u8(),
+                    got: batch.grade,
+                }));
+            }
+            Ok(batch
+                .days
+                .into_iter()
+                .filter(|d| days.contains(&d.day_id) && (from..=to).contains(&d.date_ymd.as_str()))
+                .filter_map(|d| {
+                    let day_id = d.day_id;
+                    validate(d, grade, day_id, None).ok()
+                })
+                .collect())
+        });
+    record(&result);
+    result
+}
+
+fn base_url(config: &AppConfig) -> String {
+    config.worker_base_url.trim().trim_end_matches('/').to_string()
+}
+
+fn record<T>(result: &Result<T, WorkerError>) {
+    match result {
+        Ok(_) => record_success(),
+        Err(e) => record_failure(js_sys::Date::now(), e),
+    }
+}
+
+/// GET with up to MAX_ATTEMPTS tries on transient errors.
+async fn get_with_retries<T: DeserializeOwned>(url: &str, timeout_ms: u32) -> Result<T, WorkerError> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = get_json(url, timeout_ms).await;
+        match &result {
+            Err(e) if e.is_transient() && attempt < MAX_ATTEMPTS => {
+                sleep_ms(backoff_ms(attempt)).await;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// Exponential backoff with jitter: attempt 1 → 100–500 ms, 2 → 100–900 ms…
+fn backoff_ms(attempt: u32) -> i32 {
+    let cap = BACKOFF_BASE_MS * 2f64.powi(attempt as i32 - 1);
+    (BACKOFF_BASE_MS / 4.0 + js_sys::Math::random() * cap) as i32
+}
+
+async fn get_json<T: DeserializeOwned>(url: &str, timeout_ms: u32) -> Result<T, WorkerError> {
+    let signal = abort_after(timeout_ms);
+    let resp = gloo_net::http::Request::get(url)
+        .abort_signal(signal.as_ref())
+        .send()
+        .await
+        .map_err(net_error)?;
+    if !resp.ok() {
+        return Err(WorkerError::Http(resp.status()));
+    }
+    let body = resp.text().await.map_err(net_error)?;
+    serde_json::from_str::<T>(&body).map_err(|e| WorkerError::Json(e.to_string()))
+}
+
+fn net_error(e: gloo_net::Error) -> WorkerError {
+    match e {
+        gloo_net::Error::JsError(js) if js.name == "AbortError" => WorkerError::Timeout,
+        other => WorkerError::Network(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ai_day::AiQuestion;
+    use crate::app::verify::AnswerCheck;
+
+    fn item(prompt: &str, answer: &str, difficulty: Difficulty) -> AiQuestion {
+        AiQuestion {
+            prompt: prompt.to_string(),
+            answer: answer.to_string(),
+            difficulty,
+            answer_kind: None,
+            skill: None,
+            hints: Vec::new(),
+            solution: None,
+            known_wrong: Vec::new(),
+            format: None,
+            visual: None,
+        }
+    }
+
+    fn day(items: Vec<AiQuestion>) -> DayResponse {
+        DayResponse {
+            version: 1,
+            grade: 3,
+            day_id: 2,
+            date_ymd: "2026-10-18".to_string(),
+            items,
+            source: "ai".to_string(),
+            check: AnswerCheck::default(),
+        }
+    }
+
+    fn full_set() -> Vec<AiQuestion> {
+        vec![
+            item("7 + 5 = __", "12", Difficulty::Core),
+            item("What is 180 ÷ 2?", "90", Difficulty::Core),
+            item("Which shape has 3 sides?", "triangle", Difficulty::Core),
+            item("A set has 3 items. How many subsets?", "8", Difficulty::Stretch),
+        ]
+    }
+
+    #[test]
+    fn a_matching_set_passes() {
+        let ok = validate(day(full_set()), Grade::G3, 2, Some("2026-10-18")).unwrap();
+        assert_eq!(ok.items.len(), 4);
+        assert!(validate(day(full_set()), Grade::G3, 2, None).is_ok());
+    }
+
+    #[test]
+    fn the_wrong_grade_day_or_date_is_rejected() {
+        assert_eq!(
+            validate(day(full_set()), Grade::G4, 2, None).unwrap_err(),
+            Invalid::WrongGrade { want: 4, got: 3 }
+        );
+        assert_eq!(
+            validate(day(full_set()), Grade::G3, 5, None).unwrap_err(),
+            Invalid::WrongDay { want: 5, got: 2 }
+        );
+        assert_eq!(
+            validate(day(full_set()), Grade::G3, 2, Some("2026-10-19")).unwrap_err(),
+            Invalid::WrongDate {
+                want: "2026-10-19".to_string(),
+                got: "2026-10-18".to_string(),
+            }
+        );
+
+        let mut bad = day(full_set());
+        bad.date_ymd = "18/10/2026".to_string();
+        assert_eq!(
+            validate(bad, Grade::G3, 2, None).unwrap_err(),
+            Invalid::BadDate("18/10/2026".to_string())
+        );
+    }
+
+    #[test]
+    fn too_few_core_items_after_dropping_bad_ones_is_rejected() {
+        let mut items = full_set();
+        // A wrong answer key and an empty prompt are both dropped.
+        items[0].answer = "13".to_string();
+        items[1].prompt = "  ".to_string();
+        assert_eq!(
+            validate(day(items), Grade::G3, 2, None).unwrap_err(),
+            Invalid::TooFewCore { need: MIN_CORE, got: 1 }
+        );
+    }
+
+    #[test]
+    fn a_set_without_stretch_items_is_rejected() {
+        let mut items = full_set();
+        items.pop();
+        assert_eq!(
+            validate(day(items), Grade::G3, 2, None).unwrap_err(),
+            Invalid::TooFewStretch { need: MIN_STRETCH, got: 0 }
+        );
+    }
+}
@@ -0,0 +1,119 @@
+// src/app/worksheet.rs
+//
+// Printable worksheets. A sheet is built from (day, grade, count, seed):
+// the local bank, shuffled by the seed. The same inputs rebuild the same
+// sheet, so a printed sheet code can be regenerated for a second copy or the
+// key. The cached online set is left out on purpose: it changes every day and
+// isn't part of the route, so it would quietly change a bookmarked sheet.
+
+use rand::seq::SliceRandom;
+
+use super::grade::Grade;
+use super::questions::{fresh_question, questions_for, Difficulty};
+use super::session::question_rng;
+use super::visuals::Visual;
+
+pub const COUNT_CHOICES: [usize; 4] = [8, 12, 16, 20];
+pub const DEFAULT_COUNT: usize = 12;
+/// Roughly 3 in 10 questions are stretch (same share as a Mixed session).
+const STRETCH_SHARE_OF_10: usize = 3;
+/// Generator draws per missing item before giving up (small ranges repeat).
+const TOP_UP_TRIES: usize = 12;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SheetItem {
+    pub prompt: String,
+    pub answer: String,
+    pub visual: Option<Visual>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Worksheet {
+    pub core: Vec<SheetItem>,
+    pub stretch: Vec<SheetItem>,
+}
+
+impl Worksheet {
+    /// Core then stretch, numbered from 1 (same order as printed).
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &SheetItem)> {
+        self.core.iter().chain(self.stretch.iter()).enumerate().map(|(i, q)| (i + 1, q))
+    }
+}
+
+fn push_unique(
+    pool: &mut Vec<SheetItem>,
+    seen: &mut Vec<String>,
+    prompt: &str,
+    answer: &str,
+    visual: Option<&Visual>,
+) {
+    if !seen.iter().any(|p| p == prompt) {
+        seen.push(prompt.to_string());
+        pool.push(SheetItem {
+            prompt: prompt.to_string(),
+            answer: answer.to_string(),
+            visual: visual.cloned(),
+        });
+    }
+}
+
+pub fn build_worksheet(day_id: usize, grade: Grade, count: usize, seed: u64) -> Worksheet {
+    let mut rng = question_rng(seed, 0);
+    let mut seen: Vec<String> = Vec::new();
+    let mut core: Vec<SheetItem> = Vec::new();
+    let mut stretch: Vec<SheetItem> = Vec::new();
+
+    for q in questions_for(day_id, grade, &mut rng) {
+        let pool = if q.difficulty == Difficulty::Stretch { &mut stretch } else { &mut core };
+        push_unique(pool, &mut seen, &q.prompt, &q.answer, q.visual.as_ref());
+    }
+
+    let want_stretch = (count * STRETCH_SHARE_OF_10 + 5) / 10;
+    let want_core = count - want_stretch;
+
+    // Top up from the generators if the bank is smaller than the sheet.
+    // Stretch also makes up for a core pool that ran dry.
+    for difficulty in [Difficulty::Core, Difficulty::Stretch] {
+        let (pool, want) = match difficulty {
+            Difficulty::Core => (&mut core, want_core),
+            Difficulty::Stretch => {
+                let short = want_core.saturating_sub(core.len());
+                (&mut stretch, want_stretch + short)
+            }
+        };
+        let mut tries = 0;
+        while pool.len() < want && tries < want * TOP_UP_TRIES {
+            tries += 1;
+            match fresh_question(day_id, grade, difficulty, &mut rng) {
+                Some(q) => push_unique(pool, &mut seen, &q.prompt, &q.answer, q.visual.as_ref()),
+                None => break,
+            }
+        }
+    }
+
+    core.shuffle(&mut rng);
+    stretch.shuffle(&mut rng);
+
+    // A short pool on one side is made up from the other. Tiny banks can
+    // still come up short; the sheet then just has fewer questions.
+    let take_stretch = want_stretch.min(stretch.len()).max(count.saturating_sub(core.len()));
+    stretch.truncate(take_stretch);
+    core.truncate(count.saturating_sub(stretch.len()));
+
+    Worksheet { core, stretch }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_inputs_rebuild_the_same_sheet() {
+        for day_id in 1..=12 {
+            let a = build_worksheet(day_id, Grade::G3, DEFAULT_COUNT, 99);
+            assert_eq!(a, build_worksheet(day_id, Grade::G3, DEFAULT_COUNT, 99));
+            assert!(a.core.len() + a.stretch.len() <= DEFAULT_COUNT);
+            assert!(!a.core.is_empty());
+        }
+    }
+}
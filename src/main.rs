@@ -1,5 +1,13 @@
 mod app;
 
 fn main() {
+    // Native builds double as dev tools: a mock question worker, and the
+    // Trunk hook that stamps sw.js with the build.
+    #[cfg(not(target_arch = "wasm32"))]
+    match std::env::args().nth(1).as_deref() {
+        Some("mock-worker") => return app::mock_worker::main(std::env::args().skip(2)),
+        Some("stamp-sw") => return app::build_stamp::main(),
+        _ => {}
+    }
     yew::Renderer::<app::App>::new().render();
-}
\ No newline at end of file
+}
@@ -0,0 +1,83 @@
+// sw.js — offline support for THE NUMBERS.
+//
+// Precaches the app shell on install so a cold start works with no network.
+// BUILD is filled in by the Trunk post_build hook (`cargo run -- stamp-sw`)
+// with a hash of the built files: every build gets its own cache, and an
+// installed copy notices that sw.js changed. A new version waits until the
+// page says so (the "new version" banner), then old caches are dropped.
+//
+// The worker API is cross-origin and never touched here; the app falls back
+// to built-in questions on its own.
+
+const BUILD = "__BUILD__";
+const CACHE = `the-numbers-${BUILD}`;
+const SHELL = [
+  "./",
+  "index.html",
+  "the-numbers.js",
+  "the-numbers_bg.wasm",
+  "manifest.webmanifest",
+  "icons/icon-192.png",
+  "icons/icon-512.png",
+  "icons/icon-maskable-512.png",
+];
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE)
+      // "reload" skips the HTTP cache so a deploy never precaches stale files.
+      .then((cache) => cache.addAll(SHELL.map((url) => new Request(url, { cache: "reload" }))))
+      // First install: nothing to interrupt, take over straight away.
+      .then(() => (self.registration.active ? undefined : self.skipWaiting()))
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((k) => k.startsWith("the-numbers-") && k !== CACHE).map((k) => caches.delete(k))))
+      .then(() => self.clients.claim())
+  );
+});
+
+// The page's "Reload" button.
+self.addEventListener("message", (event) => {
+  if (event.data && event.data.type === "SKIP_WAITING") {
+    self.skipWaiting();
+  }
+});
+
+self.addEventListener("fetch", (event) => {
+  const req = event.request;
+  const url = new URL(req.url);
+  if (req.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  // config.json can be edited after a deploy: network first, last copy when offline.
+  if (url.pathname.endsWith("/config.json")) {
+    event.respondWith(
+      fetch(req)
+        .then((resp) => {
+          if (resp.ok) {
+            const copy = resp.clone();
+            caches.open(CACHE).then((cache) => cache.put(req, copy));
+          }
+          return resp;
+        })
+        .catch(() => caches.match(req).then((hit) => hit || Response.error()))
+    );
+    return;
+  }
+
+  // Page loads get the cached shell (HashRouter: every route is index.html).
+  if (req.mode === "navigate") {
+    event.respondWith(caches.match("index.html").then((hit) => hit || fetch(req)));
+    return;
+  }
+
+  // Everything else: cache first, then the network.
+  event.respondWith(caches.match(req).then((hit) => hit || fetch(req)));
+});